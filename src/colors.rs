@@ -2,7 +2,7 @@
 //! This module includes all default colors for quick use
 //!
 
-use crate::{Color, ColorRGBA, ColorType};
+use crate::{Color, ColorRGBA};
 
 /// A color in the gray spectrum
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -35,15 +35,20 @@ impl Grays {
     /// v: The value of the gray color, 0: black, 1: white
     ///
     /// a: The alpha value
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that v and a are between 0 and 1
     pub unsafe fn new_unsafe(v: f32, a: f32) -> Self {
         return Self { v, a };
     }
 }
 
 impl Color for Grays {
-    const TYPE: ColorType = ColorType::RGB;
+    type Base = crate::ColorRGBA;
 
-    fn get_rgba(&self) -> crate::ColorRGBA {
+    fn get_base(&self) -> crate::ColorRGBA {
         return unsafe { ColorRGBA::new_unsafe(self.v, self.v, self.v, self.a) };
     }
 }
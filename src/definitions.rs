@@ -4,8 +4,8 @@
 
 use crate::utils;
 
-/// A struct for defining a single color in RGBA space all values are between 0
-/// and 1
+/// A struct for defining a single color in gamma encoded sRGBA space all values
+/// are between 0 and 1, use ColorLinearRGBA for linear light values
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
 pub struct ColorRGBA {
     /// The red combonent
@@ -64,6 +64,11 @@ impl ColorRGBA {
     /// b: The blue component
     ///
     /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that r, g, b and a are between 0 and 1
     pub unsafe fn new_unsafe(r: f32, g: f32, b: f32, a: f32) -> Self {
         return Self {
             r: r,
@@ -101,13 +106,150 @@ impl ColorRGBA {
 }
 
 impl Color for ColorRGBA {
-    const TYPE: ColorType = ColorType::RGB;
+    type Base = ColorRGBA;
 
-    fn get_rgba(&self) -> ColorRGBA {
+    fn get_base(&self) -> ColorRGBA {
         return *self;
     }
 }
 
+impl ColorSpace for ColorRGBA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_rgb(color);
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return color.get_rgba();
+    }
+}
+
+/// A struct for defining a single color in linear light sRGBA space all values
+/// are between 0 and 1, unlike ColorRGBA the components are proportional to the
+/// emitted light so they can be mixed and averaged directly
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorLinearRGBA {
+    /// The red component
+    r: f32,
+    /// The green component
+    g: f32,
+    /// The blue component
+    b: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorLinearRGBA {
+    /// Constructs a new linear rgba color, all values are clamped to between 0
+    /// and 1
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// g: The green component
+    ///
+    /// b: The blue component
+    ///
+    /// a: The alpha component
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        return Self {
+            r: r.clamp(0.0, 1.0),
+            g: g.clamp(0.0, 1.0),
+            b: b.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new linear rgba color with the alpha component equal to 1,
+    /// all values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// g: The green component
+    ///
+    /// b: The blue component
+    pub fn new_rgb(r: f32, g: f32, b: f32) -> Self {
+        return Self::new(r, g, b, 1.0);
+    }
+
+    /// Constructs a new linear rgba color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// g: The green component
+    ///
+    /// b: The blue component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that r, g, b and a are between 0 and 1
+    pub unsafe fn new_unsafe(r: f32, g: f32, b: f32, a: f32) -> Self {
+        return Self {
+            r: r,
+            g: g,
+            b: b,
+            a: a,
+        };
+    }
+
+    /// Retrieves the red component of the color
+    pub fn get_red(&self) -> f32 {
+        return self.r;
+    }
+
+    /// Retrieves the green component of the color
+    pub fn get_green(&self) -> f32 {
+        return self.g;
+    }
+
+    /// Retrieves the blue component of the color
+    pub fn get_blue(&self) -> f32 {
+        return self.b;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: red, green,
+    /// blue, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.r, self.g, self.b, self.a];
+    }
+
+    /// Retrieves the relative luminance of the color, this is the Y component of
+    /// the color in XYZ space
+    pub fn get_luminance(&self) -> f32 {
+        return 0.2126729 * self.r + 0.7151522 * self.g + 0.072175 * self.b;
+    }
+}
+
+impl Color for ColorLinearRGBA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::linear_rgb_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorLinearRGBA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_linear_rgb(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_linear_rgb(&color.get_rgba());
+    }
+}
+
 /// A struct for defining a single color in HSLA space all values are between 0
 /// and 1
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -168,6 +310,11 @@ impl ColorHSLA {
     /// l: The lightness component
     ///
     /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1 and s, l and a are between 0 and 1
     pub unsafe fn new_unsafe(h: f32, s: f32, l: f32, a: f32) -> Self {
         return Self {
             h: h,
@@ -205,13 +352,23 @@ impl ColorHSLA {
 }
 
 impl Color for ColorHSLA {
-    const TYPE: ColorType = ColorType::HSL;
+    type Base = ColorHSLA;
 
-    fn get_hsla(&self) -> ColorHSLA {
+    fn get_base(&self) -> ColorHSLA {
         return *self;
     }
 }
 
+impl ColorSpace for ColorHSLA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return ColorHSLA::from(utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return color.get_hsla();
+    }
+}
+
 /// A struct for defining a single color in HSLV space all values are between 0
 /// and 1
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -272,6 +429,11 @@ impl ColorHSVA {
     /// v: The value component
     ///
     /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1 and s, v and a are between 0 and 1
     pub unsafe fn new_unsafe(h: f32, s: f32, v: f32, a: f32) -> Self {
         return Self {
             h: h,
@@ -309,13 +471,23 @@ impl ColorHSVA {
 }
 
 impl Color for ColorHSVA {
-    const TYPE: ColorType = ColorType::HSV;
+    type Base = ColorHSVA;
 
-    fn get_hsva(&self) -> ColorHSVA {
+    fn get_base(&self) -> ColorHSVA {
         return *self;
     }
 }
 
+impl ColorSpace for ColorHSVA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return ColorHSVA::from(utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return color.get_hsva();
+    }
+}
+
 /// A struct for defining a single color in HSIA space all values are between 0
 /// and 1
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -376,6 +548,11 @@ impl ColorHSIA {
     /// i: The intensity component
     ///
     /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1 and s, i and a are between 0 and 1
     pub unsafe fn new_unsafe(h: f32, s: f32, i: f32, a: f32) -> Self {
         return Self {
             h: h,
@@ -413,133 +590,5297 @@ impl ColorHSIA {
 }
 
 impl Color for ColorHSIA {
-    const TYPE: ColorType = ColorType::HSI;
+    type Base = ColorHSIA;
 
-    fn get_hsia(&self) -> ColorHSIA {
+    fn get_base(&self) -> ColorHSIA {
         return *self;
     }
 }
 
-/// A generic N-dimensional color, all components are clamped between 0 and 1
+impl ColorSpace for ColorHSIA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return ColorHSIA::from(utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return color.get_hsia();
+    }
+}
+
+/// A struct for defining a single color in HWBA space all values are between 0
+/// and 1, if the whiteness and blackness add up to more than 1 the color is the
+/// gray with the ratio between them
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct ColorND<const N: usize> {
-    values: [f32; N],
+pub struct ColorHWBA {
+    /// The hue component
+    h: f32,
+    /// The whiteness component
+    w: f32,
+    /// The blackness component
+    b: f32,
+    /// The alpha component
+    a: f32,
 }
 
-impl<const N: usize> ColorND<N> {
-    /// Constructs a new N-dimensional color
+impl ColorHWBA {
+    /// Constructs a new hwba color, all values are clamped to between 0 and 1
     ///
     /// # Parameters
     ///
-    /// values: All color components
-    pub fn new(values: &[f32; N]) -> Self {
-        let values: [f32; N] = values
-            .iter()
-            .map(|value| {
-                return value.clamp(0.0, 1.0);
-            })
-            .collect::<Vec<f32>>()
-            .try_into()
-            .expect("Will never fail");
-
-        return Self { values };
+    /// h: The hue component
+    ///
+    /// w: The whiteness component
+    ///
+    /// b: The blackness component
+    ///
+    /// a: The alpha component
+    pub fn new(h: f32, w: f32, b: f32, a: f32) -> Self {
+        return Self {
+            h: h.rem_euclid(1.0),
+            w: w.clamp(0.0, 1.0),
+            b: b.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
     }
 
-    /// Retrieves all the color components
-    pub fn get(&self) -> &[f32; N] {
-        return &self.values;
+    /// Constructs a new hwba color with the alpha component equal to 1, all
+    /// values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// w: The whiteness component
+    ///
+    /// b: The blackness component
+    pub fn new_hwb(h: f32, w: f32, b: f32) -> Self {
+        return Self::new(h, w, b, 1.0);
     }
-}
 
-/// A simple enum type for determining which format is the base format of a
-/// color trait, this is used to implement the correct default functions such
-/// that only one color return function needs to be implemented
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub enum ColorType {
-    RGB,
-    HSV,
-    HSL,
-    HSI,
-}
+    /// Constructs a new hwba color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// w: The whiteness component
+    ///
+    /// b: The blackness component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1 and w, b and a are between 0 and 1
+    pub unsafe fn new_unsafe(h: f32, w: f32, b: f32, a: f32) -> Self {
+        return Self {
+            h: h,
+            w: w,
+            b: b,
+            a: a,
+        };
+    }
 
-/// Defines a single color which can be expressed in RGBA
-pub trait Color {
-    /// The base color type, the get_x method of this color must be implemented
-    /// while the others will be implemented automatically
-    const TYPE: ColorType;
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
 
-    /// Retrieves the RGBA color for this color
-    fn get_rgba(&self) -> ColorRGBA {
-        return match Self::TYPE {
-            ColorType::RGB => {
-                panic!("The get_rgba() method must be implemented for a color of TYPE RGB")
-            }
-            ColorType::HSV => utils::hsv_to_rgb(&self.get_hsva()),
-            ColorType::HSL => utils::hsl_to_rgb(&self.get_hsla()),
-            ColorType::HSI => utils::hsi_to_rgb(&self.get_hsia()),
-        };
+    /// Retrieves the whiteness component of the color
+    pub fn get_whiteness(&self) -> f32 {
+        return self.w;
     }
 
-    /// Retrieves the HSVA color for this color
-    fn get_hsva(&self) -> ColorHSVA {
-        return match Self::TYPE {
-            ColorType::RGB => utils::rgb_to_hsv(&self.get_rgba()),
-            ColorType::HSV => {
-                panic!("The get_hsva() method must be implemented for a color of TYPE HSV")
-            }
-            ColorType::HSL => utils::hsl_to_hsv(&self.get_hsla()),
-            ColorType::HSI => utils::hsi_to_hsv(&self.get_hsia()),
-        };
+    /// Retrieves the blackness component of the color
+    pub fn get_blackness(&self) -> f32 {
+        return self.b;
     }
 
-    /// Retrieves the HSLA color for this color
-    fn get_hsla(&self) -> ColorHSLA {
-        return match Self::TYPE {
-            ColorType::RGB => utils::rgb_to_hsl(&self.get_rgba()),
-            ColorType::HSV => utils::hsv_to_hsl(&self.get_hsva()),
-            ColorType::HSL => {
-                panic!("The get_hsla() method must be implemented for a color of TYPE HSL")
-            }
-            ColorType::HSI => utils::hsi_to_hsl(&self.get_hsia()),
-        };
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
     }
 
-    /// Retrieves the HSIA color for this color
-    fn get_hsia(&self) -> ColorHSIA {
-        return match Self::TYPE {
-            ColorType::RGB => utils::rgb_to_hsi(&self.get_rgba()),
-            ColorType::HSV => utils::hsv_to_hsi(&self.get_hsva()),
-            ColorType::HSL => utils::hsl_to_hsi(&self.get_hsla()),
-            ColorType::HSI => {
-                panic!("The get_hsia() method must be implemented for a color of TYPE HSI")
-            }
-        };
+    /// Retrieves all the color components in an array in the order: hue,
+    /// whiteness, blackness, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.h, self.w, self.b, self.a];
     }
 }
 
-/// Defines a color map which can convert a N-dimensional color into a normal
-/// color
-pub trait ColorMap<const N: usize> {
-    /// Retrieves the normal color from the N-dimensional color
-    ///
-    /// # Parameters
-    ///
-    /// color: The N-dimensional color to convert
-    fn get_color(&self, color: ColorND<N>) -> impl Color;
+impl Color for ColorHWBA {
+    type Base = ColorHWBA;
+
+    fn get_base(&self) -> ColorHWBA {
+        return *self;
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl ColorSpace for ColorHWBA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_hwb(&utils::xyz_to_rgb(color));
+    }
 
-    /// Test the ColorRGBA struct
-    mod color_rgba {
-        use super::*;
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return color.get_hwba();
+    }
+}
 
-        /// Test new method
-        #[test]
-        fn new() {
-            let result_valid = ColorRGBA::new(0.1, 0.2, 0.3, 0.4);
+/// A struct for defining a single color in RYBA space all values are between 0
+/// and 1, the components are the amounts of the red, yellow and blue paints of
+/// the traditional artist color wheel mixed on white paper
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorRYBA {
+    /// The red component
+    r: f32,
+    /// The yellow component
+    y: f32,
+    /// The blue component
+    b: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorRYBA {
+    /// Constructs a new ryba color, all values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// y: The yellow component
+    ///
+    /// b: The blue component
+    ///
+    /// a: The alpha component
+    pub fn new(r: f32, y: f32, b: f32, a: f32) -> Self {
+        return Self {
+            r: r.clamp(0.0, 1.0),
+            y: y.clamp(0.0, 1.0),
+            b: b.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new ryba color with the alpha component equal to 1, all
+    /// values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// y: The yellow component
+    ///
+    /// b: The blue component
+    pub fn new_ryb(r: f32, y: f32, b: f32) -> Self {
+        return Self::new(r, y, b, 1.0);
+    }
+
+    /// Constructs a new ryba color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// y: The yellow component
+    ///
+    /// b: The blue component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that r, y, b and a are between 0 and 1
+    pub unsafe fn new_unsafe(r: f32, y: f32, b: f32, a: f32) -> Self {
+        return Self {
+            r: r,
+            y: y,
+            b: b,
+            a: a,
+        };
+    }
+
+    /// Retrieves the red component of the color
+    pub fn get_red(&self) -> f32 {
+        return self.r;
+    }
+
+    /// Retrieves the yellow component of the color
+    pub fn get_yellow(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the blue component of the color
+    pub fn get_blue(&self) -> f32 {
+        return self.b;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: red,
+    /// yellow, blue, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.r, self.y, self.b, self.a];
+    }
+}
+
+impl Color for ColorRYBA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::ryb_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorRYBA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_ryb(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_ryb(&color.get_rgba());
+    }
+}
+
+/// A struct for defining a single color in CMYKA space all values are between 0
+/// and 1, the color is a device independent subtractive color where the ink
+/// coverage of each component is multiplied with the paper white
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorCMYKA {
+    /// The cyan component
+    c: f32,
+    /// The magenta component
+    m: f32,
+    /// The yellow component
+    y: f32,
+    /// The black component
+    k: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorCMYKA {
+    /// Constructs a new cmyka color, all values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// c: The cyan component
+    ///
+    /// m: The magenta component
+    ///
+    /// y: The yellow component
+    ///
+    /// k: The black component
+    ///
+    /// a: The alpha component
+    pub fn new(c: f32, m: f32, y: f32, k: f32, a: f32) -> Self {
+        return Self {
+            c: c.clamp(0.0, 1.0),
+            m: m.clamp(0.0, 1.0),
+            y: y.clamp(0.0, 1.0),
+            k: k.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new cmyka color with the alpha component equal to 1, all
+    /// values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// c: The cyan component
+    ///
+    /// m: The magenta component
+    ///
+    /// y: The yellow component
+    ///
+    /// k: The black component
+    pub fn new_cmyk(c: f32, m: f32, y: f32, k: f32) -> Self {
+        return Self::new(c, m, y, k, 1.0);
+    }
+
+    /// Constructs a new cmyka color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// c: The cyan component
+    ///
+    /// m: The magenta component
+    ///
+    /// y: The yellow component
+    ///
+    /// k: The black component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that c, m, y, k and a are between 0 and 1
+    pub unsafe fn new_unsafe(c: f32, m: f32, y: f32, k: f32, a: f32) -> Self {
+        return Self {
+            c: c,
+            m: m,
+            y: y,
+            k: k,
+            a: a,
+        };
+    }
+
+    /// Retrieves the cyan component of the color
+    pub fn get_cyan(&self) -> f32 {
+        return self.c;
+    }
+
+    /// Retrieves the magenta component of the color
+    pub fn get_magenta(&self) -> f32 {
+        return self.m;
+    }
+
+    /// Retrieves the yellow component of the color
+    pub fn get_yellow(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the black component of the color
+    pub fn get_black(&self) -> f32 {
+        return self.k;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: cyan,
+    /// magenta, yellow, black, alpha
+    pub fn get(&self) -> [f32; 5] {
+        return [self.c, self.m, self.y, self.k, self.a];
+    }
+}
+
+impl Color for ColorCMYKA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::cmyk_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorCMYKA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_cmyk(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_cmyk(&color.get_rgba());
+    }
+}
+
+/// The strategy used to generate the black component when separating a color
+/// into CMYK, the gray component of a color is the amount of cyan, magenta and
+/// yellow they have in common
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum BlackGeneration {
+    /// The entire gray component is replaced by black, this is the naive
+    /// conversion
+    Full,
+    /// Under color removal, black replaces the gray component only in dark
+    /// tones, black starts being added when the gray component is above start
+    /// and increases linearly until it replaces the fraction amount of the
+    /// gray component at full darkness, both values are between 0 and 1
+    UnderColorRemoval { start: f32, amount: f32 },
+    /// Gray component replacement, black replaces the fraction amount of the
+    /// gray component in all tones, the amount is between 0 and 1
+    GrayComponentReplacement { amount: f32 },
+}
+
+/// The settings used to separate a color into CMYK
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct CmykSeparation {
+    /// The strategy used to generate the black component
+    black: BlackGeneration,
+    /// The maximum total ink coverage, between 1 and 4
+    ink_limit: f32,
+}
+
+impl CmykSeparation {
+    /// The naive separation with full black generation and no ink limit
+    pub const NAIVE: Self = Self {
+        black: BlackGeneration::Full,
+        ink_limit: 4.0,
+    };
+
+    /// Constructs a new separation, the parameters of the black generation are
+    /// clamped to between 0 and 1 and the ink limit is clamped to between 1
+    /// and 4
+    ///
+    /// # Parameters
+    ///
+    /// black: The strategy used to generate the black component
+    ///
+    /// ink_limit: The maximum total ink coverage, 3 means a total coverage of
+    /// 300%
+    pub fn new(black: BlackGeneration, ink_limit: f32) -> Self {
+        let black = match black {
+            BlackGeneration::Full => BlackGeneration::Full,
+            BlackGeneration::UnderColorRemoval { start, amount } => {
+                BlackGeneration::UnderColorRemoval {
+                    start: start.clamp(0.0, 1.0),
+                    amount: amount.clamp(0.0, 1.0),
+                }
+            }
+            BlackGeneration::GrayComponentReplacement { amount } => {
+                BlackGeneration::GrayComponentReplacement {
+                    amount: amount.clamp(0.0, 1.0),
+                }
+            }
+        };
+
+        return Self {
+            black: black,
+            ink_limit: ink_limit.clamp(1.0, 4.0),
+        };
+    }
+
+    /// Retrieves the strategy used to generate the black component
+    pub fn get_black_generation(&self) -> BlackGeneration {
+        return self.black;
+    }
+
+    /// Retrieves the maximum total ink coverage
+    pub fn get_ink_limit(&self) -> f32 {
+        return self.ink_limit;
+    }
+}
+
+/// The matrix coefficients used to convert between R'G'B' and YCbCr
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum YCbCrMatrix {
+    /// The coefficients of ITU-R BT.601 used for standard definition video
+    BT601,
+    /// The coefficients of ITU-R BT.709 used for high definition video
+    BT709,
+    /// The non-constant luminance coefficients of ITU-R BT.2020 used for ultra
+    /// high definition video
+    BT2020,
+}
+
+impl YCbCrMatrix {
+    /// Retrieves the luma coefficients of the red and blue components in the
+    /// order: Kr, Kb, the green coefficient is 1 - Kr - Kb
+    pub fn get_coefficients(&self) -> [f32; 2] {
+        return match self {
+            Self::BT601 => [0.299, 0.114],
+            Self::BT709 => [0.2126, 0.0722],
+            Self::BT2020 => [0.2627, 0.0593],
+        };
+    }
+}
+
+/// The quantization range of a YCbCr color
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum YCbCrRange {
+    /// The full range where luma uses all values from 0 to 1 and chroma is
+    /// centered at 0.5
+    Full,
+    /// The limited studio swing range where luma is between 16/255 and 235/255
+    /// and chroma is between 16/255 and 240/255 centered at 128/255, the values
+    /// correspond to 8-bit code values divided by 255 or 10-bit code values
+    /// divided by 1020
+    Limited,
+}
+
+/// A struct for defining a single color in YCbCrA space all values are between
+/// 0 and 1, the color is defined by its matrix coefficients and quantization
+/// range and is converted from the gamma encoded R'G'B' values of a ColorRGBA
+/// without any change of primaries
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorYCbCrA {
+    /// The luma component
+    y: f32,
+    /// The blue-difference chroma component
+    cb: f32,
+    /// The red-difference chroma component
+    cr: f32,
+    /// The alpha component
+    a: f32,
+    /// The matrix coefficients of the color
+    matrix: YCbCrMatrix,
+    /// The quantization range of the color
+    range: YCbCrRange,
+}
+
+impl ColorYCbCrA {
+    /// Constructs a new ycbcra color, all values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// cb: The blue-difference chroma component
+    ///
+    /// cr: The red-difference chroma component
+    ///
+    /// a: The alpha component
+    ///
+    /// matrix: The matrix coefficients of the color
+    ///
+    /// range: The quantization range of the color
+    pub fn new(y: f32, cb: f32, cr: f32, a: f32, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        return Self {
+            y: y.clamp(0.0, 1.0),
+            cb: cb.clamp(0.0, 1.0),
+            cr: cr.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+            matrix: matrix,
+            range: range,
+        };
+    }
+
+    /// Constructs a new ycbcra color with the alpha component equal to 1, all
+    /// values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// cb: The blue-difference chroma component
+    ///
+    /// cr: The red-difference chroma component
+    ///
+    /// matrix: The matrix coefficients of the color
+    ///
+    /// range: The quantization range of the color
+    pub fn new_ycbcr(y: f32, cb: f32, cr: f32, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        return Self::new(y, cb, cr, 1.0, matrix, range);
+    }
+
+    /// Constructs a new ycbcra color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// cb: The blue-difference chroma component
+    ///
+    /// cr: The red-difference chroma component
+    ///
+    /// a: The alpha component
+    ///
+    /// matrix: The matrix coefficients of the color
+    ///
+    /// range: The quantization range of the color
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that y, cb, cr and a are between 0 and 1
+    pub unsafe fn new_unsafe(
+        y: f32,
+        cb: f32,
+        cr: f32,
+        a: f32,
+        matrix: YCbCrMatrix,
+        range: YCbCrRange,
+    ) -> Self {
+        return Self {
+            y: y,
+            cb: cb,
+            cr: cr,
+            a: a,
+            matrix: matrix,
+            range: range,
+        };
+    }
+
+    /// Retrieves the luma component of the color
+    pub fn get_luma(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the blue-difference chroma component of the color
+    pub fn get_blue_difference(&self) -> f32 {
+        return self.cb;
+    }
+
+    /// Retrieves the red-difference chroma component of the color
+    pub fn get_red_difference(&self) -> f32 {
+        return self.cr;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the matrix coefficients of the color
+    pub fn get_matrix(&self) -> YCbCrMatrix {
+        return self.matrix;
+    }
+
+    /// Retrieves the quantization range of the color
+    pub fn get_range(&self) -> YCbCrRange {
+        return self.range;
+    }
+
+    /// Retrieves all the color components in an array in the order: luma,
+    /// blue-difference chroma, red-difference chroma, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.y, self.cb, self.cr, self.a];
+    }
+}
+
+impl Color for ColorYCbCrA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::ycbcr_to_rgb(self);
+    }
+}
+
+/// Converts from XYZ using the BT.709 matrix with full range
+impl ColorSpace for ColorYCbCrA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_ycbcr(
+            &utils::xyz_to_rgb(color),
+            YCbCrMatrix::BT709,
+            YCbCrRange::Full,
+        );
+    }
+}
+
+/// A struct for defining a single color in YUVA space as used by analog PAL
+/// television, the luma and alpha are between 0 and 1, u is between -0.436 and
+/// 0.436 and v is between -0.615 and 0.615, the color is converted from the
+/// gamma encoded R'G'B' values of a ColorRGBA
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorYUVA {
+    /// The luma component
+    y: f32,
+    /// The u chroma component
+    u: f32,
+    /// The v chroma component
+    v: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorYUVA {
+    /// Constructs a new yuva color, luma and alpha are clamped to between 0 and
+    /// 1, u is clamped to between -0.436 and 0.436 and v is clamped to between
+    /// -0.615 and 0.615
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// u: The u chroma component
+    ///
+    /// v: The v chroma component
+    ///
+    /// a: The alpha component
+    pub fn new(y: f32, u: f32, v: f32, a: f32) -> Self {
+        return Self {
+            y: y.clamp(0.0, 1.0),
+            u: u.clamp(-0.436, 0.436),
+            v: v.clamp(-0.615, 0.615),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new yuva color with the alpha component equal to 1, luma is
+    /// clamped to between 0 and 1, u is clamped to between -0.436 and 0.436 and
+    /// v is clamped to between -0.615 and 0.615
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// u: The u chroma component
+    ///
+    /// v: The v chroma component
+    pub fn new_yuv(y: f32, u: f32, v: f32) -> Self {
+        return Self::new(y, u, v, 1.0);
+    }
+
+    /// Constructs a new yuva color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// u: The u chroma component
+    ///
+    /// v: The v chroma component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that y and a are between 0 and 1, u is between -0.436 and 0.436 and v is
+    /// between -0.615 and 0.615
+    pub unsafe fn new_unsafe(y: f32, u: f32, v: f32, a: f32) -> Self {
+        return Self {
+            y: y,
+            u: u,
+            v: v,
+            a: a,
+        };
+    }
+
+    /// Retrieves the luma component of the color
+    pub fn get_luma(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the u chroma component of the color
+    pub fn get_u(&self) -> f32 {
+        return self.u;
+    }
+
+    /// Retrieves the v chroma component of the color
+    pub fn get_v(&self) -> f32 {
+        return self.v;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: luma, u
+    /// chroma, v chroma, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.y, self.u, self.v, self.a];
+    }
+}
+
+impl Color for ColorYUVA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::yuv_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorYUVA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_yuv(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_yuv(&color.get_rgba());
+    }
+}
+
+/// A struct for defining a single color in YIQA space as used by analog NTSC
+/// television, the luma and alpha are between 0 and 1, the in-phase component
+/// is between -0.596 and 0.596 and the quadrature component is between -0.523
+/// and 0.523
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorYIQA {
+    /// The luma component
+    y: f32,
+    /// The in-phase chroma component
+    i: f32,
+    /// The quadrature chroma component
+    q: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorYIQA {
+    /// Constructs a new yiqa color, luma and alpha are clamped to between 0 and
+    /// 1, the in-phase component is clamped to between -0.596 and 0.596 and the
+    /// quadrature component is clamped to between -0.523 and 0.523
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// i: The in-phase chroma component
+    ///
+    /// q: The quadrature chroma component
+    ///
+    /// a: The alpha component
+    pub fn new(y: f32, i: f32, q: f32, a: f32) -> Self {
+        return Self {
+            y: y.clamp(0.0, 1.0),
+            i: i.clamp(-0.596, 0.596),
+            q: q.clamp(-0.523, 0.523),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new yiqa color with the alpha component equal to 1, luma is
+    /// clamped to between 0 and 1, the in-phase component is clamped to between
+    /// -0.596 and 0.596 and the quadrature component is clamped to between
+    /// -0.523 and 0.523
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// i: The in-phase chroma component
+    ///
+    /// q: The quadrature chroma component
+    pub fn new_yiq(y: f32, i: f32, q: f32) -> Self {
+        return Self::new(y, i, q, 1.0);
+    }
+
+    /// Constructs a new yiqa color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// i: The in-phase chroma component
+    ///
+    /// q: The quadrature chroma component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that y and a are between 0 and 1, i is between -0.596 and 0.596 and q is
+    /// between -0.523 and 0.523
+    pub unsafe fn new_unsafe(y: f32, i: f32, q: f32, a: f32) -> Self {
+        return Self {
+            y: y,
+            i: i,
+            q: q,
+            a: a,
+        };
+    }
+
+    /// Retrieves the luma component of the color
+    pub fn get_luma(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the in-phase chroma component of the color
+    pub fn get_in_phase(&self) -> f32 {
+        return self.i;
+    }
+
+    /// Retrieves the quadrature chroma component of the color
+    pub fn get_quadrature(&self) -> f32 {
+        return self.q;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: luma,
+    /// in-phase chroma, quadrature chroma, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.y, self.i, self.q, self.a];
+    }
+}
+
+impl Color for ColorYIQA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::yiq_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorYIQA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_yiq(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_yiq(&color.get_rgba());
+    }
+}
+
+/// A struct for defining a single color in YCoCgA space, the luma and alpha are
+/// between 0 and 1 and the chroma components are between -0.5 and 0.5
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorYCoCgA {
+    /// The luma component
+    y: f32,
+    /// The orange chroma component
+    co: f32,
+    /// The green chroma component
+    cg: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorYCoCgA {
+    /// Constructs a new ycocga color, luma and alpha are clamped to between 0
+    /// and 1 and the chroma components are clamped to between -0.5 and 0.5
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// co: The orange chroma component
+    ///
+    /// cg: The green chroma component
+    ///
+    /// a: The alpha component
+    pub fn new(y: f32, co: f32, cg: f32, a: f32) -> Self {
+        return Self {
+            y: y.clamp(0.0, 1.0),
+            co: co.clamp(-0.5, 0.5),
+            cg: cg.clamp(-0.5, 0.5),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new ycocga color with the alpha component equal to 1, luma
+    /// is clamped to between 0 and 1 and the chroma components are clamped to
+    /// between -0.5 and 0.5
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// co: The orange chroma component
+    ///
+    /// cg: The green chroma component
+    pub fn new_ycocg(y: f32, co: f32, cg: f32) -> Self {
+        return Self::new(y, co, cg, 1.0);
+    }
+
+    /// Constructs a new ycocga color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// co: The orange chroma component
+    ///
+    /// cg: The green chroma component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that y and a are between 0 and 1 and co and cg are between -0.5 and 0.5
+    pub unsafe fn new_unsafe(y: f32, co: f32, cg: f32, a: f32) -> Self {
+        return Self {
+            y: y,
+            co: co,
+            cg: cg,
+            a: a,
+        };
+    }
+
+    /// Retrieves the luma component of the color
+    pub fn get_luma(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the orange chroma component of the color
+    pub fn get_orange_chroma(&self) -> f32 {
+        return self.co;
+    }
+
+    /// Retrieves the green chroma component of the color
+    pub fn get_green_chroma(&self) -> f32 {
+        return self.cg;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: luma,
+    /// orange chroma, green chroma, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.y, self.co, self.cg, self.a];
+    }
+}
+
+impl Color for ColorYCoCgA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::ycocg_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorYCoCgA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_ycocg(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_ycocg(&color.get_rgba());
+    }
+}
+
+/// A struct for defining a single color in the lossless YCoCg-R space for 8 bit
+/// colors, the luma is between 0 and 255, the chroma components are between
+/// -255 and 255 and the alpha is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorYCoCgRA {
+    /// The luma component
+    y: i32,
+    /// The orange chroma component
+    co: i32,
+    /// The green chroma component
+    cg: i32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorYCoCgRA {
+    /// Constructs a new ycocgra color, the luma is clamped to between 0 and 255,
+    /// the chroma components are clamped to between -255 and 255 and alpha is
+    /// clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// co: The orange chroma component
+    ///
+    /// cg: The green chroma component
+    ///
+    /// a: The alpha component
+    pub fn new(y: i32, co: i32, cg: i32, a: f32) -> Self {
+        return Self {
+            y: y.clamp(0, 255),
+            co: co.clamp(-255, 255),
+            cg: cg.clamp(-255, 255),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new ycocgra color with the alpha component equal to 1, the
+    /// luma is clamped to between 0 and 255 and the chroma components are
+    /// clamped to between -255 and 255
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// co: The orange chroma component
+    ///
+    /// cg: The green chroma component
+    pub fn new_ycocg_r(y: i32, co: i32, cg: i32) -> Self {
+        return Self::new(y, co, cg, 1.0);
+    }
+
+    /// Constructs a new ycocgra color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// y: The luma component
+    ///
+    /// co: The orange chroma component
+    ///
+    /// cg: The green chroma component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that y is between 0 and 255, co and cg are between -255 and 255 and a is
+    /// between 0 and 1
+    pub unsafe fn new_unsafe(y: i32, co: i32, cg: i32, a: f32) -> Self {
+        return Self {
+            y: y,
+            co: co,
+            cg: cg,
+            a: a,
+        };
+    }
+
+    /// Retrieves the luma component of the color
+    pub fn get_luma(&self) -> i32 {
+        return self.y;
+    }
+
+    /// Retrieves the orange chroma component of the color
+    pub fn get_orange_chroma(&self) -> i32 {
+        return self.co;
+    }
+
+    /// Retrieves the green chroma component of the color
+    pub fn get_green_chroma(&self) -> i32 {
+        return self.cg;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the integer color components in an array in the order: luma,
+    /// orange chroma, green chroma
+    pub fn get_integers(&self) -> [i32; 3] {
+        return [self.y, self.co, self.cg];
+    }
+
+    /// Retrieves all the color components in an array in the order: luma,
+    /// orange chroma, green chroma, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.y as f32, self.co as f32, self.cg as f32, self.a];
+    }
+}
+
+impl Color for ColorYCoCgRA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::ycocg_r_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorYCoCgRA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_ycocg_r(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_ycocg_r(&color.get_rgba());
+    }
+}
+
+/// A struct for defining a single color in CIE XYZ space relative to the D65
+/// reference white, the reference white has a Y value of 1, the constructors
+/// clamp the tristimulus values to be non-negative while conversions from RGB
+/// spaces with primaries outside of the spectral locus like AP0 keep negative
+/// values, the alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorXYZA {
+    /// The X tristimulus value
+    x: f32,
+    /// The Y tristimulus value
+    y: f32,
+    /// The Z tristimulus value
+    z: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorXYZA {
+    /// Constructs a new xyza color, the tristimulus values are clamped to be
+    /// non-negative and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// x: The X tristimulus value
+    ///
+    /// y: The Y tristimulus value
+    ///
+    /// z: The Z tristimulus value
+    ///
+    /// a: The alpha component
+    pub fn new(x: f32, y: f32, z: f32, a: f32) -> Self {
+        return Self {
+            x: x.max(0.0),
+            y: y.max(0.0),
+            z: z.max(0.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new xyza color with the alpha component equal to 1, the
+    /// tristimulus values are clamped to be non-negative
+    ///
+    /// # Parameters
+    ///
+    /// x: The X tristimulus value
+    ///
+    /// y: The Y tristimulus value
+    ///
+    /// z: The Z tristimulus value
+    pub fn new_xyz(x: f32, y: f32, z: f32) -> Self {
+        return Self::new(x, y, z, 1.0);
+    }
+
+    /// Constructs a new xyza color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// x: The X tristimulus value
+    ///
+    /// y: The Y tristimulus value
+    ///
+    /// z: The Z tristimulus value
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that a is between 0 and 1 and that x, y and z are only negative for
+    /// colors outside of the spectral locus
+    pub unsafe fn new_unsafe(x: f32, y: f32, z: f32, a: f32) -> Self {
+        return Self {
+            x: x,
+            y: y,
+            z: z,
+            a: a,
+        };
+    }
+
+    /// Retrieves the X tristimulus value of the color
+    pub fn get_x(&self) -> f32 {
+        return self.x;
+    }
+
+    /// Retrieves the Y tristimulus value of the color
+    pub fn get_y(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the Z tristimulus value of the color
+    pub fn get_z(&self) -> f32 {
+        return self.z;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: X, Y, Z,
+    /// alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.x, self.y, self.z, self.a];
+    }
+}
+
+impl Color for ColorXYZA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return *self;
+    }
+}
+
+impl ColorSpace for ColorXYZA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return *color;
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return color.get_xyza();
+    }
+}
+
+/// A struct for defining a single color in CIE xyY space relative to the D65
+/// reference white, the chromaticity coordinates are between 0 and 1, the
+/// luminance is non-negative and the alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorxyYA {
+    /// The x chromaticity coordinate
+    x: f32,
+    /// The y chromaticity coordinate
+    y: f32,
+    /// The luminance, the Y tristimulus value
+    l: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorxyYA {
+    /// Constructs a new xyya color, the chromaticity coordinates and alpha are
+    /// clamped to between 0 and 1 and the luminance is clamped to be
+    /// non-negative
+    ///
+    /// # Parameters
+    ///
+    /// x: The x chromaticity coordinate
+    ///
+    /// y: The y chromaticity coordinate
+    ///
+    /// l: The luminance
+    ///
+    /// a: The alpha component
+    pub fn new(x: f32, y: f32, l: f32, a: f32) -> Self {
+        return Self {
+            x: x.clamp(0.0, 1.0),
+            y: y.clamp(0.0, 1.0),
+            l: l.max(0.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new xyya color with the alpha component equal to 1, the
+    /// chromaticity coordinates are clamped to between 0 and 1 and the
+    /// luminance is clamped to be non-negative
+    ///
+    /// # Parameters
+    ///
+    /// x: The x chromaticity coordinate
+    ///
+    /// y: The y chromaticity coordinate
+    ///
+    /// l: The luminance
+    pub fn new_xyy(x: f32, y: f32, l: f32) -> Self {
+        return Self::new(x, y, l, 1.0);
+    }
+
+    /// Constructs a new xyya color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// x: The x chromaticity coordinate
+    ///
+    /// y: The y chromaticity coordinate
+    ///
+    /// l: The luminance
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that x, y and a are between 0 and 1 and l is non-negative
+    pub unsafe fn new_unsafe(x: f32, y: f32, l: f32, a: f32) -> Self {
+        return Self {
+            x: x,
+            y: y,
+            l: l,
+            a: a,
+        };
+    }
+
+    /// Retrieves the x chromaticity coordinate of the color
+    pub fn get_x(&self) -> f32 {
+        return self.x;
+    }
+
+    /// Retrieves the y chromaticity coordinate of the color
+    pub fn get_y(&self) -> f32 {
+        return self.y;
+    }
+
+    /// Retrieves the luminance (the Y tristimulus value) of the color
+    pub fn get_luminance(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: x, y,
+    /// luminance, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.x, self.y, self.l, self.a];
+    }
+}
+
+impl Color for ColorxyYA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::xyy_to_xyz(self);
+    }
+}
+
+impl ColorSpace for ColorxyYA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_xyy(color);
+    }
+}
+
+/// A reference white given by its XYZ tristimulus values normalized such that
+/// the Y value is 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct WhitePoint {
+    /// The X tristimulus value
+    x: f32,
+    /// The Z tristimulus value
+    z: f32,
+}
+
+impl WhitePoint {
+    /// CIE standard illuminant A, incandescent light at 2856 K
+    pub const A: Self = Self {
+        x: 1.09850,
+        z: 0.35585,
+    };
+    /// CIE standard illuminant C, average daylight
+    pub const C: Self = Self {
+        x: 0.98074,
+        z: 1.18232,
+    };
+    /// CIE standard illuminant D50, horizon daylight
+    pub const D50: Self = Self {
+        x: 0.96422,
+        z: 0.82521,
+    };
+    /// CIE standard illuminant D55, mid-morning daylight
+    pub const D55: Self = Self {
+        x: 0.95682,
+        z: 0.92149,
+    };
+    /// CIE standard illuminant D65, noon daylight and the reference white of
+    /// sRGB
+    pub const D65: Self = Self {
+        x: 0.95047,
+        z: 1.08883,
+    };
+    /// CIE standard illuminant D75, north sky daylight
+    pub const D75: Self = Self {
+        x: 0.94972,
+        z: 1.22638,
+    };
+    /// CIE standard illuminant E, the equal energy illuminant
+    pub const E: Self = Self { x: 1.0, z: 1.0 };
+    /// The DCI theatrical projector white used by DCI-P3
+    pub const DCI: Self = Self {
+        x: 0.89459,
+        z: 0.95442,
+    };
+    /// The ACES white point used by the ACES color spaces, it is close to but
+    /// not exactly D60
+    pub const ACES: Self = Self {
+        x: 0.95265,
+        z: 1.00883,
+    };
+
+    /// Constructs a new white point from its tristimulus values, the values
+    /// are clamped to be non-negative
+    ///
+    /// # Parameters
+    ///
+    /// x: The X tristimulus value relative to a Y value of 1
+    ///
+    /// z: The Z tristimulus value relative to a Y value of 1
+    pub fn new(x: f32, z: f32) -> Self {
+        return Self {
+            x: x.max(0.0),
+            z: z.max(0.0),
+        };
+    }
+
+    /// Constructs a new white point from its xy chromaticity coordinates, the
+    /// coordinates are clamped to between 0 and 1 and y must be larger than 0
+    ///
+    /// # Parameters
+    ///
+    /// x: The x chromaticity coordinate
+    ///
+    /// y: The y chromaticity coordinate
+    pub fn from_chromaticity(x: f32, y: f32) -> Self {
+        let x = x.clamp(0.0, 1.0);
+        let y = y.clamp(f32::EPSILON, 1.0);
+
+        return Self {
+            x: x / y,
+            z: (1.0 - x - y) / y,
+        };
+    }
+
+    /// Retrieves the X tristimulus value of the white point
+    pub fn get_x(&self) -> f32 {
+        return self.x;
+    }
+
+    /// Retrieves the Z tristimulus value of the white point
+    pub fn get_z(&self) -> f32 {
+        return self.z;
+    }
+
+    /// Retrieves the xy chromaticity coordinates of the white point
+    pub fn get_chromaticity(&self) -> [f32; 2] {
+        let sum = self.x + 1.0 + self.z;
+
+        return [self.x / sum, 1.0 / sum];
+    }
+
+    /// Retrieves all the tristimulus values in an array in the order: X, Y, Z
+    pub fn get(&self) -> [f32; 3] {
+        return [self.x, 1.0, self.z];
+    }
+}
+
+/// A transfer function converting between linear light and the encoded
+/// components of an RGB color space
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum TransferFunction {
+    /// The components are linear light
+    Linear,
+    /// The piecewise sRGB transfer function, also used by Display P3
+    SRGB,
+    /// A pure power law with the given gamma, the encoded value is the linear
+    /// value raised to the power of 1/gamma
+    Gamma(f32),
+    /// The ITU-R BT.709 transfer function, also used by BT.2020
+    Rec709,
+    /// The ROMM RGB transfer function used by ProPhoto RGB
+    ProPhoto,
+    /// The pure logarithmic ACEScc encoding
+    ACEScc,
+    /// The ACEScct encoding, this is ACEScc with a linear toe near black
+    ACEScct,
+    /// The Sony S-Log3 camera log encoding
+    SLog3,
+    /// The ARRI LogC3 camera log encoding at an exposure index of 800
+    LogC3,
+    /// The ARRI LogC4 camera log encoding
+    LogC4,
+    /// The Panasonic V-Log camera log encoding
+    VLog,
+    /// The Canon Log 3 camera log encoding, the linear value is the scene
+    /// reflectance and the encoded value is the full range code value
+    CanonLog3,
+    /// The SMPTE ST 2084 perceptual quantizer, a linear value of 1 is the
+    /// reference white luminance
+    PQ,
+    /// The ARIB STD-B67 hybrid log-gamma OETF, a linear value of 1 is the scene
+    /// light which is encoded at the reference white signal level
+    HLG,
+}
+
+/// The largest value of a half float, used as the upper limit of the ACES log
+/// encodings
+const ACES_HALF_MAX: f32 = 65504.0;
+
+/// The encoded ACEScct value at which the linear toe ends
+#[allow(clippy::excessive_precision)]
+const ACESCCT_Y_BREAK: f32 = 0.155251141552511;
+
+/// The slope of the linear toe of ACEScct
+#[allow(clippy::excessive_precision)]
+const ACESCCT_A: f32 = 10.5402377416545;
+
+/// The offset of the linear toe of ACEScct
+#[allow(clippy::excessive_precision)]
+const ACESCCT_B: f32 = 0.0729055341958355;
+
+/// The scale of the linear value inside the logarithm of LogC4
+#[allow(clippy::excessive_precision)]
+const LOGC4_A: f32 = 2231.82630906;
+
+/// The scale of the encoded value of LogC4
+#[allow(clippy::excessive_precision)]
+const LOGC4_B: f32 = 0.90713587487781;
+
+/// The offset of the encoded value of LogC4
+#[allow(clippy::excessive_precision)]
+const LOGC4_C: f32 = 0.09286412512219;
+
+/// The slope of the linear toe of LogC4
+#[allow(clippy::excessive_precision)]
+const LOGC4_S: f32 = 0.11359720861058;
+
+/// The linear value at which the linear toe of LogC4 ends
+#[allow(clippy::excessive_precision)]
+const LOGC4_T: f32 = -0.01805699611992;
+
+impl TransferFunction {
+    /// Converts a linear light component to its encoded value
+    ///
+    /// # Parameters
+    ///
+    /// value: The linear light component to encode
+    #[allow(clippy::excessive_precision)]
+    pub fn encode(&self, value: f32) -> f32 {
+        return match self {
+            Self::Linear => value,
+            Self::SRGB => utils::linear_to_srgb(value),
+            Self::Gamma(gamma) => value.max(0.0).powf(1.0 / gamma),
+            Self::Rec709 => {
+                if value < 0.018 {
+                    4.5 * value
+                } else {
+                    1.099 * value.powf(0.45) - 0.099
+                }
+            }
+            Self::ProPhoto => {
+                if value < 1.0 / 512.0 {
+                    16.0 * value
+                } else {
+                    value.powf(1.0 / 1.8)
+                }
+            }
+            Self::ACEScc => {
+                if value <= 0.0 {
+                    (-16.0 + 9.72) / 17.52
+                } else if value < 2.0_f32.powi(-15) {
+                    ((2.0_f32.powi(-16) + 0.5 * value).log2() + 9.72) / 17.52
+                } else {
+                    (value.log2() + 9.72) / 17.52
+                }
+            }
+            Self::ACEScct => {
+                if value <= 0.0078125 {
+                    ACESCCT_A * value + ACESCCT_B
+                } else {
+                    (value.log2() + 9.72) / 17.52
+                }
+            }
+            Self::SLog3 => {
+                if value >= 0.01125 {
+                    (420.0 + ((value + 0.01) / 0.19).log10() * 261.5) / 1023.0
+                } else {
+                    (value * (171.2102946929 - 95.0) / 0.01125 + 95.0) / 1023.0
+                }
+            }
+            Self::LogC3 => {
+                if value > 0.010591 {
+                    0.24719 * (5.555556 * value + 0.052272).log10() + 0.385537
+                } else {
+                    5.367655 * value + 0.092809
+                }
+            }
+            Self::LogC4 => {
+                if value >= LOGC4_T {
+                    ((LOGC4_A * value + 64.0).log2() - 6.0) / 14.0 * LOGC4_B + LOGC4_C
+                } else {
+                    (value - LOGC4_T) / LOGC4_S
+                }
+            }
+            Self::VLog => {
+                if value < 0.01 {
+                    5.6 * value + 0.125
+                } else {
+                    0.241514 * (value + 0.00873).log10() + 0.598206
+                }
+            }
+            Self::CanonLog3 => {
+                let value = value / 0.9;
+                let ire = if value < -0.014 {
+                    -0.36726845 * (1.0 - 14.98325 * value).log10() + 0.12783901
+                } else if value <= 0.014 {
+                    1.9754798 * value + 0.12512219
+                } else {
+                    0.36726845 * (1.0 + 14.98325 * value).log10() + 0.12240537
+                };
+
+                (64.0 + 876.0 * ire) / 1023.0
+            }
+            Self::PQ => utils::pq_encode(value * utils::REFERENCE_WHITE_LUMINANCE),
+            Self::HLG => utils::hlg_encode(value * utils::HLG_REFERENCE_SCENE_LIGHT),
+        };
+    }
+
+    /// Converts an encoded component to linear light
+    ///
+    /// # Parameters
+    ///
+    /// value: The encoded component to decode
+    #[allow(clippy::excessive_precision)]
+    pub fn decode(&self, value: f32) -> f32 {
+        return match self {
+            Self::Linear => value,
+            Self::SRGB => utils::srgb_to_linear(value),
+            Self::Gamma(gamma) => value.max(0.0).powf(*gamma),
+            Self::Rec709 => {
+                if value < 0.081 {
+                    value / 4.5
+                } else {
+                    ((value + 0.099) / 1.099).powf(1.0 / 0.45)
+                }
+            }
+            Self::ProPhoto => {
+                if value < 16.0 / 512.0 {
+                    value / 16.0
+                } else {
+                    value.powf(1.8)
+                }
+            }
+            Self::ACEScc => {
+                if value < (9.72 - 15.0) / 17.52 {
+                    (2.0_f32.powf(value * 17.52 - 9.72) - 2.0_f32.powi(-16)) * 2.0
+                } else {
+                    2.0_f32.powf(value * 17.52 - 9.72).min(ACES_HALF_MAX)
+                }
+            }
+            Self::ACEScct => {
+                if value <= ACESCCT_Y_BREAK {
+                    (value - ACESCCT_B) / ACESCCT_A
+                } else {
+                    2.0_f32.powf(value * 17.52 - 9.72).min(ACES_HALF_MAX)
+                }
+            }
+            Self::SLog3 => {
+                if value >= 171.2102946929 / 1023.0 {
+                    10.0_f32.powf((value * 1023.0 - 420.0) / 261.5) * 0.19 - 0.01
+                } else {
+                    (value * 1023.0 - 95.0) * 0.01125 / (171.2102946929 - 95.0)
+                }
+            }
+            Self::LogC3 => {
+                if value > 0.149658 {
+                    (10.0_f32.powf((value - 0.385537) / 0.24719) - 0.052272) / 5.555556
+                } else {
+                    (value - 0.092809) / 5.367655
+                }
+            }
+            Self::LogC4 => {
+                if value >= 0.0 {
+                    (2.0_f32.powf(14.0 * (value - LOGC4_C) / LOGC4_B + 6.0) - 64.0) / LOGC4_A
+                } else {
+                    value * LOGC4_S + LOGC4_T
+                }
+            }
+            Self::VLog => {
+                if value < 0.181 {
+                    (value - 0.125) / 5.6
+                } else {
+                    10.0_f32.powf((value - 0.598206) / 0.241514) - 0.00873
+                }
+            }
+            Self::CanonLog3 => {
+                let ire = (value * 1023.0 - 64.0) / 876.0;
+                let value = if ire < 0.097465473 {
+                    -(10.0_f32.powf((0.12783901 - ire) / 0.36726845) - 1.0) / 14.98325
+                } else if ire <= 0.15277891 {
+                    (ire - 0.12512219) / 1.9754798
+                } else {
+                    (10.0_f32.powf((ire - 0.12240537) / 0.36726845) - 1.0) / 14.98325
+                };
+
+                0.9 * value
+            }
+            Self::PQ => utils::pq_decode(value) / utils::REFERENCE_WHITE_LUMINANCE,
+            Self::HLG => utils::hlg_decode(value) / utils::HLG_REFERENCE_SCENE_LIGHT,
+        };
+    }
+}
+
+/// A description of an RGB color space given by the xy chromaticities of its
+/// primaries, its white point and its transfer function, display referred
+/// spaces have linear components between 0 and 1 while scene referred spaces
+/// have non-negative linear components without an upper limit
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct RgbSpace {
+    /// The xy chromaticity of the red primary
+    red: [f32; 2],
+    /// The xy chromaticity of the green primary
+    green: [f32; 2],
+    /// The xy chromaticity of the blue primary
+    blue: [f32; 2],
+    /// The white point of the space
+    white: WhitePoint,
+    /// The transfer function of the space
+    transfer: TransferFunction,
+    /// True if the linear components of the space are unbounded above
+    scene_referred: bool,
+}
+
+impl RgbSpace {
+    /// The sRGB color space, this is the space of ColorRGBA
+    pub const SRGB: Self = Self {
+        red: [0.64, 0.33],
+        green: [0.30, 0.60],
+        blue: [0.15, 0.06],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::SRGB,
+        scene_referred: false,
+    };
+    /// The Display P3 color space using the DCI-P3 primaries with a D65 white
+    /// point and the sRGB transfer function
+    pub const DISPLAY_P3: Self = Self {
+        red: [0.680, 0.320],
+        green: [0.265, 0.690],
+        blue: [0.150, 0.060],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::SRGB,
+        scene_referred: false,
+    };
+    /// The Adobe RGB (1998) color space
+    pub const ADOBE_RGB: Self = Self {
+        red: [0.64, 0.33],
+        green: [0.21, 0.71],
+        blue: [0.15, 0.06],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Gamma(563.0 / 256.0),
+        scene_referred: false,
+    };
+    /// The ITU-R BT.2020 color space for ultra high definition video
+    pub const REC2020: Self = Self {
+        red: [0.708, 0.292],
+        green: [0.170, 0.797],
+        blue: [0.131, 0.046],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Rec709,
+        scene_referred: false,
+    };
+    /// The ProPhoto RGB (ROMM RGB) color space
+    pub const PROPHOTO_RGB: Self = Self {
+        red: [0.7347, 0.2653],
+        green: [0.1596, 0.8404],
+        blue: [0.0366, 0.0001],
+        white: WhitePoint::D50,
+        transfer: TransferFunction::ProPhoto,
+        scene_referred: false,
+    };
+    /// The DCI-P3 color space for digital cinema projection with the DCI white
+    /// point and a gamma of 2.6
+    pub const DCI_P3: Self = Self {
+        red: [0.680, 0.320],
+        green: [0.265, 0.690],
+        blue: [0.150, 0.060],
+        white: WhitePoint::DCI,
+        transfer: TransferFunction::Gamma(2.6),
+        scene_referred: false,
+    };
+    /// The ACES2065-1 interchange space using the AP0 primaries
+    pub const ACES2065_1: Self = Self {
+        red: [0.7347, 0.2653],
+        green: [0.0, 1.0],
+        blue: [0.0001, -0.0770],
+        white: WhitePoint::ACES,
+        transfer: TransferFunction::Linear,
+        scene_referred: true,
+    };
+    /// The ACEScg working space using the AP1 primaries
+    pub const ACESCG: Self = Self {
+        red: [0.713, 0.293],
+        green: [0.165, 0.830],
+        blue: [0.128, 0.044],
+        white: WhitePoint::ACES,
+        transfer: TransferFunction::Linear,
+        scene_referred: true,
+    };
+    /// The ACEScc grading space using the AP1 primaries and a logarithmic
+    /// encoding
+    pub const ACESCC: Self = Self {
+        red: [0.713, 0.293],
+        green: [0.165, 0.830],
+        blue: [0.128, 0.044],
+        white: WhitePoint::ACES,
+        transfer: TransferFunction::ACEScc,
+        scene_referred: true,
+    };
+    /// The ACEScct grading space using the AP1 primaries and a logarithmic
+    /// encoding with a linear toe
+    pub const ACESCCT: Self = Self {
+        red: [0.713, 0.293],
+        green: [0.165, 0.830],
+        blue: [0.128, 0.044],
+        white: WhitePoint::ACES,
+        transfer: TransferFunction::ACEScct,
+        scene_referred: true,
+    };
+    /// The ITU-R BT.2100 space with the BT.2020 primaries and the PQ encoding
+    /// as used by HDR10
+    pub const REC2100_PQ: Self = Self {
+        red: [0.708, 0.292],
+        green: [0.170, 0.797],
+        blue: [0.131, 0.046],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::PQ,
+        scene_referred: true,
+    };
+    /// The ITU-R BT.2100 space with the BT.2020 primaries and the HLG encoding
+    pub const REC2100_HLG: Self = Self {
+        red: [0.708, 0.292],
+        green: [0.170, 0.797],
+        blue: [0.131, 0.046],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::HLG,
+        scene_referred: true,
+    };
+    /// The Sony S-Gamut3 gamut with the S-Log3 encoding
+    pub const SGAMUT3_SLOG3: Self = Self {
+        red: [0.730, 0.280],
+        green: [0.140, 0.855],
+        blue: [0.100, -0.050],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::SLog3,
+        scene_referred: true,
+    };
+    /// The ARRI Wide Gamut 3 gamut with the LogC3 encoding
+    pub const AWG3_LOGC3: Self = Self {
+        red: [0.6840, 0.3130],
+        green: [0.2210, 0.8480],
+        blue: [0.0861, -0.1020],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::LogC3,
+        scene_referred: true,
+    };
+    /// The ARRI Wide Gamut 4 gamut with the LogC4 encoding
+    pub const AWG4_LOGC4: Self = Self {
+        red: [0.7347, 0.2653],
+        green: [0.1424, 0.8576],
+        blue: [0.0991, -0.0308],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::LogC4,
+        scene_referred: true,
+    };
+    /// The Panasonic V-Gamut gamut with the V-Log encoding
+    pub const VGAMUT_VLOG: Self = Self {
+        red: [0.730, 0.280],
+        green: [0.165, 0.840],
+        blue: [0.100, -0.030],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::VLog,
+        scene_referred: true,
+    };
+    /// The Canon Cinema Gamut gamut with the Canon Log 3 encoding
+    pub const CINEMA_GAMUT_CLOG3: Self = Self {
+        red: [0.740, 0.270],
+        green: [0.170, 1.140],
+        blue: [0.080, -0.100],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::CanonLog3,
+        scene_referred: true,
+    };
+
+    /// Constructs a new display referred RGB color space
+    ///
+    /// # Parameters
+    ///
+    /// red: The xy chromaticity of the red primary
+    ///
+    /// green: The xy chromaticity of the green primary
+    ///
+    /// blue: The xy chromaticity of the blue primary
+    ///
+    /// white: The white point of the space
+    ///
+    /// transfer: The transfer function of the space
+    pub fn new(
+        red: [f32; 2],
+        green: [f32; 2],
+        blue: [f32; 2],
+        white: WhitePoint,
+        transfer: TransferFunction,
+    ) -> Self {
+        return Self {
+            red: red,
+            green: green,
+            blue: blue,
+            white: white,
+            transfer: transfer,
+            scene_referred: false,
+        };
+    }
+
+    /// Constructs a new scene referred RGB color space
+    ///
+    /// # Parameters
+    ///
+    /// red: The xy chromaticity of the red primary
+    ///
+    /// green: The xy chromaticity of the green primary
+    ///
+    /// blue: The xy chromaticity of the blue primary
+    ///
+    /// white: The white point of the space
+    ///
+    /// transfer: The transfer function of the space
+    pub fn new_scene_referred(
+        red: [f32; 2],
+        green: [f32; 2],
+        blue: [f32; 2],
+        white: WhitePoint,
+        transfer: TransferFunction,
+    ) -> Self {
+        return Self {
+            red: red,
+            green: green,
+            blue: blue,
+            white: white,
+            transfer: transfer,
+            scene_referred: true,
+        };
+    }
+
+    /// Retrieves the xy chromaticity of the red primary
+    pub fn get_red_chromaticity(&self) -> [f32; 2] {
+        return self.red;
+    }
+
+    /// Retrieves the xy chromaticity of the green primary
+    pub fn get_green_chromaticity(&self) -> [f32; 2] {
+        return self.green;
+    }
+
+    /// Retrieves the xy chromaticity of the blue primary
+    pub fn get_blue_chromaticity(&self) -> [f32; 2] {
+        return self.blue;
+    }
+
+    /// Retrieves the white point of the space
+    pub fn get_white_point(&self) -> WhitePoint {
+        return self.white;
+    }
+
+    /// Retrieves the transfer function of the space
+    pub fn get_transfer_function(&self) -> TransferFunction {
+        return self.transfer;
+    }
+
+    /// Checks if the space is scene referred
+    pub fn is_scene_referred(&self) -> bool {
+        return self.scene_referred;
+    }
+
+    /// Clamps a linear light component to the valid range of the space
+    ///
+    /// # Parameters
+    ///
+    /// value: The linear light component to clamp
+    pub fn clamp_linear(&self, value: f32) -> f32 {
+        return if self.scene_referred {
+            value.max(0.0)
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+    }
+
+    /// Clamps an encoded component to the valid range of the space
+    ///
+    /// # Parameters
+    ///
+    /// value: The encoded component to clamp
+    pub fn clamp_encoded(&self, value: f32) -> f32 {
+        return if self.scene_referred {
+            value.max(self.transfer.encode(0.0))
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+    }
+}
+
+/// A struct for defining a single color in an arbitrary RGB color space, the
+/// encoded components are between 0 and 1 for display referred spaces and
+/// correspond to non-negative linear values for scene referred spaces, alpha
+/// is between 0 and 1, conversions chromatically adapt between the white point
+/// of the space and D65 using the Bradford transform
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorSpaceRGBA {
+    /// The red component
+    r: f32,
+    /// The green component
+    g: f32,
+    /// The blue component
+    b: f32,
+    /// The alpha component
+    a: f32,
+    /// The RGB color space of the color
+    space: RgbSpace,
+}
+
+impl ColorSpaceRGBA {
+    /// Constructs a new space rgba color, the components are clamped to the
+    /// valid range of the space and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// g: The green component
+    ///
+    /// b: The blue component
+    ///
+    /// a: The alpha component
+    ///
+    /// space: The RGB color space of the color
+    pub fn new(r: f32, g: f32, b: f32, a: f32, space: RgbSpace) -> Self {
+        return Self {
+            r: space.clamp_encoded(r),
+            g: space.clamp_encoded(g),
+            b: space.clamp_encoded(b),
+            a: a.clamp(0.0, 1.0),
+            space: space,
+        };
+    }
+
+    /// Constructs a new space rgba color with the alpha component equal to 1,
+    /// the components are clamped to the valid range of the space
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// g: The green component
+    ///
+    /// b: The blue component
+    ///
+    /// space: The RGB color space of the color
+    pub fn new_rgb(r: f32, g: f32, b: f32, space: RgbSpace) -> Self {
+        return Self::new(r, g, b, 1.0, space);
+    }
+
+    /// Constructs a new space rgba color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// r: The red component
+    ///
+    /// g: The green component
+    ///
+    /// b: The blue component
+    ///
+    /// a: The alpha component
+    ///
+    /// space: The RGB color space of the color
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that r, g and b are inside the encoded range of the space and a is
+    /// between 0 and 1
+    pub unsafe fn new_unsafe(r: f32, g: f32, b: f32, a: f32, space: RgbSpace) -> Self {
+        return Self {
+            r: r,
+            g: g,
+            b: b,
+            a: a,
+            space: space,
+        };
+    }
+
+    /// Retrieves the red component of the color
+    pub fn get_red(&self) -> f32 {
+        return self.r;
+    }
+
+    /// Retrieves the green component of the color
+    pub fn get_green(&self) -> f32 {
+        return self.g;
+    }
+
+    /// Retrieves the blue component of the color
+    pub fn get_blue(&self) -> f32 {
+        return self.b;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the RGB color space of the color
+    pub fn get_space(&self) -> RgbSpace {
+        return self.space;
+    }
+
+    /// Retrieves all the color components in an array in the order: red, green,
+    /// blue, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.r, self.g, self.b, self.a];
+    }
+}
+
+impl Color for ColorSpaceRGBA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::space_rgb_to_xyz(self);
+    }
+}
+
+/// Converts from XYZ to sRGB
+impl ColorSpace for ColorSpaceRGBA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_space_rgb(color, RgbSpace::SRGB);
+    }
+}
+
+/// A struct for defining a single color in CIE L*a*b* space relative to a
+/// given white point, the lightness is between 0 and 100, the a and b
+/// components are unbounded and the alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorLabA {
+    /// The lightness component
+    l: f32,
+    /// The green-red component
+    a: f32,
+    /// The blue-yellow component
+    b: f32,
+    /// The alpha component
+    alpha: f32,
+    /// The reference white of the color
+    white: WhitePoint,
+}
+
+impl ColorLabA {
+    /// Constructs a new laba color, the lightness is clamped to between 0 and
+    /// 100 and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// alpha: The alpha component
+    ///
+    /// white: The reference white
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l.clamp(0.0, 100.0),
+            a: a,
+            b: b,
+            alpha: alpha.clamp(0.0, 1.0),
+            white: white,
+        };
+    }
+
+    /// Constructs a new laba color with the alpha component equal to 1, the
+    /// lightness is clamped to between 0 and 100
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// white: The reference white
+    pub fn new_lab(l: f32, a: f32, b: f32, white: WhitePoint) -> Self {
+        return Self::new(l, a, b, 1.0, white);
+    }
+
+    /// Constructs a new laba color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// alpha: The alpha component
+    ///
+    /// white: The reference white
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that l is between 0 and 100 and alpha is between 0 and 1
+    pub unsafe fn new_unsafe(l: f32, a: f32, b: f32, alpha: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l,
+            a: a,
+            b: b,
+            alpha: alpha,
+            white: white,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the green-red component of the color
+    pub fn get_a(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the blue-yellow component of the color
+    pub fn get_b(&self) -> f32 {
+        return self.b;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.alpha;
+    }
+
+    /// Retrieves the reference white of the color
+    pub fn get_white_point(&self) -> WhitePoint {
+        return self.white;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// a, b, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.l, self.a, self.b, self.alpha];
+    }
+}
+
+impl Color for ColorLabA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::lab_to_xyz(self);
+    }
+}
+
+/// Converts from XYZ relative to D65
+impl ColorSpace for ColorLabA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_lab(color, WhitePoint::D65);
+    }
+}
+
+/// A struct for defining a single color in CIE LCh(ab) space, the cylindrical
+/// form of L*a*b*, relative to a given white point, the lightness is between 0
+/// and 100, the chroma is non-negative, the hue is between 0 and 1 and the
+/// alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorLChA {
+    /// The lightness component
+    l: f32,
+    /// The chroma component
+    c: f32,
+    /// The hue component
+    h: f32,
+    /// The alpha component
+    a: f32,
+    /// The reference white of the color
+    white: WhitePoint,
+}
+
+impl ColorLChA {
+    /// Constructs a new lcha color, the lightness is clamped to between 0 and
+    /// 100, the chroma is clamped to be non-negative, the hue is wrapped to
+    /// between 0 and 1 and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// a: The alpha component
+    ///
+    /// white: The reference white
+    pub fn new(l: f32, c: f32, h: f32, a: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l.clamp(0.0, 100.0),
+            c: c.max(0.0),
+            h: h.rem_euclid(1.0),
+            a: a.clamp(0.0, 1.0),
+            white: white,
+        };
+    }
+
+    /// Constructs a new lcha color with the alpha component equal to 1, the
+    /// lightness is clamped to between 0 and 100, the chroma is clamped to be
+    /// non-negative and the hue is wrapped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// white: The reference white
+    pub fn new_lch(l: f32, c: f32, h: f32, white: WhitePoint) -> Self {
+        return Self::new(l, c, h, 1.0, white);
+    }
+
+    /// Constructs a new lcha color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// a: The alpha component
+    ///
+    /// white: The reference white
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that l is between 0 and 100, c is non-negative, h is between 0 and 1
+    /// excluding 1 and a is between 0 and 1
+    pub unsafe fn new_unsafe(l: f32, c: f32, h: f32, a: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l,
+            c: c,
+            h: h,
+            a: a,
+            white: white,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the chroma component of the color
+    pub fn get_chroma(&self) -> f32 {
+        return self.c;
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the reference white of the color
+    pub fn get_white_point(&self) -> WhitePoint {
+        return self.white;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// chroma, hue, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.l, self.c, self.h, self.a];
+    }
+}
+
+impl Color for ColorLChA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::lab_to_xyz(&utils::lch_to_lab(self));
+    }
+}
+
+/// Converts from XYZ relative to D65
+impl ColorSpace for ColorLChA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::lab_to_lch(&utils::xyz_to_lab(color, WhitePoint::D65));
+    }
+}
+
+/// A struct for defining a single color in OKLab space, the lightness is
+/// between 0 and 1, the a and b components are unbounded and the alpha value is
+/// between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorOKLabA {
+    /// The lightness component
+    l: f32,
+    /// The green-red component
+    a: f32,
+    /// The blue-yellow component
+    b: f32,
+    /// The alpha component
+    alpha: f32,
+}
+
+impl ColorOKLabA {
+    /// Constructs a new oklaba color, the lightness and alpha are clamped to
+    /// between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// alpha: The alpha component
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        return Self {
+            l: l.clamp(0.0, 1.0),
+            a: a,
+            b: b,
+            alpha: alpha.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new oklaba color with the alpha component equal to 1, the
+    /// lightness is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    pub fn new_oklab(l: f32, a: f32, b: f32) -> Self {
+        return Self::new(l, a, b, 1.0);
+    }
+
+    /// Constructs a new oklaba color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// alpha: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that l and alpha are between 0 and 1
+    pub unsafe fn new_unsafe(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        return Self {
+            l: l,
+            a: a,
+            b: b,
+            alpha: alpha,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the green-red component of the color
+    pub fn get_a(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the blue-yellow component of the color
+    pub fn get_b(&self) -> f32 {
+        return self.b;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.alpha;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// green-red, blue-yellow, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.l, self.a, self.b, self.alpha];
+    }
+}
+
+impl Color for ColorOKLabA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::oklab_to_xyz(self);
+    }
+}
+
+impl ColorSpace for ColorOKLabA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_oklab(color);
+    }
+}
+
+/// A struct for defining a single color in OKLCh space, the cylindrical form of
+/// OKLab, the lightness is between 0 and 1, the chroma is non-negative, the hue
+/// is between 0 and 1 and the alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorOKLChA {
+    /// The lightness component
+    l: f32,
+    /// The chroma component
+    c: f32,
+    /// The hue component
+    h: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorOKLChA {
+    /// Constructs a new oklcha color, the lightness is clamped to between 0 and
+    /// 1, the chroma is clamped to be non-negative, the hue is wrapped to
+    /// between 0 and 1 and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// a: The alpha component
+    pub fn new(l: f32, c: f32, h: f32, a: f32) -> Self {
+        return Self {
+            l: l.clamp(0.0, 1.0),
+            c: c.max(0.0),
+            h: h.rem_euclid(1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new oklcha color with the alpha component equal to 1, the
+    /// lightness is clamped to between 0 and 1, the chroma is clamped to be
+    /// non-negative and the hue is wrapped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    pub fn new_oklch(l: f32, c: f32, h: f32) -> Self {
+        return Self::new(l, c, h, 1.0);
+    }
+
+    /// Constructs a new oklcha color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that l and a are between 0 and 1, c is non-negative and h is between 0
+    /// and 1 excluding 1
+    pub unsafe fn new_unsafe(l: f32, c: f32, h: f32, a: f32) -> Self {
+        return Self {
+            l: l,
+            c: c,
+            h: h,
+            a: a,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the chroma component of the color
+    pub fn get_chroma(&self) -> f32 {
+        return self.c;
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// chroma, hue, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.l, self.c, self.h, self.a];
+    }
+}
+
+impl Color for ColorOKLChA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::oklab_to_xyz(&utils::oklch_to_oklab(self));
+    }
+}
+
+impl ColorSpace for ColorOKLChA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::oklab_to_oklch(&utils::xyz_to_oklab(color));
+    }
+}
+
+/// A struct for defining a single color in Okhsv space, a hue, saturation and
+/// value representation built on OKLab such that the hue and value are
+/// perceptually consistent, all values are between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorOkHSVA {
+    /// The hue component
+    h: f32,
+    /// The saturation component
+    s: f32,
+    /// The value component
+    v: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorOkHSVA {
+    /// Constructs a new okhsva color, all values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// v: The value component
+    ///
+    /// a: The alpha component
+    pub fn new(h: f32, s: f32, v: f32, a: f32) -> Self {
+        return Self {
+            h: h.rem_euclid(1.0),
+            s: s.clamp(0.0, 1.0),
+            v: v.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new okhsva color with the alpha component equal to 1, all
+    /// values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// v: The value component
+    pub fn new_okhsv(h: f32, s: f32, v: f32) -> Self {
+        return Self::new(h, s, v, 1.0);
+    }
+
+    /// Constructs a new okhsva color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// v: The value component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1 and s, v and a are between 0 and 1
+    pub unsafe fn new_unsafe(h: f32, s: f32, v: f32, a: f32) -> Self {
+        return Self {
+            h: h,
+            s: s,
+            v: v,
+            a: a,
+        };
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the saturation component of the color
+    pub fn get_saturation(&self) -> f32 {
+        return self.s;
+    }
+
+    /// Retrieves the value component of the color
+    pub fn get_value(&self) -> f32 {
+        return self.v;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: hue,
+    /// saturation, value, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.h, self.s, self.v, self.a];
+    }
+}
+
+impl Color for ColorOkHSVA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::okhsv_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorOkHSVA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_okhsv(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_okhsv(&color.get_rgba());
+    }
+}
+
+/// A struct for defining a single color in Okhsl space, a hue, saturation and
+/// lightness representation built on OKLab such that the hue and lightness are
+/// perceptually consistent, all values are between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorOkHSLA {
+    /// The hue component
+    h: f32,
+    /// The saturation component
+    s: f32,
+    /// The lightness component
+    l: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorOkHSLA {
+    /// Constructs a new okhsla color, all values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The alpha component
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        return Self {
+            h: h.rem_euclid(1.0),
+            s: s.clamp(0.0, 1.0),
+            l: l.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new okhsla color with the alpha component equal to 1, all
+    /// values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// l: The lightness component
+    pub fn new_okhsl(h: f32, s: f32, l: f32) -> Self {
+        return Self::new(h, s, l, 1.0);
+    }
+
+    /// Constructs a new okhsla color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1 and s, l and a are between 0 and 1
+    pub unsafe fn new_unsafe(h: f32, s: f32, l: f32, a: f32) -> Self {
+        return Self {
+            h: h,
+            s: s,
+            l: l,
+            a: a,
+        };
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the saturation component of the color
+    pub fn get_saturation(&self) -> f32 {
+        return self.s;
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: hue,
+    /// saturation, lightness, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.h, self.s, self.l, self.a];
+    }
+}
+
+impl Color for ColorOkHSLA {
+    type Base = ColorRGBA;
+
+    fn get_base(&self) -> ColorRGBA {
+        return utils::okhsl_to_rgb(self);
+    }
+}
+
+impl ColorSpace for ColorOkHSLA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::rgb_to_okhsl(&utils::xyz_to_rgb(color));
+    }
+
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return utils::rgb_to_okhsl(&color.get_rgba());
+    }
+}
+
+/// A struct for defining a single color in CIE L*u*v* space relative to a given
+/// white point, the lightness is between 0 and 100, the u and v components are
+/// unbounded and the alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorLuvA {
+    /// The lightness component
+    l: f32,
+    /// The u component
+    u: f32,
+    /// The v component
+    v: f32,
+    /// The alpha component
+    a: f32,
+    /// The reference white of the color
+    white: WhitePoint,
+}
+
+impl ColorLuvA {
+    /// Constructs a new luva color, the lightness is clamped to between 0 and
+    /// 100 and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// u: The u component
+    ///
+    /// v: The v component
+    ///
+    /// a: The alpha component
+    ///
+    /// white: The reference white of the color
+    pub fn new(l: f32, u: f32, v: f32, a: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l.clamp(0.0, 100.0),
+            u: u,
+            v: v,
+            a: a.clamp(0.0, 1.0),
+            white: white,
+        };
+    }
+
+    /// Constructs a new luva color with the alpha component equal to 1, the
+    /// lightness is clamped to between 0 and 100
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// u: The u component
+    ///
+    /// v: The v component
+    ///
+    /// white: The reference white of the color
+    pub fn new_luv(l: f32, u: f32, v: f32, white: WhitePoint) -> Self {
+        return Self::new(l, u, v, 1.0, white);
+    }
+
+    /// Constructs a new luva color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// u: The u component
+    ///
+    /// v: The v component
+    ///
+    /// a: The alpha component
+    ///
+    /// white: The reference white of the color
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that l is between 0 and 100 and a is between 0 and 1
+    pub unsafe fn new_unsafe(l: f32, u: f32, v: f32, a: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l,
+            u: u,
+            v: v,
+            a: a,
+            white: white,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the u component of the color
+    pub fn get_u(&self) -> f32 {
+        return self.u;
+    }
+
+    /// Retrieves the v component of the color
+    pub fn get_v(&self) -> f32 {
+        return self.v;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the reference white of the color
+    pub fn get_white_point(&self) -> WhitePoint {
+        return self.white;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// u, v, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.l, self.u, self.v, self.a];
+    }
+}
+
+impl Color for ColorLuvA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::luv_to_xyz(self);
+    }
+}
+
+/// Converts from XYZ relative to D65
+impl ColorSpace for ColorLuvA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_luv(color, WhitePoint::D65);
+    }
+}
+
+/// A struct for defining a single color in CIE LCh(uv) space, the cylindrical
+/// form of L*u*v*, relative to a given white point, the lightness is between 0
+/// and 100, the chroma is non-negative, the hue is between 0 and 1 and the
+/// alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorLChuvA {
+    /// The lightness component
+    l: f32,
+    /// The chroma component
+    c: f32,
+    /// The hue component
+    h: f32,
+    /// The alpha component
+    a: f32,
+    /// The reference white of the color
+    white: WhitePoint,
+}
+
+impl ColorLChuvA {
+    /// Constructs a new lchuva color, the lightness is clamped to between 0 and
+    /// 100, the chroma is clamped to be non-negative, the hue is wrapped to
+    /// between 0 and 1 and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// a: The alpha component
+    ///
+    /// white: The reference white of the color
+    pub fn new(l: f32, c: f32, h: f32, a: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l.clamp(0.0, 100.0),
+            c: c.max(0.0),
+            h: h.rem_euclid(1.0),
+            a: a.clamp(0.0, 1.0),
+            white: white,
+        };
+    }
+
+    /// Constructs a new lchuva color with the alpha component equal to 1, the
+    /// lightness is clamped to between 0 and 100, the chroma is clamped to be
+    /// non-negative and the hue is wrapped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// white: The reference white of the color
+    pub fn new_lchuv(l: f32, c: f32, h: f32, white: WhitePoint) -> Self {
+        return Self::new(l, c, h, 1.0, white);
+    }
+
+    /// Constructs a new lchuva color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// l: The lightness component
+    ///
+    /// c: The chroma component
+    ///
+    /// h: The hue component
+    ///
+    /// a: The alpha component
+    ///
+    /// white: The reference white of the color
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that l is between 0 and 100, c is non-negative, h is between 0 and 1
+    /// excluding 1 and a is between 0 and 1
+    pub unsafe fn new_unsafe(l: f32, c: f32, h: f32, a: f32, white: WhitePoint) -> Self {
+        return Self {
+            l: l,
+            c: c,
+            h: h,
+            a: a,
+            white: white,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the chroma component of the color
+    pub fn get_chroma(&self) -> f32 {
+        return self.c;
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the reference white of the color
+    pub fn get_white_point(&self) -> WhitePoint {
+        return self.white;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// chroma, hue, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.l, self.c, self.h, self.a];
+    }
+}
+
+impl Color for ColorLChuvA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::luv_to_xyz(&utils::lchuv_to_luv(self));
+    }
+}
+
+/// Converts from XYZ relative to D65
+impl ColorSpace for ColorLChuvA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::luv_to_lchuv(&utils::xyz_to_luv(color, WhitePoint::D65));
+    }
+}
+
+/// A struct for defining a single color in HSLuv space, a remapping of LCh(uv)
+/// relative to D65 where a saturation of 1 is the most saturated color in the
+/// sRGB gamut for the given hue and lightness, all values are between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorHSLuvA {
+    /// The hue component
+    h: f32,
+    /// The saturation component
+    s: f32,
+    /// The lightness component
+    l: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorHSLuvA {
+    /// Constructs a new hsluva color, all values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The alpha component
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        return Self {
+            h: h.rem_euclid(1.0),
+            s: s.clamp(0.0, 1.0),
+            l: l.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new hsluva color with the alpha component equal to 1, all
+    /// values are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// l: The lightness component
+    pub fn new_hsluv(h: f32, s: f32, l: f32) -> Self {
+        return Self::new(h, s, l, 1.0);
+    }
+
+    /// Constructs a new hsluva color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// s: The saturation component
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1 and s, l and a are between 0 and 1
+    pub unsafe fn new_unsafe(h: f32, s: f32, l: f32, a: f32) -> Self {
+        return Self {
+            h: h,
+            s: s,
+            l: l,
+            a: a,
+        };
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the saturation component of the color
+    pub fn get_saturation(&self) -> f32 {
+        return self.s;
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: hue,
+    /// saturation, lightness, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.h, self.s, self.l, self.a];
+    }
+}
+
+impl Color for ColorHSLuvA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::luv_to_xyz(&utils::lchuv_to_luv(&utils::hsluv_to_lchuv(self)));
+    }
+}
+
+impl ColorSpace for ColorHSLuvA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::lchuv_to_hsluv(&utils::luv_to_lchuv(&utils::xyz_to_luv(
+            color,
+            WhitePoint::D65,
+        )));
+    }
+}
+
+/// A struct for defining a single color in HPLuv space, a remapping of LCh(uv)
+/// relative to D65 where a perceived saturation of 1 is the most saturated
+/// color in the sRGB gamut for the given lightness independent of hue, the hue,
+/// lightness and alpha values are between 0 and 1 while the perceived
+/// saturation is non-negative, values above 1 are outside the pastel range but
+/// may still be inside the sRGB gamut
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorHPLuvA {
+    /// The hue component
+    h: f32,
+    /// The perceived saturation component
+    p: f32,
+    /// The lightness component
+    l: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorHPLuvA {
+    /// Constructs a new hpluva color, the perceived saturation is clamped to be
+    /// non-negative, the hue is wrapped to between 0 and 1 and the lightness
+    /// and alpha are clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// p: The perceived saturation component
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The alpha component
+    pub fn new(h: f32, p: f32, l: f32, a: f32) -> Self {
+        return Self {
+            h: h.rem_euclid(1.0),
+            p: p.max(0.0),
+            l: l.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new hpluva color with the alpha component equal to 1, the
+    /// perceived saturation is clamped to be non-negative, the hue is wrapped
+    /// to between 0 and 1 and the lightness is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// p: The perceived saturation component
+    ///
+    /// l: The lightness component
+    pub fn new_hpluv(h: f32, p: f32, l: f32) -> Self {
+        return Self::new(h, p, l, 1.0);
+    }
+
+    /// Constructs a new hpluva color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue component
+    ///
+    /// p: The perceived saturation component
+    ///
+    /// l: The lightness component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1, p is non-negative and l and a are
+    /// between 0 and 1
+    pub unsafe fn new_unsafe(h: f32, p: f32, l: f32, a: f32) -> Self {
+        return Self {
+            h: h,
+            p: p,
+            l: l,
+            a: a,
+        };
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the perceived saturation component of the color
+    pub fn get_perceived_saturation(&self) -> f32 {
+        return self.p;
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.l;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: hue,
+    /// perceived saturation, lightness, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.h, self.p, self.l, self.a];
+    }
+}
+
+impl Color for ColorHPLuvA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::luv_to_xyz(&utils::lchuv_to_luv(&utils::hpluv_to_lchuv(self)));
+    }
+}
+
+impl ColorSpace for ColorHPLuvA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::lchuv_to_hpluv(&utils::luv_to_lchuv(&utils::xyz_to_luv(
+            color,
+            WhitePoint::D65,
+        )));
+    }
+}
+
+/// A struct for defining a single color in the ICtCp space of ITU-R BT.2100
+/// using the PQ encoding, the intensity is between 0 and 1, the chroma
+/// components are unbounded and the alpha value is between 0 and 1, a XYZ
+/// luminance of 1 corresponds to the reference white luminance
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorICtCpA {
+    /// The intensity component
+    i: f32,
+    /// The blue-yellow component
+    ct: f32,
+    /// The red-green component
+    cp: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorICtCpA {
+    /// Constructs a new ictcpa color, the intensity and alpha are clamped to
+    /// between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// i: The intensity component
+    ///
+    /// ct: The blue-yellow component
+    ///
+    /// cp: The red-green component
+    ///
+    /// a: The alpha component
+    pub fn new(i: f32, ct: f32, cp: f32, a: f32) -> Self {
+        return Self {
+            i: i.clamp(0.0, 1.0),
+            ct: ct,
+            cp: cp,
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new ictcpa color with the alpha component equal to 1, the
+    /// intensity is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// i: The intensity component
+    ///
+    /// ct: The blue-yellow component
+    ///
+    /// cp: The red-green component
+    pub fn new_ictcp(i: f32, ct: f32, cp: f32) -> Self {
+        return Self::new(i, ct, cp, 1.0);
+    }
+
+    /// Constructs a new ictcpa color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// i: The intensity component
+    ///
+    /// ct: The blue-yellow component
+    ///
+    /// cp: The red-green component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that i and a are between 0 and 1
+    pub unsafe fn new_unsafe(i: f32, ct: f32, cp: f32, a: f32) -> Self {
+        return Self {
+            i: i,
+            ct: ct,
+            cp: cp,
+            a: a,
+        };
+    }
+
+    /// Retrieves the intensity component of the color
+    pub fn get_intensity(&self) -> f32 {
+        return self.i;
+    }
+
+    /// Retrieves the blue-yellow component of the color
+    pub fn get_tritan(&self) -> f32 {
+        return self.ct;
+    }
+
+    /// Retrieves the red-green component of the color
+    pub fn get_protan(&self) -> f32 {
+        return self.cp;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: intensity,
+    /// blue-yellow, red-green, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.i, self.ct, self.cp, self.a];
+    }
+}
+
+impl Color for ColorICtCpA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::ictcp_to_xyz(self);
+    }
+}
+
+impl ColorSpace for ColorICtCpA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_ictcp(color);
+    }
+}
+
+/// A struct for defining a single color in the high dynamic range Jzazbz space,
+/// the lightness is non-negative, the a and b components are unbounded and the
+/// alpha value is between 0 and 1, the conversions from relative XYZ map a
+/// luminance of 1 to the reference white luminance while absolute XYZ values
+/// in cd/m² are converted with utils::xyz_nits_to_jzazbz
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorJzazbzA {
+    /// The lightness component
+    jz: f32,
+    /// The green-red component
+    az: f32,
+    /// The blue-yellow component
+    bz: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorJzazbzA {
+    /// Constructs a new jzazbza color, the lightness is clamped to be
+    /// non-negative and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// jz: The lightness component
+    ///
+    /// az: The green-red component
+    ///
+    /// bz: The blue-yellow component
+    ///
+    /// a: The alpha component
+    pub fn new(jz: f32, az: f32, bz: f32, a: f32) -> Self {
+        return Self {
+            jz: jz.max(0.0),
+            az: az,
+            bz: bz,
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new jzazbza color with the alpha component equal to 1, the
+    /// lightness is clamped to be non-negative
+    ///
+    /// # Parameters
+    ///
+    /// jz: The lightness component
+    ///
+    /// az: The green-red component
+    ///
+    /// bz: The blue-yellow component
+    pub fn new_jzazbz(jz: f32, az: f32, bz: f32) -> Self {
+        return Self::new(jz, az, bz, 1.0);
+    }
+
+    /// Constructs a new jzazbza color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// jz: The lightness component
+    ///
+    /// az: The green-red component
+    ///
+    /// bz: The blue-yellow component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that jz is non-negative and a is between 0 and 1
+    pub unsafe fn new_unsafe(jz: f32, az: f32, bz: f32, a: f32) -> Self {
+        return Self {
+            jz: jz,
+            az: az,
+            bz: bz,
+            a: a,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.jz;
+    }
+
+    /// Retrieves the green-red component of the color
+    pub fn get_a(&self) -> f32 {
+        return self.az;
+    }
+
+    /// Retrieves the blue-yellow component of the color
+    pub fn get_b(&self) -> f32 {
+        return self.bz;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// green-red, blue-yellow, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.jz, self.az, self.bz, self.a];
+    }
+}
+
+impl Color for ColorJzazbzA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::jzazbz_to_xyz(self);
+    }
+}
+
+impl ColorSpace for ColorJzazbzA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_jzazbz(color);
+    }
+}
+
+/// A struct for defining a single color in the JzCzhz space, the polar form of
+/// Jzazbz, the lightness and chroma are non-negative and the hue and alpha are
+/// between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorJzCzhzA {
+    /// The lightness component
+    jz: f32,
+    /// The chroma component
+    cz: f32,
+    /// The hue component
+    hz: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl ColorJzCzhzA {
+    /// Constructs a new jzczhza color, the lightness and chroma are clamped to
+    /// be non-negative, the hue is wrapped to between 0 and 1 and alpha is
+    /// clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// jz: The lightness component
+    ///
+    /// cz: The chroma component
+    ///
+    /// hz: The hue component
+    ///
+    /// a: The alpha component
+    pub fn new(jz: f32, cz: f32, hz: f32, a: f32) -> Self {
+        return Self {
+            jz: jz.max(0.0),
+            cz: cz.max(0.0),
+            hz: hz.rem_euclid(1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new jzczhza color with the alpha component equal to 1, the
+    /// lightness and chroma are clamped to be non-negative and the hue is
+    /// wrapped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// jz: The lightness component
+    ///
+    /// cz: The chroma component
+    ///
+    /// hz: The hue component
+    pub fn new_jzczhz(jz: f32, cz: f32, hz: f32) -> Self {
+        return Self::new(jz, cz, hz, 1.0);
+    }
+
+    /// Constructs a new jzczhza color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// jz: The lightness component
+    ///
+    /// cz: The chroma component
+    ///
+    /// hz: The hue component
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that jz and cz are non-negative, hz is between 0 and 1 excluding 1 and a
+    /// is between 0 and 1
+    pub unsafe fn new_unsafe(jz: f32, cz: f32, hz: f32, a: f32) -> Self {
+        return Self {
+            jz: jz,
+            cz: cz,
+            hz: hz,
+            a: a,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.jz;
+    }
+
+    /// Retrieves the chroma component of the color
+    pub fn get_chroma(&self) -> f32 {
+        return self.cz;
+    }
+
+    /// Retrieves the hue component of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.hz;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// chroma, hue, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.jz, self.cz, self.hz, self.a];
+    }
+}
+
+impl Color for ColorJzCzhzA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::jzazbz_to_xyz(&utils::jzczhz_to_jzazbz(self));
+    }
+}
+
+impl ColorSpace for ColorJzCzhzA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::jzazbz_to_jzczhz(&utils::xyz_to_jzazbz(color));
+    }
+}
+
+/// The surround of a viewing environment, describing the luminance of the
+/// area around the viewed field
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum Surround {
+    /// An average surround like viewing surface colors in a lit room
+    Average,
+    /// A dim surround like watching television in a dimmed room
+    Dim,
+    /// A dark surround like watching a projector in a dark room
+    Dark,
+}
+
+impl Surround {
+    /// Retrieves the surround parameters in the order: the degree of adaptation
+    /// factor F, the impact of surround c, the chromatic induction factor Nc
+    pub fn get_parameters(&self) -> [f32; 3] {
+        return match self {
+            Self::Average => [1.0, 0.69, 1.0],
+            Self::Dim => [0.9, 0.59, 0.9],
+            Self::Dark => [0.8, 0.525, 0.8],
+        };
+    }
+}
+
+/// The viewing conditions of a color appearance model
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ViewingConditions {
+    /// The adopted white of the observer
+    white: WhitePoint,
+    /// The luminance of the adapting field in nits
+    adapting_luminance: f32,
+    /// The relative luminance of the background in percent of the white
+    background_luminance: f32,
+    /// The surround of the viewing environment
+    surround: Surround,
+    /// True if the observer fully discounts the illuminant
+    discounting: bool,
+}
+
+impl ViewingConditions {
+    /// The reference viewing conditions of sRGB, a D65 display viewed in an
+    /// average surround with an ambient illuminance of 64 lux and a background
+    /// of 20% gray
+    pub const SRGB: Self = Self {
+        white: WhitePoint::D65,
+        adapting_luminance: 64.0 / std::f32::consts::PI * 0.2,
+        background_luminance: 20.0,
+        surround: Surround::Average,
+        discounting: false,
+    };
+
+    /// Constructs new viewing conditions, the adapting luminance is clamped to
+    /// be non-negative and the background luminance is clamped to between 0 and
+    /// 100 excluding 0
+    ///
+    /// # Parameters
+    ///
+    /// white: The adopted white of the observer
+    ///
+    /// adapting_luminance: The luminance of the adapting field in nits, this is
+    /// typically 20% of the luminance of a white object in the scene
+    ///
+    /// background_luminance: The relative luminance of the background in
+    /// percent of the white
+    ///
+    /// surround: The surround of the viewing environment
+    ///
+    /// discounting: True if the observer fully discounts the illuminant
+    pub fn new(
+        white: WhitePoint,
+        adapting_luminance: f32,
+        background_luminance: f32,
+        surround: Surround,
+        discounting: bool,
+    ) -> Self {
+        return Self {
+            white: white,
+            adapting_luminance: adapting_luminance.max(0.0),
+            background_luminance: background_luminance.clamp(f32::EPSILON, 100.0),
+            surround: surround,
+            discounting: discounting,
+        };
+    }
+
+    /// Retrieves the adopted white of the observer
+    pub fn get_white_point(&self) -> WhitePoint {
+        return self.white;
+    }
+
+    /// Retrieves the luminance of the adapting field in nits
+    pub fn get_adapting_luminance(&self) -> f32 {
+        return self.adapting_luminance;
+    }
+
+    /// Retrieves the relative luminance of the background in percent of the
+    /// white
+    pub fn get_background_luminance(&self) -> f32 {
+        return self.background_luminance;
+    }
+
+    /// Retrieves the surround of the viewing environment
+    pub fn get_surround(&self) -> Surround {
+        return self.surround;
+    }
+
+    /// Checks if the observer fully discounts the illuminant
+    pub fn is_discounting(&self) -> bool {
+        return self.discounting;
+    }
+}
+
+/// A struct for defining the appearance of a single color with the CAM16 color
+/// appearance model under given viewing conditions, the lightness and chroma
+/// are non-negative with a lightness of 100 for the white and the hue and alpha
+/// are between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorCAM16A {
+    /// The lightness correlate J
+    j: f32,
+    /// The chroma correlate C
+    c: f32,
+    /// The hue angle h
+    h: f32,
+    /// The alpha component
+    a: f32,
+    /// The viewing conditions of the color
+    conditions: ViewingConditions,
+}
+
+impl ColorCAM16A {
+    /// Constructs a new cam16a color, the lightness and chroma are clamped to
+    /// be non-negative, the hue is wrapped to between 0 and 1 and alpha is
+    /// clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// j: The lightness correlate J
+    ///
+    /// c: The chroma correlate C
+    ///
+    /// h: The hue angle h
+    ///
+    /// a: The alpha component
+    ///
+    /// conditions: The viewing conditions of the color
+    pub fn new(j: f32, c: f32, h: f32, a: f32, conditions: ViewingConditions) -> Self {
+        return Self {
+            j: j.max(0.0),
+            c: c.max(0.0),
+            h: h.rem_euclid(1.0),
+            a: a.clamp(0.0, 1.0),
+            conditions: conditions,
+        };
+    }
+
+    /// Constructs a new cam16a color with the alpha component equal to 1, the
+    /// lightness and chroma are clamped to be non-negative and the hue is
+    /// wrapped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// j: The lightness correlate J
+    ///
+    /// c: The chroma correlate C
+    ///
+    /// h: The hue angle h
+    ///
+    /// conditions: The viewing conditions of the color
+    pub fn new_cam16(j: f32, c: f32, h: f32, conditions: ViewingConditions) -> Self {
+        return Self::new(j, c, h, 1.0, conditions);
+    }
+
+    /// Constructs a new cam16a color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// j: The lightness correlate J
+    ///
+    /// c: The chroma correlate C
+    ///
+    /// h: The hue angle h
+    ///
+    /// a: The alpha component
+    ///
+    /// conditions: The viewing conditions of the color
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that j and c are non-negative, h is between 0 and 1 excluding 1 and a is
+    /// between 0 and 1
+    pub unsafe fn new_unsafe(
+        j: f32,
+        c: f32,
+        h: f32,
+        a: f32,
+        conditions: ViewingConditions,
+    ) -> Self {
+        return Self {
+            j: j,
+            c: c,
+            h: h,
+            a: a,
+            conditions: conditions,
+        };
+    }
+
+    /// Retrieves the lightness correlate J of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.j;
+    }
+
+    /// Retrieves the chroma correlate C of the color
+    pub fn get_chroma(&self) -> f32 {
+        return self.c;
+    }
+
+    /// Retrieves the hue angle h of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the viewing conditions of the color
+    pub fn get_viewing_conditions(&self) -> ViewingConditions {
+        return self.conditions;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness
+    /// correlate J, chroma correlate C, hue angle h, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.j, self.c, self.h, self.a];
+    }
+
+    /// Retrieves the brightness correlate Q of the color
+    pub fn get_brightness(&self) -> f32 {
+        return utils::cam16_brightness(self);
+    }
+
+    /// Retrieves the colorfulness correlate M of the color
+    pub fn get_colorfulness(&self) -> f32 {
+        return utils::cam16_colorfulness(self);
+    }
+
+    /// Retrieves the saturation correlate s of the color
+    pub fn get_saturation(&self) -> f32 {
+        return utils::cam16_saturation(self);
+    }
+}
+
+impl Color for ColorCAM16A {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::cam16_to_xyz(self);
+    }
+}
+
+/// Converts from XYZ under the sRGB viewing conditions
+impl ColorSpace for ColorCAM16A {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::xyz_to_cam16(color, ViewingConditions::SRGB);
+    }
+}
+
+/// A struct for defining a single color in the CAM16-UCS uniform color space
+/// under given viewing conditions, the lightness is non-negative, the a and b
+/// components are unbounded and the alpha value is between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorCAM16UCSA {
+    /// The lightness component
+    j: f32,
+    /// The green-red component
+    a: f32,
+    /// The blue-yellow component
+    b: f32,
+    /// The alpha component
+    alpha: f32,
+    /// The viewing conditions of the color
+    conditions: ViewingConditions,
+}
+
+impl ColorCAM16UCSA {
+    /// Constructs a new cam16 ucs color, the lightness is clamped to be
+    /// non-negative and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// j: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// alpha: The alpha component
+    ///
+    /// conditions: The viewing conditions of the color
+    pub fn new(j: f32, a: f32, b: f32, alpha: f32, conditions: ViewingConditions) -> Self {
+        return Self {
+            j: j.max(0.0),
+            a: a,
+            b: b,
+            alpha: alpha.clamp(0.0, 1.0),
+            conditions: conditions,
+        };
+    }
+
+    /// Constructs a new cam16 ucs color with the alpha component equal to 1,
+    /// the lightness is clamped to be non-negative
+    ///
+    /// # Parameters
+    ///
+    /// j: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// conditions: The viewing conditions of the color
+    pub fn new_cam16_ucs(j: f32, a: f32, b: f32, conditions: ViewingConditions) -> Self {
+        return Self::new(j, a, b, 1.0, conditions);
+    }
+
+    /// Constructs a new cam16 ucs color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// j: The lightness component
+    ///
+    /// a: The green-red component
+    ///
+    /// b: The blue-yellow component
+    ///
+    /// alpha: The alpha component
+    ///
+    /// conditions: The viewing conditions of the color
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that j is non-negative and alpha is between 0 and 1
+    pub unsafe fn new_unsafe(
+        j: f32,
+        a: f32,
+        b: f32,
+        alpha: f32,
+        conditions: ViewingConditions,
+    ) -> Self {
+        return Self {
+            j: j,
+            a: a,
+            b: b,
+            alpha: alpha,
+            conditions: conditions,
+        };
+    }
+
+    /// Retrieves the lightness component of the color
+    pub fn get_lightness(&self) -> f32 {
+        return self.j;
+    }
+
+    /// Retrieves the green-red component of the color
+    pub fn get_a(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves the blue-yellow component of the color
+    pub fn get_b(&self) -> f32 {
+        return self.b;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.alpha;
+    }
+
+    /// Retrieves the viewing conditions of the color
+    pub fn get_viewing_conditions(&self) -> ViewingConditions {
+        return self.conditions;
+    }
+
+    /// Retrieves all the color components in an array in the order: lightness,
+    /// green-red, blue-yellow, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.j, self.a, self.b, self.alpha];
+    }
+}
+
+impl Color for ColorCAM16UCSA {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return utils::cam16_to_xyz(&utils::cam16_ucs_to_cam16(self));
+    }
+}
+
+/// Converts from XYZ under the sRGB viewing conditions
+impl ColorSpace for ColorCAM16UCSA {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return utils::cam16_to_cam16_ucs(&utils::xyz_to_cam16(color, ViewingConditions::SRGB));
+    }
+}
+
+/// Applies an expression to the color stored in any variant of AnyColor
+macro_rules! any_color_apply {
+    ($value:expr, $color:ident => $body:expr) => {
+        match $value {
+            AnyColor::RGB($color) => $body,
+            AnyColor::LinearRGB($color) => $body,
+            AnyColor::HSV($color) => $body,
+            AnyColor::HSL($color) => $body,
+            AnyColor::HSI($color) => $body,
+            AnyColor::HWB($color) => $body,
+            AnyColor::RYB($color) => $body,
+            AnyColor::CMYK($color) => $body,
+            AnyColor::YCbCr($color) => $body,
+            AnyColor::YUV($color) => $body,
+            AnyColor::YIQ($color) => $body,
+            AnyColor::YCoCg($color) => $body,
+            AnyColor::YCoCgR($color) => $body,
+            AnyColor::XYZ($color) => $body,
+            AnyColor::xyY($color) => $body,
+            AnyColor::SpaceRGB($color) => $body,
+            AnyColor::Lab($color) => $body,
+            AnyColor::LCh($color) => $body,
+            AnyColor::OKLab($color) => $body,
+            AnyColor::OKLCh($color) => $body,
+            AnyColor::OkHSV($color) => $body,
+            AnyColor::OkHSL($color) => $body,
+            AnyColor::Luv($color) => $body,
+            AnyColor::LChuv($color) => $body,
+            AnyColor::HSLuv($color) => $body,
+            AnyColor::HPLuv($color) => $body,
+            AnyColor::ICtCp($color) => $body,
+            AnyColor::Jzazbz($color) => $body,
+            AnyColor::JzCzhz($color) => $body,
+            AnyColor::CAM16($color) => $body,
+            AnyColor::CAM16UCS($color) => $body,
+        }
+    };
+}
+
+/// The representations a color can be stored in by AnyColor
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum AnyColorType {
+    /// An sRGB representation
+    RGB,
+    /// A linear sRGB representation
+    LinearRGB,
+    /// A HSV representation
+    HSV,
+    /// A HSL representation
+    HSL,
+    /// A HSI representation
+    HSI,
+    /// A HWB representation
+    HWB,
+    /// A RYB representation
+    RYB,
+    /// A CMYK representation
+    CMYK,
+    /// A YCbCr representation
+    YCbCr,
+    /// A YUV representation
+    YUV,
+    /// A YIQ representation
+    YIQ,
+    /// A YCoCg representation
+    YCoCg,
+    /// A lossless YCoCg-R representation
+    YCoCgR,
+    /// A CIE XYZ representation
+    XYZ,
+    /// A CIE xyY representation
+    #[allow(non_camel_case_types)]
+    xyY,
+    /// A RGB representation in an arbitrary RGB space
+    SpaceRGB,
+    /// A CIELAB representation
+    Lab,
+    /// A CIELCh representation
+    LCh,
+    /// An OKLab representation
+    OKLab,
+    /// An OKLCh representation
+    OKLCh,
+    /// An OkHSV representation
+    OkHSV,
+    /// An OkHSL representation
+    OkHSL,
+    /// A CIELUV representation
+    Luv,
+    /// A CIELCh(uv) representation
+    LChuv,
+    /// A HSLuv representation
+    HSLuv,
+    /// A HPLuv representation
+    HPLuv,
+    /// An ICtCp representation
+    ICtCp,
+    /// A Jzazbz representation
+    Jzazbz,
+    /// A JzCzhz representation
+    JzCzhz,
+    /// A CAM16 representation
+    CAM16,
+    /// A CAM16-UCS representation
+    CAM16UCS,
+}
+
+impl AnyColorType {
+    /// All representations in the order they are declared
+    pub const ALL: [Self; 31] = [
+        Self::RGB,
+        Self::LinearRGB,
+        Self::HSV,
+        Self::HSL,
+        Self::HSI,
+        Self::HWB,
+        Self::RYB,
+        Self::CMYK,
+        Self::YCbCr,
+        Self::YUV,
+        Self::YIQ,
+        Self::YCoCg,
+        Self::YCoCgR,
+        Self::XYZ,
+        Self::xyY,
+        Self::SpaceRGB,
+        Self::Lab,
+        Self::LCh,
+        Self::OKLab,
+        Self::OKLCh,
+        Self::OkHSV,
+        Self::OkHSL,
+        Self::Luv,
+        Self::LChuv,
+        Self::HSLuv,
+        Self::HPLuv,
+        Self::ICtCp,
+        Self::Jzazbz,
+        Self::JzCzhz,
+        Self::CAM16,
+        Self::CAM16UCS,
+    ];
+
+    /// Retrieves the representation with the given name, the name is case
+    /// insensitive, None is returned if the name is unknown
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the representation as reported by get_name
+    pub fn from_name(name: &str) -> Option<Self> {
+        return Self::ALL
+            .iter()
+            .find(|representation| representation.get_name().eq_ignore_ascii_case(name))
+            .copied();
+    }
+
+    /// Retrieves the name of the representation
+    pub fn get_name(&self) -> &'static str {
+        return match self {
+            Self::RGB => "RGB",
+            Self::LinearRGB => "LinearRGB",
+            Self::HSV => "HSV",
+            Self::HSL => "HSL",
+            Self::HSI => "HSI",
+            Self::HWB => "HWB",
+            Self::RYB => "RYB",
+            Self::CMYK => "CMYK",
+            Self::YCbCr => "YCbCr",
+            Self::YUV => "YUV",
+            Self::YIQ => "YIQ",
+            Self::YCoCg => "YCoCg",
+            Self::YCoCgR => "YCoCgR",
+            Self::XYZ => "XYZ",
+            Self::xyY => "xyY",
+            Self::SpaceRGB => "SpaceRGB",
+            Self::Lab => "Lab",
+            Self::LCh => "LCh",
+            Self::OKLab => "OKLab",
+            Self::OKLCh => "OKLCh",
+            Self::OkHSV => "OkHSV",
+            Self::OkHSL => "OkHSL",
+            Self::Luv => "Luv",
+            Self::LChuv => "LChuv",
+            Self::HSLuv => "HSLuv",
+            Self::HPLuv => "HPLuv",
+            Self::ICtCp => "ICtCp",
+            Self::Jzazbz => "Jzazbz",
+            Self::JzCzhz => "JzCzhz",
+            Self::CAM16 => "CAM16",
+            Self::CAM16UCS => "CAM16UCS",
+        };
+    }
+
+    /// Retrieves the number of components of the representation without alpha
+    pub fn get_component_count(&self) -> usize {
+        return match self {
+            Self::CMYK => 4,
+            _ => 3,
+        };
+    }
+}
+
+/// A color in any of the representations of the library, the representation
+/// is chosen at runtime which allows colors of mixed representations to be
+/// stored in the same collection. It implements Color by forwarding to the
+/// stored color so no precision is lost until it is converted
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum AnyColor {
+    /// An sRGB color
+    RGB(ColorRGBA),
+    /// A linear sRGB color
+    LinearRGB(ColorLinearRGBA),
+    /// A HSV color
+    HSV(ColorHSVA),
+    /// A HSL color
+    HSL(ColorHSLA),
+    /// A HSI color
+    HSI(ColorHSIA),
+    /// A HWB color
+    HWB(ColorHWBA),
+    /// A RYB color
+    RYB(ColorRYBA),
+    /// A CMYK color
+    CMYK(ColorCMYKA),
+    /// A YCbCr color
+    YCbCr(ColorYCbCrA),
+    /// A YUV color
+    YUV(ColorYUVA),
+    /// A YIQ color
+    YIQ(ColorYIQA),
+    /// A YCoCg color
+    YCoCg(ColorYCoCgA),
+    /// A lossless YCoCg-R color
+    YCoCgR(ColorYCoCgRA),
+    /// A CIE XYZ color
+    XYZ(ColorXYZA),
+    /// A CIE xyY color
+    #[allow(non_camel_case_types)]
+    xyY(ColorxyYA),
+    /// A RGB color in an arbitrary RGB space
+    SpaceRGB(ColorSpaceRGBA),
+    /// A CIELAB color
+    Lab(ColorLabA),
+    /// A CIELCh color
+    LCh(ColorLChA),
+    /// An OKLab color
+    OKLab(ColorOKLabA),
+    /// An OKLCh color
+    OKLCh(ColorOKLChA),
+    /// An OkHSV color
+    OkHSV(ColorOkHSVA),
+    /// An OkHSL color
+    OkHSL(ColorOkHSLA),
+    /// A CIELUV color
+    Luv(ColorLuvA),
+    /// A CIELCh(uv) color
+    LChuv(ColorLChuvA),
+    /// A HSLuv color
+    HSLuv(ColorHSLuvA),
+    /// A HPLuv color
+    HPLuv(ColorHPLuvA),
+    /// An ICtCp color
+    ICtCp(ColorICtCpA),
+    /// A Jzazbz color
+    Jzazbz(ColorJzazbzA),
+    /// A JzCzhz color
+    JzCzhz(ColorJzCzhzA),
+    /// A CAM16 color
+    CAM16(ColorCAM16A),
+    /// A CAM16-UCS color
+    CAM16UCS(ColorCAM16UCSA),
+}
+
+impl AnyColor {
+    /// Constructs a color from its representation and its components given in
+    /// the same order and units as the new method of the color, the alpha
+    /// component may be left out in which case it is 1. Representations with
+    /// additional parameters use the D65 white point, the BT.709 matrix with
+    /// full range, the sRGB space and the sRGB viewing conditions and the
+    /// YCoCg-R components are rounded to integers. Returns None if the number
+    /// of components does not match
+    ///
+    /// # Parameters
+    ///
+    /// representation: The representation of the color
+    ///
+    /// components: The components of the color
+    pub fn from_components(representation: AnyColorType, components: &[f32]) -> Option<Self> {
+        let count = representation.get_component_count();
+        if components.len() != count && components.len() != count + 1 {
+            return None;
+        }
+
+        let c = components;
+        let a = if components.len() == count {
+            1.0
+        } else {
+            components[count]
+        };
+
+        return Some(match representation {
+            AnyColorType::RGB => AnyColor::RGB(ColorRGBA::new(c[0], c[1], c[2], a)),
+            AnyColorType::LinearRGB => {
+                AnyColor::LinearRGB(ColorLinearRGBA::new(c[0], c[1], c[2], a))
+            }
+            AnyColorType::HSV => AnyColor::HSV(ColorHSVA::new(c[0], c[1], c[2], a)),
+            AnyColorType::HSL => AnyColor::HSL(ColorHSLA::new(c[0], c[1], c[2], a)),
+            AnyColorType::HSI => AnyColor::HSI(ColorHSIA::new(c[0], c[1], c[2], a)),
+            AnyColorType::HWB => AnyColor::HWB(ColorHWBA::new(c[0], c[1], c[2], a)),
+            AnyColorType::RYB => AnyColor::RYB(ColorRYBA::new(c[0], c[1], c[2], a)),
+            AnyColorType::CMYK => AnyColor::CMYK(ColorCMYKA::new(c[0], c[1], c[2], c[3], a)),
+            AnyColorType::YUV => AnyColor::YUV(ColorYUVA::new(c[0], c[1], c[2], a)),
+            AnyColorType::YIQ => AnyColor::YIQ(ColorYIQA::new(c[0], c[1], c[2], a)),
+            AnyColorType::YCoCg => AnyColor::YCoCg(ColorYCoCgA::new(c[0], c[1], c[2], a)),
+            AnyColorType::XYZ => AnyColor::XYZ(ColorXYZA::new(c[0], c[1], c[2], a)),
+            AnyColorType::xyY => AnyColor::xyY(ColorxyYA::new(c[0], c[1], c[2], a)),
+            AnyColorType::OKLab => AnyColor::OKLab(ColorOKLabA::new(c[0], c[1], c[2], a)),
+            AnyColorType::OKLCh => AnyColor::OKLCh(ColorOKLChA::new(c[0], c[1], c[2], a)),
+            AnyColorType::OkHSV => AnyColor::OkHSV(ColorOkHSVA::new(c[0], c[1], c[2], a)),
+            AnyColorType::OkHSL => AnyColor::OkHSL(ColorOkHSLA::new(c[0], c[1], c[2], a)),
+            AnyColorType::YCbCr => AnyColor::YCbCr(ColorYCbCrA::new(
+                c[0],
+                c[1],
+                c[2],
+                a,
+                YCbCrMatrix::BT709,
+                YCbCrRange::Full,
+            )),
+            AnyColorType::YCoCgR => AnyColor::YCoCgR(ColorYCoCgRA::new(
+                c[0].round() as i32,
+                c[1].round() as i32,
+                c[2].round() as i32,
+                a,
+            )),
+            AnyColorType::SpaceRGB => {
+                AnyColor::SpaceRGB(ColorSpaceRGBA::new(c[0], c[1], c[2], a, RgbSpace::SRGB))
+            }
+            AnyColorType::Lab => {
+                AnyColor::Lab(ColorLabA::new(c[0], c[1], c[2], a, WhitePoint::D65))
+            }
+            AnyColorType::LCh => {
+                AnyColor::LCh(ColorLChA::new(c[0], c[1], c[2], a, WhitePoint::D65))
+            }
+            AnyColorType::Luv => {
+                AnyColor::Luv(ColorLuvA::new(c[0], c[1], c[2], a, WhitePoint::D65))
+            }
+            AnyColorType::LChuv => {
+                AnyColor::LChuv(ColorLChuvA::new(c[0], c[1], c[2], a, WhitePoint::D65))
+            }
+            AnyColorType::HSLuv => AnyColor::HSLuv(ColorHSLuvA::new(c[0], c[1], c[2], a)),
+            AnyColorType::HPLuv => AnyColor::HPLuv(ColorHPLuvA::new(c[0], c[1], c[2], a)),
+            AnyColorType::ICtCp => AnyColor::ICtCp(ColorICtCpA::new(c[0], c[1], c[2], a)),
+            AnyColorType::Jzazbz => AnyColor::Jzazbz(ColorJzazbzA::new(c[0], c[1], c[2], a)),
+            AnyColorType::JzCzhz => AnyColor::JzCzhz(ColorJzCzhzA::new(c[0], c[1], c[2], a)),
+            AnyColorType::CAM16 => AnyColor::CAM16(ColorCAM16A::new(
+                c[0],
+                c[1],
+                c[2],
+                a,
+                ViewingConditions::SRGB,
+            )),
+            AnyColorType::CAM16UCS => AnyColor::CAM16UCS(ColorCAM16UCSA::new(
+                c[0],
+                c[1],
+                c[2],
+                a,
+                ViewingConditions::SRGB,
+            )),
+        });
+    }
+
+    /// Retrieves the representation of the stored color, unlike get_type which
+    /// reports XYZ as the base of every AnyColor
+    pub fn get_representation(&self) -> AnyColorType {
+        return match self {
+            AnyColor::RGB(_) => AnyColorType::RGB,
+            AnyColor::LinearRGB(_) => AnyColorType::LinearRGB,
+            AnyColor::HSV(_) => AnyColorType::HSV,
+            AnyColor::HSL(_) => AnyColorType::HSL,
+            AnyColor::HSI(_) => AnyColorType::HSI,
+            AnyColor::HWB(_) => AnyColorType::HWB,
+            AnyColor::RYB(_) => AnyColorType::RYB,
+            AnyColor::CMYK(_) => AnyColorType::CMYK,
+            AnyColor::YCbCr(_) => AnyColorType::YCbCr,
+            AnyColor::YUV(_) => AnyColorType::YUV,
+            AnyColor::YIQ(_) => AnyColorType::YIQ,
+            AnyColor::YCoCg(_) => AnyColorType::YCoCg,
+            AnyColor::YCoCgR(_) => AnyColorType::YCoCgR,
+            AnyColor::XYZ(_) => AnyColorType::XYZ,
+            AnyColor::xyY(_) => AnyColorType::xyY,
+            AnyColor::SpaceRGB(_) => AnyColorType::SpaceRGB,
+            AnyColor::Lab(_) => AnyColorType::Lab,
+            AnyColor::LCh(_) => AnyColorType::LCh,
+            AnyColor::OKLab(_) => AnyColorType::OKLab,
+            AnyColor::OKLCh(_) => AnyColorType::OKLCh,
+            AnyColor::OkHSV(_) => AnyColorType::OkHSV,
+            AnyColor::OkHSL(_) => AnyColorType::OkHSL,
+            AnyColor::Luv(_) => AnyColorType::Luv,
+            AnyColor::LChuv(_) => AnyColorType::LChuv,
+            AnyColor::HSLuv(_) => AnyColorType::HSLuv,
+            AnyColor::HPLuv(_) => AnyColorType::HPLuv,
+            AnyColor::ICtCp(_) => AnyColorType::ICtCp,
+            AnyColor::Jzazbz(_) => AnyColorType::Jzazbz,
+            AnyColor::JzCzhz(_) => AnyColorType::JzCzhz,
+            AnyColor::CAM16(_) => AnyColorType::CAM16,
+            AnyColor::CAM16UCS(_) => AnyColorType::CAM16UCS,
+        };
+    }
+
+    /// Retrieves the name of the representation of the stored color
+    pub fn get_name(&self) -> &'static str {
+        return self.get_representation().get_name();
+    }
+}
+
+impl Color for AnyColor {
+    type Base = ColorXYZA;
+
+    fn get_base(&self) -> ColorXYZA {
+        return any_color_apply!(self, color => color.get_xyza());
+    }
+
+    fn get_rgba(&self) -> ColorRGBA {
+        return any_color_apply!(self, color => color.get_rgba());
+    }
+
+    fn get_hsva(&self) -> ColorHSVA {
+        return any_color_apply!(self, color => color.get_hsva());
+    }
+
+    fn get_hsla(&self) -> ColorHSLA {
+        return any_color_apply!(self, color => color.get_hsla());
+    }
+
+    fn get_hsia(&self) -> ColorHSIA {
+        return any_color_apply!(self, color => color.get_hsia());
+    }
+
+    fn get_xyza(&self) -> ColorXYZA {
+        return any_color_apply!(self, color => color.get_xyza());
+    }
+
+    fn get_hwba(&self) -> ColorHWBA {
+        return any_color_apply!(self, color => color.get_hwba());
+    }
+}
+
+impl ColorSpace for AnyColor {
+    fn from_xyza(color: &ColorXYZA) -> Self {
+        return AnyColor::XYZ(*color);
+    }
+}
+
+impl From<ColorRGBA> for AnyColor {
+    fn from(color: ColorRGBA) -> Self {
+        return AnyColor::RGB(color);
+    }
+}
+
+impl From<ColorLinearRGBA> for AnyColor {
+    fn from(color: ColorLinearRGBA) -> Self {
+        return AnyColor::LinearRGB(color);
+    }
+}
+
+impl From<ColorHSVA> for AnyColor {
+    fn from(color: ColorHSVA) -> Self {
+        return AnyColor::HSV(color);
+    }
+}
+
+impl From<ColorHSLA> for AnyColor {
+    fn from(color: ColorHSLA) -> Self {
+        return AnyColor::HSL(color);
+    }
+}
+
+impl From<ColorHSIA> for AnyColor {
+    fn from(color: ColorHSIA) -> Self {
+        return AnyColor::HSI(color);
+    }
+}
+
+impl From<ColorHWBA> for AnyColor {
+    fn from(color: ColorHWBA) -> Self {
+        return AnyColor::HWB(color);
+    }
+}
+
+impl From<ColorRYBA> for AnyColor {
+    fn from(color: ColorRYBA) -> Self {
+        return AnyColor::RYB(color);
+    }
+}
+
+impl From<ColorCMYKA> for AnyColor {
+    fn from(color: ColorCMYKA) -> Self {
+        return AnyColor::CMYK(color);
+    }
+}
+
+impl From<ColorYCbCrA> for AnyColor {
+    fn from(color: ColorYCbCrA) -> Self {
+        return AnyColor::YCbCr(color);
+    }
+}
+
+impl From<ColorYUVA> for AnyColor {
+    fn from(color: ColorYUVA) -> Self {
+        return AnyColor::YUV(color);
+    }
+}
+
+impl From<ColorYIQA> for AnyColor {
+    fn from(color: ColorYIQA) -> Self {
+        return AnyColor::YIQ(color);
+    }
+}
+
+impl From<ColorYCoCgA> for AnyColor {
+    fn from(color: ColorYCoCgA) -> Self {
+        return AnyColor::YCoCg(color);
+    }
+}
+
+impl From<ColorYCoCgRA> for AnyColor {
+    fn from(color: ColorYCoCgRA) -> Self {
+        return AnyColor::YCoCgR(color);
+    }
+}
+
+impl From<ColorXYZA> for AnyColor {
+    fn from(color: ColorXYZA) -> Self {
+        return AnyColor::XYZ(color);
+    }
+}
+
+impl From<ColorxyYA> for AnyColor {
+    fn from(color: ColorxyYA) -> Self {
+        return AnyColor::xyY(color);
+    }
+}
+
+impl From<ColorSpaceRGBA> for AnyColor {
+    fn from(color: ColorSpaceRGBA) -> Self {
+        return AnyColor::SpaceRGB(color);
+    }
+}
+
+impl From<ColorLabA> for AnyColor {
+    fn from(color: ColorLabA) -> Self {
+        return AnyColor::Lab(color);
+    }
+}
+
+impl From<ColorLChA> for AnyColor {
+    fn from(color: ColorLChA) -> Self {
+        return AnyColor::LCh(color);
+    }
+}
+
+impl From<ColorOKLabA> for AnyColor {
+    fn from(color: ColorOKLabA) -> Self {
+        return AnyColor::OKLab(color);
+    }
+}
+
+impl From<ColorOKLChA> for AnyColor {
+    fn from(color: ColorOKLChA) -> Self {
+        return AnyColor::OKLCh(color);
+    }
+}
+
+impl From<ColorOkHSVA> for AnyColor {
+    fn from(color: ColorOkHSVA) -> Self {
+        return AnyColor::OkHSV(color);
+    }
+}
+
+impl From<ColorOkHSLA> for AnyColor {
+    fn from(color: ColorOkHSLA) -> Self {
+        return AnyColor::OkHSL(color);
+    }
+}
+
+impl From<ColorLuvA> for AnyColor {
+    fn from(color: ColorLuvA) -> Self {
+        return AnyColor::Luv(color);
+    }
+}
+
+impl From<ColorLChuvA> for AnyColor {
+    fn from(color: ColorLChuvA) -> Self {
+        return AnyColor::LChuv(color);
+    }
+}
+
+impl From<ColorHSLuvA> for AnyColor {
+    fn from(color: ColorHSLuvA) -> Self {
+        return AnyColor::HSLuv(color);
+    }
+}
+
+impl From<ColorHPLuvA> for AnyColor {
+    fn from(color: ColorHPLuvA) -> Self {
+        return AnyColor::HPLuv(color);
+    }
+}
+
+impl From<ColorICtCpA> for AnyColor {
+    fn from(color: ColorICtCpA) -> Self {
+        return AnyColor::ICtCp(color);
+    }
+}
+
+impl From<ColorJzazbzA> for AnyColor {
+    fn from(color: ColorJzazbzA) -> Self {
+        return AnyColor::Jzazbz(color);
+    }
+}
+
+impl From<ColorJzCzhzA> for AnyColor {
+    fn from(color: ColorJzCzhzA) -> Self {
+        return AnyColor::JzCzhz(color);
+    }
+}
+
+impl From<ColorCAM16A> for AnyColor {
+    fn from(color: ColorCAM16A) -> Self {
+        return AnyColor::CAM16(color);
+    }
+}
+
+impl From<ColorCAM16UCSA> for AnyColor {
+    fn from(color: ColorCAM16UCSA) -> Self {
+        return AnyColor::CAM16UCS(color);
+    }
+}
+
+/// A generic N-dimensional color, all components are clamped between 0 and 1
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ColorND<const N: usize> {
+    values: [f32; N],
+}
+
+impl<const N: usize> ColorND<N> {
+    /// Constructs a new N-dimensional color
+    ///
+    /// # Parameters
+    ///
+    /// values: All color components
+    pub fn new(values: &[f32; N]) -> Self {
+        let values: [f32; N] = values
+            .iter()
+            .map(|value| {
+                return value.clamp(0.0, 1.0);
+            })
+            .collect::<Vec<f32>>()
+            .try_into()
+            .expect("Will never fail");
+
+        return Self { values };
+    }
+
+    /// Retrieves all the color components
+    pub fn get(&self) -> &[f32; N] {
+        return &self.values;
+    }
+}
+
+/// A simple enum type for determining which format is the base format of a
+/// color trait, it is reported by the base color of every color
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum ColorType {
+    RGB,
+    HSV,
+    HSL,
+    HSI,
+    XYZ,
+    HWB,
+}
+
+/// A color representation which other colors can be based on, a base color
+/// converts directly to all the other base colors so the conversions of a
+/// Color are all available once it can be expressed in a base color
+pub trait BaseColor: Copy {
+    /// The type of the base color
+    const TYPE: ColorType;
+
+    /// Converts the color to RGBA
+    fn to_rgba(&self) -> ColorRGBA;
+
+    /// Converts the color to HSVA
+    fn to_hsva(&self) -> ColorHSVA;
+
+    /// Converts the color to HSLA
+    fn to_hsla(&self) -> ColorHSLA;
+
+    /// Converts the color to HSIA
+    fn to_hsia(&self) -> ColorHSIA;
+
+    /// Converts the color to XYZA
+    fn to_xyza(&self) -> ColorXYZA;
+
+    /// Converts the color to HWBA
+    fn to_hwba(&self) -> ColorHWBA;
+}
+
+impl BaseColor for ColorRGBA {
+    const TYPE: ColorType = ColorType::RGB;
+
+    fn to_rgba(&self) -> ColorRGBA {
+        return *self;
+    }
+
+    fn to_hsva(&self) -> ColorHSVA {
+        return ColorHSVA::from(*self);
+    }
+
+    fn to_hsla(&self) -> ColorHSLA {
+        return ColorHSLA::from(*self);
+    }
+
+    fn to_hsia(&self) -> ColorHSIA {
+        return ColorHSIA::from(*self);
+    }
+
+    fn to_xyza(&self) -> ColorXYZA {
+        return utils::rgb_to_xyz(self);
+    }
+
+    fn to_hwba(&self) -> ColorHWBA {
+        return utils::rgb_to_hwb(self);
+    }
+}
+
+impl BaseColor for ColorHSVA {
+    const TYPE: ColorType = ColorType::HSV;
+
+    fn to_rgba(&self) -> ColorRGBA {
+        return ColorRGBA::from(*self);
+    }
+
+    fn to_hsva(&self) -> ColorHSVA {
+        return *self;
+    }
+
+    fn to_hsla(&self) -> ColorHSLA {
+        return ColorHSLA::from(*self);
+    }
+
+    fn to_hsia(&self) -> ColorHSIA {
+        return ColorHSIA::from(*self);
+    }
+
+    fn to_xyza(&self) -> ColorXYZA {
+        return utils::rgb_to_xyz(&ColorRGBA::from(*self));
+    }
+
+    fn to_hwba(&self) -> ColorHWBA {
+        return utils::hsv_to_hwb(self);
+    }
+}
+
+impl BaseColor for ColorHSLA {
+    const TYPE: ColorType = ColorType::HSL;
+
+    fn to_rgba(&self) -> ColorRGBA {
+        return ColorRGBA::from(*self);
+    }
+
+    fn to_hsva(&self) -> ColorHSVA {
+        return ColorHSVA::from(*self);
+    }
+
+    fn to_hsla(&self) -> ColorHSLA {
+        return *self;
+    }
+
+    fn to_hsia(&self) -> ColorHSIA {
+        return ColorHSIA::from(*self);
+    }
+
+    fn to_xyza(&self) -> ColorXYZA {
+        return utils::rgb_to_xyz(&ColorRGBA::from(*self));
+    }
+
+    fn to_hwba(&self) -> ColorHWBA {
+        return utils::hsl_to_hwb(self);
+    }
+}
+
+impl BaseColor for ColorHSIA {
+    const TYPE: ColorType = ColorType::HSI;
+
+    fn to_rgba(&self) -> ColorRGBA {
+        return ColorRGBA::from(*self);
+    }
+
+    fn to_hsva(&self) -> ColorHSVA {
+        return ColorHSVA::from(*self);
+    }
+
+    fn to_hsla(&self) -> ColorHSLA {
+        return ColorHSLA::from(*self);
+    }
+
+    fn to_hsia(&self) -> ColorHSIA {
+        return *self;
+    }
+
+    fn to_xyza(&self) -> ColorXYZA {
+        return utils::rgb_to_xyz(&ColorRGBA::from(*self));
+    }
+
+    fn to_hwba(&self) -> ColorHWBA {
+        return utils::hsi_to_hwb(self);
+    }
+}
+
+impl BaseColor for ColorXYZA {
+    const TYPE: ColorType = ColorType::XYZ;
+
+    fn to_rgba(&self) -> ColorRGBA {
+        return utils::xyz_to_rgb(self);
+    }
+
+    fn to_hsva(&self) -> ColorHSVA {
+        return ColorHSVA::from(utils::xyz_to_rgb(self));
+    }
+
+    fn to_hsla(&self) -> ColorHSLA {
+        return ColorHSLA::from(utils::xyz_to_rgb(self));
+    }
+
+    fn to_hsia(&self) -> ColorHSIA {
+        return ColorHSIA::from(utils::xyz_to_rgb(self));
+    }
+
+    fn to_xyza(&self) -> ColorXYZA {
+        return *self;
+    }
+
+    fn to_hwba(&self) -> ColorHWBA {
+        return utils::rgb_to_hwb(&utils::xyz_to_rgb(self));
+    }
+}
+
+impl BaseColor for ColorHWBA {
+    const TYPE: ColorType = ColorType::HWB;
+
+    fn to_rgba(&self) -> ColorRGBA {
+        return utils::hwb_to_rgb(self);
+    }
+
+    fn to_hsva(&self) -> ColorHSVA {
+        return utils::hwb_to_hsv(self);
+    }
+
+    fn to_hsla(&self) -> ColorHSLA {
+        return utils::hwb_to_hsl(self);
+    }
+
+    fn to_hsia(&self) -> ColorHSIA {
+        return utils::hwb_to_hsi(self);
+    }
+
+    fn to_xyza(&self) -> ColorXYZA {
+        return utils::rgb_to_xyz(&utils::hwb_to_rgb(self));
+    }
+
+    fn to_hwba(&self) -> ColorHWBA {
+        return *self;
+    }
+}
+
+/// Defines a single color which can be expressed in RGBA, only the conversion
+/// to the base color has to be implemented and all other conversions are
+/// derived from it
+///
+/// ```
+/// use color_lib::{Color, ColorRGBA};
+///
+/// struct Gray(f32);
+///
+/// impl Color for Gray {
+///     type Base = ColorRGBA;
+///
+///     fn get_base(&self) -> ColorRGBA {
+///         return ColorRGBA::new_rgb(self.0, self.0, self.0);
+///     }
+/// }
+///
+/// assert_eq!(0.5, Gray(0.5).get_hsla().get_lightness());
+/// ```
+///
+/// A color without the conversion to its base color does not compile
+///
+/// ```compile_fail,E0046
+/// use color_lib::{Color, ColorRGBA};
+///
+/// struct Gray(f32);
+///
+/// impl Color for Gray {
+///     type Base = ColorRGBA;
+/// }
+/// ```
+pub trait Color {
+    /// The base color type which this color is converted through
+    type Base: BaseColor;
+
+    /// Retrieves the color in its base color type, this is the only method
+    /// which must be implemented
+    fn get_base(&self) -> Self::Base;
+
+    /// Retrieves the type of the base color of this color
+    fn get_type(&self) -> ColorType {
+        return Self::Base::TYPE;
+    }
+
+    /// Retrieves the RGBA color for this color
+    fn get_rgba(&self) -> ColorRGBA {
+        return self.get_base().to_rgba();
+    }
+
+    /// Retrieves the HSVA color for this color
+    fn get_hsva(&self) -> ColorHSVA {
+        return self.get_base().to_hsva();
+    }
+
+    /// Retrieves the HSLA color for this color
+    fn get_hsla(&self) -> ColorHSLA {
+        return self.get_base().to_hsla();
+    }
+
+    /// Retrieves the HSIA color for this color
+    fn get_hsia(&self) -> ColorHSIA {
+        return self.get_base().to_hsia();
+    }
+
+    /// Retrieves the XYZA color for this color
+    fn get_xyza(&self) -> ColorXYZA {
+        return self.get_base().to_xyza();
+    }
+
+    /// Retrieves the HWBA color for this color
+    fn get_hwba(&self) -> ColorHWBA {
+        return self.get_base().to_hwba();
+    }
+
+    /// Converts this color to any color space
+    fn convert<T: ColorSpace>(&self) -> T
+    where
+        Self: Sized,
+    {
+        return T::from_color(self);
+    }
+}
+
+/// A color space which every color can be converted to, XYZ is the hub of the
+/// conversions so a new color type only has to implement Color and the
+/// conversion from XYZ to be reachable from all other colors with convert.
+/// Colors which depend on additional parameters use the defaults D65 for the
+/// white point, sRGB for the RGB space, BT.709 with full range for YCbCr and
+/// the sRGB viewing conditions for CAM16, use their conversion functions in
+/// utils for other parameters
+///
+/// ```
+/// use color_lib::{Color, ColorHSLA, ColorRGBA, ColorSpace, ColorXYZA};
+///
+/// /// A sensor space which measures the XYZ values with a gain
+/// struct Sensor([f32; 3]);
+///
+/// impl Color for Sensor {
+///     type Base = ColorXYZA;
+///
+///     fn get_base(&self) -> ColorXYZA {
+///         let [x, y, z] = self.0;
+///         return ColorXYZA::new_xyz(x / 2.0, y / 2.0, z / 2.0);
+///     }
+/// }
+///
+/// impl ColorSpace for Sensor {
+///     fn from_xyza(color: &ColorXYZA) -> Self {
+///         return Sensor([color.get_x(), color.get_y(), color.get_z()].map(|v| v * 2.0));
+///     }
+/// }
+///
+/// let sensor: Sensor = ColorRGBA::new_rgb(1.0, 1.0, 1.0).convert();
+/// assert_eq!(2.0, sensor.0[1].round());
+///
+/// let hsl: ColorHSLA = sensor.convert();
+/// assert_eq!(1.0, (hsl.get_lightness() * 1000.0).round() / 1000.0);
+/// ```
+pub trait ColorSpace: Color + Sized {
+    /// Constructs the color from a XYZ color
+    ///
+    /// # Parameters
+    ///
+    /// color: The XYZ color to convert
+    fn from_xyza(color: &ColorXYZA) -> Self;
+
+    /// Constructs the color from any other color, by default the color is
+    /// converted through XYZ and it is overridden where a more direct
+    /// conversion exists
+    ///
+    /// # Parameters
+    ///
+    /// color: The color to convert
+    fn from_color<C: Color + ?Sized>(color: &C) -> Self {
+        return Self::from_xyza(&color.get_xyza());
+    }
+}
+
+/// Defines a color map which can convert a N-dimensional color into a normal
+/// color
+pub trait ColorMap<const N: usize> {
+    /// Retrieves the normal color from the N-dimensional color
+    ///
+    /// # Parameters
+    ///
+    /// color: The N-dimensional color to convert
+    fn get_color(&self, color: ColorND<N>) -> impl Color;
+}
+
+/// An object safe version of ColorMap which retrieves the color as RGBA, it is
+/// implemented for every color map so a color map can be chosen at runtime and
+/// stored as a `Box<dyn DynColorMap<N>>`
+///
+/// ```
+/// use color_lib::{maps, DynColorMap, ColorND, ColorRGBA};
+///
+/// let map: Box<dyn DynColorMap<1>> = Box::new(maps::Grays::new(1.0));
+/// let color = map.get_color_rgba(ColorND::new(&[0.5]));
+/// assert_eq!(color, ColorRGBA::new_rgb(0.5, 0.5, 0.5));
+/// ```
+pub trait DynColorMap<const N: usize> {
+    /// Retrieves the normal color from the N-dimensional color as RGBA
+    ///
+    /// # Parameters
+    ///
+    /// color: The N-dimensional color to convert
+    fn get_color_rgba(&self, color: ColorND<N>) -> ColorRGBA;
+}
+
+impl<const N: usize, T: ColorMap<N>> DynColorMap<N> for T {
+    fn get_color_rgba(&self, color: ColorND<N>) -> ColorRGBA {
+        return self.get_color(color).get_rgba();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test the ColorRGBA struct
+    mod color_rgba {
+        use super::*;
+
+        /// Test new method
+        #[test]
+        fn new() {
+            let result_valid = ColorRGBA::new(0.1, 0.2, 0.3, 0.4);
             let result_low_r = ColorRGBA::new(-0.1, 0.2, 0.3, 0.4);
             let result_low_g = ColorRGBA::new(0.1, -0.2, 0.3, 0.4);
             let result_low_b = ColorRGBA::new(0.1, 0.2, -0.3, 0.4);
@@ -811,4 +6152,361 @@ mod tests {
             );
         }
     }
+
+    /// Test the AnyColor enum
+    mod any_color {
+        use super::*;
+
+        /// Test from_components method
+        #[test]
+        fn from_components() {
+            let result_rgb = AnyColor::from_components(AnyColorType::RGB, &[0.1, 0.2, 0.3]);
+            let result_hsl = AnyColor::from_components(AnyColorType::HSL, &[0.1, 0.2, 0.3, 0.4]);
+            let result_cmyk = AnyColor::from_components(AnyColorType::CMYK, &[0.1, 0.2, 0.3, 0.4]);
+            let result_count = AnyColor::from_components(AnyColorType::HSV, &[0.1, 0.2]);
+            let result_lab = AnyColor::from_components(AnyColorType::Lab, &[50.0, 20.0, -30.0]);
+
+            assert_eq!(
+                result_rgb,
+                Some(AnyColor::RGB(ColorRGBA::new_rgb(0.1, 0.2, 0.3)))
+            );
+            assert_eq!(
+                result_hsl,
+                Some(AnyColor::HSL(ColorHSLA::new(0.1, 0.2, 0.3, 0.4)))
+            );
+            assert_eq!(
+                result_cmyk,
+                Some(AnyColor::CMYK(ColorCMYKA::new_cmyk(0.1, 0.2, 0.3, 0.4)))
+            );
+            assert_eq!(
+                result_lab,
+                Some(AnyColor::Lab(ColorLabA::new(
+                    50.0,
+                    20.0,
+                    -30.0,
+                    1.0,
+                    WhitePoint::D65
+                )))
+            );
+            assert_eq!(result_count, None);
+        }
+
+        /// Test that representations are found by their names
+        #[test]
+        fn from_name() {
+            for representation in AnyColorType::ALL.iter() {
+                assert_eq!(
+                    AnyColorType::from_name(representation.get_name()),
+                    Some(*representation)
+                );
+                assert_eq!(
+                    AnyColorType::from_name(&representation.get_name().to_lowercase()),
+                    Some(*representation)
+                );
+            }
+            assert_eq!(AnyColorType::from_name("Munsell"), None);
+        }
+
+        /// Test that every representation is constructed again from its
+        /// representation and components
+        #[test]
+        fn from_components_round_trip() {
+            let rgb = ColorRGBA::new(0.8, 0.3, 0.1, 0.5);
+            let colors = [
+                AnyColor::RGB(rgb.convert()),
+                AnyColor::LinearRGB(rgb.convert()),
+                AnyColor::HSV(rgb.convert()),
+                AnyColor::HSL(rgb.convert()),
+                AnyColor::HSI(rgb.convert()),
+                AnyColor::HWB(rgb.convert()),
+                AnyColor::RYB(rgb.convert()),
+                AnyColor::CMYK(rgb.convert()),
+                AnyColor::YCbCr(rgb.convert()),
+                AnyColor::YUV(rgb.convert()),
+                AnyColor::YIQ(rgb.convert()),
+                AnyColor::YCoCg(rgb.convert()),
+                AnyColor::YCoCgR(rgb.convert()),
+                AnyColor::XYZ(rgb.convert()),
+                AnyColor::xyY(rgb.convert()),
+                AnyColor::SpaceRGB(rgb.convert()),
+                AnyColor::Lab(rgb.convert()),
+                AnyColor::LCh(rgb.convert()),
+                AnyColor::OKLab(rgb.convert()),
+                AnyColor::OKLCh(rgb.convert()),
+                AnyColor::OkHSV(rgb.convert()),
+                AnyColor::OkHSL(rgb.convert()),
+                AnyColor::Luv(rgb.convert()),
+                AnyColor::LChuv(rgb.convert()),
+                AnyColor::HSLuv(rgb.convert()),
+                AnyColor::HPLuv(rgb.convert()),
+                AnyColor::ICtCp(rgb.convert()),
+                AnyColor::Jzazbz(rgb.convert()),
+                AnyColor::JzCzhz(rgb.convert()),
+                AnyColor::CAM16(rgb.convert()),
+                AnyColor::CAM16UCS(rgb.convert()),
+            ];
+
+            assert_eq!(
+                colors.map(|color| color.get_representation()),
+                AnyColorType::ALL
+            );
+            for color in colors.iter() {
+                let components: Vec<f32> = any_color_apply!(color, stored => stored.get().to_vec());
+                assert_eq!(
+                    AnyColor::from_components(color.get_representation(), &components),
+                    Some(*color)
+                );
+            }
+        }
+
+        /// Test get_representation, get_name and get_type methods
+        #[test]
+        fn name() {
+            let colors = [
+                AnyColor::from(ColorRGBA::new_rgb(0.1, 0.2, 0.3)),
+                AnyColor::from(ColorHSVA::new_hsv(0.1, 0.2, 0.3)),
+                AnyColor::from(ColorxyYA::new_xyy(0.3, 0.3, 0.5)),
+            ];
+
+            let result_representations: Vec<AnyColorType> = colors
+                .iter()
+                .map(|color| color.get_representation())
+                .collect();
+            let result_names: Vec<&str> = colors.iter().map(|color| color.get_name()).collect();
+            let result_types: Vec<ColorType> =
+                colors.iter().map(|color| color.get_type()).collect();
+
+            assert_eq!(
+                result_representations,
+                vec![AnyColorType::RGB, AnyColorType::HSV, AnyColorType::xyY]
+            );
+            assert_eq!(result_names, vec!["RGB", "HSV", "xyY"]);
+            assert_eq!(
+                result_types,
+                vec![ColorType::XYZ, ColorType::XYZ, ColorType::XYZ]
+            );
+        }
+
+        /// Test that conversions are forwarded to the stored color
+        #[test]
+        fn convert() {
+            let hsl = ColorHSLA::new(0.1, 0.2, 0.3, 0.4);
+            let oklab = ColorOKLabA::new(0.5, 0.1, -0.1, 1.0);
+            let palette = [AnyColor::from(hsl), AnyColor::from(oklab)];
+
+            assert_eq!(palette[0].get_hsla(), hsl);
+            assert_eq!(palette[0].get_rgba(), hsl.get_rgba());
+            assert_eq!(palette[1].get_rgba(), oklab.get_rgba());
+            assert_eq!(palette[1].get_xyza(), oklab.get_xyza());
+            assert_eq!(
+                palette[0].convert::<ColorOKLabA>(),
+                hsl.convert::<ColorOKLabA>()
+            );
+            assert_eq!(hsl.convert::<AnyColor>(), AnyColor::XYZ(hsl.get_xyza()));
+        }
+    }
+
+    /// Test the conversions through the ColorSpace trait
+    mod convert {
+        use super::*;
+
+        /// A color space defined outside of the library by its conversions
+        /// from and to XYZ
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        struct Scaled([f32; 3]);
+
+        impl Color for Scaled {
+            type Base = ColorXYZA;
+
+            fn get_base(&self) -> ColorXYZA {
+                return ColorXYZA::new_xyz(self.0[0] / 2.0, self.0[1] / 3.0, self.0[2] / 4.0);
+            }
+        }
+
+        impl ColorSpace for Scaled {
+            fn from_xyza(color: &ColorXYZA) -> Self {
+                return Self([
+                    color.get_x() * 2.0,
+                    color.get_y() * 3.0,
+                    color.get_z() * 4.0,
+                ]);
+            }
+        }
+
+        /// Rounds the components of a color to 3 decimal places
+        fn round<const N: usize>(values: [f32; N]) -> [i32; N] {
+            return values.map(|value| (value * 1000.0).round() as i32);
+        }
+
+        /// Retrieves the RGB colors to convert
+        fn get_test_values() -> [ColorRGBA; 5] {
+            return [
+                ColorRGBA::new(0.0, 0.0, 0.0, 1.0),
+                ColorRGBA::new(1.0, 1.0, 1.0, 1.0),
+                ColorRGBA::new(0.8, 0.3, 0.1, 0.5),
+                ColorRGBA::new(0.2, 0.6, 0.4, 0.8),
+                ColorRGBA::new(0.1, 0.2, 0.9, 0.0),
+            ];
+        }
+
+        /// Test that convert matches the direct conversions of the hsx spaces
+        #[test]
+        fn hsx() {
+            for rgb in get_test_values().iter() {
+                let hsv = ColorHSVA::from(*rgb);
+                let hsl = ColorHSLA::from(*rgb);
+                let hsi = ColorHSIA::from(*rgb);
+
+                assert_eq!(round(hsv.get()), round(rgb.convert::<ColorHSVA>().get()));
+                assert_eq!(round(hsl.get()), round(hsv.convert::<ColorHSLA>().get()));
+                assert_eq!(round(rgb.get()), round(hsi.convert::<ColorRGBA>().get()));
+            }
+        }
+
+        /// Test that the From implementations round trip
+        #[test]
+        fn from() {
+            for rgb in get_test_values().iter() {
+                assert_eq!(
+                    round(rgb.get()),
+                    round(ColorRGBA::from(ColorHSVA::from(*rgb)).get())
+                );
+                assert_eq!(
+                    round(rgb.get()),
+                    round(ColorRGBA::from(ColorHSLA::from(*rgb)).get())
+                );
+                assert_eq!(
+                    round(rgb.get()),
+                    round(ColorRGBA::from(ColorHSIA::from(*rgb)).get())
+                );
+            }
+        }
+
+        /// Test the Into implementations
+        #[test]
+        fn into() {
+            let rgb = ColorRGBA::new(0.8, 0.3, 0.1, 0.5);
+            let hsv: ColorHSVA = rgb.into();
+            let hsl: ColorHSLA = hsv.into();
+            let hsi: ColorHSIA = hsl.into();
+            let result: ColorRGBA = hsi.into();
+
+            assert_eq!(round(rgb.get()), round(result.get()));
+            assert_eq!(round(hsv.get()), round(ColorHSVA::from(hsi).get()));
+            assert_eq!(round(hsv.get()), round(ColorHSVA::from(hsl).get()));
+            assert_eq!(round(hsi.get()), round(ColorHSIA::from(hsv).get()));
+            assert_eq!(round(hsl.get()), round(ColorHSLA::from(hsi).get()));
+            assert_eq!(round(hsl.get()), round(rgb.convert::<ColorHSLA>().get()));
+        }
+
+        /// Test that conversions between unrelated spaces go through XYZ
+        #[test]
+        fn hub() {
+            let rgb = ColorRGBA::new(0.8, 0.3, 0.1, 0.5);
+
+            assert_eq!(
+                round(utils::rgb_to_oklab(&rgb).get()),
+                round(rgb.convert::<ColorOKLabA>().get())
+            );
+            assert_eq!(
+                round(utils::rgb_to_jzazbz(&rgb).get()),
+                round(rgb.convert::<ColorHSLA>().convert::<ColorJzazbzA>().get())
+            );
+            assert_eq!(
+                round(utils::rgb_to_cmyk(&rgb).get()),
+                round(rgb.convert::<ColorOKLabA>().convert::<ColorCMYKA>().get())
+            );
+        }
+
+        /// Test that the parameterized spaces convert with their defaults
+        #[test]
+        fn defaults() {
+            for rgb in get_test_values().iter() {
+                let xyz = rgb.get_xyza();
+                let lab = utils::xyz_to_lab(&xyz, WhitePoint::D65);
+                let luv = utils::xyz_to_luv(&xyz, WhitePoint::D65);
+                let cam16 = utils::xyz_to_cam16(&xyz, ViewingConditions::SRGB);
+
+                assert_eq!(round(lab.get()), round(rgb.convert::<ColorLabA>().get()));
+                assert_eq!(
+                    round(utils::lab_to_lch(&lab).get()),
+                    round(rgb.convert::<ColorLChA>().get())
+                );
+                assert_eq!(round(luv.get()), round(rgb.convert::<ColorLuvA>().get()));
+                assert_eq!(
+                    round(utils::luv_to_lchuv(&luv).get()),
+                    round(rgb.convert::<ColorLChuvA>().get())
+                );
+                assert_eq!(
+                    round(utils::xyz_to_space_rgb(&xyz, RgbSpace::SRGB).get()),
+                    round(rgb.convert::<ColorSpaceRGBA>().get())
+                );
+                assert_eq!(
+                    round(utils::rgb_to_ycbcr(rgb, YCbCrMatrix::BT709, YCbCrRange::Full).get()),
+                    round(rgb.convert::<ColorYCbCrA>().get())
+                );
+                assert_eq!(
+                    round(cam16.get()),
+                    round(rgb.convert::<ColorCAM16A>().get())
+                );
+                assert_eq!(
+                    round(utils::cam16_to_cam16_ucs(&cam16).get()),
+                    round(rgb.convert::<ColorCAM16UCSA>().get())
+                );
+            }
+        }
+
+        /// Test a color space defined outside of the library
+        #[test]
+        fn custom_space() {
+            let rgb = ColorRGBA::new_rgb(0.8, 0.3, 0.1);
+            let xyz = utils::rgb_to_xyz(&rgb);
+            let scaled: Scaled = rgb.convert();
+
+            assert_eq!(
+                round([xyz.get_x() * 2.0, xyz.get_y() * 3.0, xyz.get_z() * 4.0]),
+                round(scaled.0)
+            );
+            assert_eq!(round(rgb.get()), round(scaled.convert::<ColorRGBA>().get()));
+            assert_eq!(
+                round(ColorHSVA::from(rgb).get()),
+                round(scaled.convert::<ColorHSVA>().get())
+            );
+        }
+    }
+
+    /// Test the DynColorMap trait
+    mod dyn_color_map {
+        use super::*;
+
+        /// A color map which inverts a gray value
+        struct Inverted;
+
+        impl ColorMap<1> for Inverted {
+            fn get_color(&self, color: ColorND<1>) -> impl Color {
+                let value = 1.0 - color.get()[0];
+                return ColorHSVA::new_hsv(0.0, 0.0, value);
+            }
+        }
+
+        /// Test get_color_rgba through trait objects
+        #[test]
+        fn get_color_rgba() {
+            let maps: Vec<Box<dyn DynColorMap<1>>> =
+                vec![Box::new(crate::maps::Grays::new(0.5)), Box::new(Inverted)];
+
+            let results: Vec<ColorRGBA> = maps
+                .iter()
+                .map(|map| map.get_color_rgba(ColorND::new(&[0.25])))
+                .collect();
+
+            assert_eq!(
+                results,
+                vec![
+                    ColorRGBA::new(0.25, 0.25, 0.25, 0.5),
+                    ColorRGBA::new_rgb(0.75, 0.75, 0.75)
+                ]
+            );
+        }
+    }
 }
@@ -4,8 +4,16 @@ mod definitions;
 
 pub mod colors;
 pub mod maps;
+pub mod munsell;
+pub mod spectral;
 pub mod utils;
 
 pub use definitions::{
-    Color, ColorHSIA, ColorHSLA, ColorHSVA, ColorMap, ColorND, ColorRGBA, ColorType,
+    AnyColor, AnyColorType, BaseColor, BlackGeneration, CmykSeparation, Color, ColorCAM16A,
+    ColorCAM16UCSA, ColorCMYKA, ColorHPLuvA, ColorHSIA, ColorHSLA, ColorHSLuvA, ColorHSVA,
+    ColorHWBA, ColorICtCpA, ColorJzCzhzA, ColorJzazbzA, ColorLChA, ColorLChuvA, ColorLabA,
+    ColorLinearRGBA, ColorLuvA, ColorMap, ColorND, ColorOKLChA, ColorOKLabA, ColorOkHSLA,
+    ColorOkHSVA, ColorRGBA, ColorRYBA, ColorSpace, ColorSpaceRGBA, ColorType, ColorXYZA,
+    ColorYCbCrA, ColorYCoCgA, ColorYCoCgRA, ColorYIQA, ColorYUVA, ColorxyYA, DynColorMap, RgbSpace,
+    Surround, TransferFunction, ViewingConditions, WhitePoint, YCbCrMatrix, YCbCrRange,
 };
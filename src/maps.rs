@@ -28,6 +28,11 @@ impl Grays {
     /// parameters
     ///
     /// a: The alpha value
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that a is between 0 and 1
     pub unsafe fn new_unsafe(a: f32) -> Self {
         return Self { a };
     }
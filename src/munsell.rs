@@ -0,0 +1,868 @@
+//!
+//! This module includes the Munsell color system, colors are converted to and
+//! from XYZ through a loaded Munsell renotation dataset
+//!
+
+use std::collections::HashMap;
+
+use crate::{utils, ColorXYZA, ColorxyYA, WhitePoint};
+
+/// The names of the Munsell hue families in the order they appear on the hue
+/// circle, each family spans a tenth of the circle and ends at 10 of the family
+const HUE_FAMILIES: [&str; 10] = ["R", "YR", "Y", "GY", "G", "BG", "B", "PB", "P", "RP"];
+/// The hue spacing of the renotation data in Munsell hue steps
+const RENOTATION_HUE_STEP: f32 = 2.5;
+/// The chroma spacing of the renotation data
+const RENOTATION_CHROMA_STEP: f32 = 2.0;
+/// The largest distance in xy for which a color is regarded as neutral
+const NEUTRAL_TOLERANCE: f32 = 1e-5;
+
+/// A color in the Munsell color system given by hue, value and chroma, it is
+/// not a Color itself as the conversion requires the renotation data, use
+/// MunsellRenotation to convert it to XYZ
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct MunsellColor {
+    /// The hue, 0 is 10RP, 0.05 is 5R and the families follow in steps of 0.1
+    h: f32,
+    /// The value, 0: black, 10: white
+    v: f32,
+    /// The chroma, 0 is neutral
+    c: f32,
+    /// The alpha component
+    a: f32,
+}
+
+impl MunsellColor {
+    /// Constructs a new Munsell color, the hue is wrapped to between 0 and 1,
+    /// the value is clamped to between 0 and 10, the chroma is clamped to be
+    /// non-negative and alpha is clamped to between 0 and 1
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue, 0 is 10RP, 0.05 is 5R and the families follow in steps of
+    /// 0.1
+    ///
+    /// v: The value, 0: black, 10: white
+    ///
+    /// c: The chroma, 0 is neutral
+    ///
+    /// a: The alpha component
+    pub fn new(h: f32, v: f32, c: f32, a: f32) -> Self {
+        return Self {
+            h: h.rem_euclid(1.0),
+            v: v.clamp(0.0, 10.0),
+            c: c.max(0.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Constructs a new Munsell color with the alpha component equal to 1, the
+    /// hue is wrapped to between 0 and 1, the value is clamped to between 0 and
+    /// 10 and the chroma is clamped to be non-negative
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue, 0 is 10RP, 0.05 is 5R and the families follow in steps of
+    /// 0.1
+    ///
+    /// v: The value, 0: black, 10: white
+    ///
+    /// c: The chroma, 0 is neutral
+    pub fn new_munsell(h: f32, v: f32, c: f32) -> Self {
+        return Self::new(h, v, c, 1.0);
+    }
+
+    /// Constructs a new neutral Munsell color with the alpha component equal
+    /// to 1, the value is clamped to between 0 and 10
+    ///
+    /// # Parameters
+    ///
+    /// v: The value, 0: black, 10: white
+    pub fn new_neutral(v: f32) -> Self {
+        return Self::new(0.0, v, 0.0, 1.0);
+    }
+
+    /// Constructs a new Munsell color without validating the input
+    ///
+    /// # Parameters
+    ///
+    /// h: The hue, 0 is 10RP, 0.05 is 5R and the families follow in steps of
+    /// 0.1
+    ///
+    /// v: The value, 0: black, 10: white
+    ///
+    /// c: The chroma, 0 is neutral
+    ///
+    /// a: The alpha component
+    ///
+    /// # Safety
+    ///
+    /// The components are stored without validation, the caller must ensure
+    /// that h is between 0 and 1 excluding 1, v is between 0 and 10, c is
+    /// non-negative and a is between 0 and 1
+    pub unsafe fn new_unsafe(h: f32, v: f32, c: f32, a: f32) -> Self {
+        return Self {
+            h: h,
+            v: v,
+            c: c,
+            a: a,
+        };
+    }
+
+    /// Parses a Munsell notation like "5R 4/14", "2.5YR 6/8" or "N 5/", the
+    /// alpha component is 1 and None is returned if the notation is invalid
+    ///
+    /// # Parameters
+    ///
+    /// notation: The notation to parse
+    pub fn from_notation(notation: &str) -> Option<Self> {
+        let notation = notation.trim();
+
+        if let Some(rest) = notation.strip_prefix('N') {
+            let (value, chroma) = rest.split_once('/').unwrap_or((rest, ""));
+            let chroma = chroma.trim();
+            if !chroma.is_empty() && chroma.parse::<f32>().ok()? != 0.0 {
+                return None;
+            }
+
+            return Some(Self::new_neutral(value.trim().parse().ok()?));
+        }
+
+        let family_start = notation.find(|c: char| c.is_ascii_alphabetic())?;
+        let family_end = family_start
+            + notation[family_start..]
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(notation.len() - family_start);
+        let step = notation[..family_start].trim().parse().ok()?;
+        let hue = parse_hue(step, &notation[family_start..family_end])?;
+        let (value, chroma) = notation[family_end..].trim().split_once('/')?;
+
+        return Some(Self::new_munsell(
+            hue,
+            value.trim().parse().ok()?,
+            chroma.trim().parse().ok()?,
+        ));
+    }
+
+    /// Retrieves the hue of the color
+    pub fn get_hue(&self) -> f32 {
+        return self.h;
+    }
+
+    /// Retrieves the value of the color
+    pub fn get_value(&self) -> f32 {
+        return self.v;
+    }
+
+    /// Retrieves the chroma of the color
+    pub fn get_chroma(&self) -> f32 {
+        return self.c;
+    }
+
+    /// Retrieves the alpha component of the color
+    pub fn get_alpha(&self) -> f32 {
+        return self.a;
+    }
+
+    /// Retrieves all the color components in an array in the order: hue,
+    /// value, chroma, alpha
+    pub fn get(&self) -> [f32; 4] {
+        return [self.h, self.v, self.c, self.a];
+    }
+
+    /// Retrieves the Munsell notation of the color like "5R 4/14" or "N 5/",
+    /// the numbers are rounded to two decimals
+    pub fn get_notation(&self) -> String {
+        let round = |value: f32| (value * 100.0).round() / 100.0;
+        if round(self.c) == 0.0 {
+            return format!("N {}/", round(self.v));
+        }
+
+        let hue = round(self.h * 100.0);
+        let family = ((hue / 10.0).ceil() as usize + HUE_FAMILIES.len() - 1) % HUE_FAMILIES.len();
+        let step = round(hue - 10.0 * ((hue / 10.0).ceil() - 1.0));
+        let step = if step == 0.0 { 10.0 } else { step };
+
+        return format!(
+            "{}{} {}/{}",
+            step,
+            HUE_FAMILIES[family],
+            round(self.v),
+            round(self.c)
+        );
+    }
+}
+
+/// Converts a Munsell hue step and family to a hue between 0 and 1, None is
+/// returned if the family does not exist
+///
+/// # Parameters
+///
+/// step: The step within the family between 0 and 10
+///
+/// family: The name of the family like "R" or "YR"
+fn parse_hue(step: f32, family: &str) -> Option<f32> {
+    let index = HUE_FAMILIES.iter().position(|name| *name == family)?;
+    if !(0.0..=10.0).contains(&step) {
+        return None;
+    }
+
+    return Some(((10.0 * index as f32 + step) / 100.0).rem_euclid(1.0));
+}
+
+/// Calculates the luminance of a Munsell value using the fifth order polynomial
+/// of ASTM D1535, the luminance is relative to a perfect diffuser such that a
+/// value of 10 has a luminance of 1
+///
+/// # Parameters
+///
+/// value: The Munsell value, it is clamped to between 0 and 10
+pub fn munsell_value_to_luminance(value: f32) -> f32 {
+    let v = value.clamp(0.0, 10.0);
+
+    return v * (1.1914 + v * (-0.22533 + v * (0.23352 + v * (-0.020484 + v * 0.00081939))))
+        / 100.0;
+}
+
+/// Calculates the Munsell value of a luminance by inverting the polynomial of
+/// ASTM D1535
+///
+/// # Parameters
+///
+/// luminance: The luminance relative to a perfect diffuser, the result is
+/// clamped to between 0 and 10
+pub fn luminance_to_munsell_value(luminance: f32) -> f32 {
+    let mut low = 0.0;
+    let mut high = 10.0;
+    for _ in 0..32 {
+        let middle = 0.5 * (low + high);
+        if munsell_value_to_luminance(middle) < luminance {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    return 0.5 * (low + high);
+}
+
+/// The Munsell renotation data mapping Munsell colors to xy chromaticities
+/// under illuminant C, colors between the tabulated hues, values and chromas
+/// are interpolated. Colors with a value outside of the tabulated values, like
+/// the values below 1 which are missing from "real.dat", use the chromaticity
+/// of the closest tabulated value while the luminance follows their own value.
+/// The dataset is not bundled with the library, load it with parse, for example
+/// from the "real.dat" file published by the Munsell Color Science Laboratory
+#[derive(Debug, PartialEq, Clone)]
+pub struct MunsellRenotation {
+    /// The chromaticities indexed by hue in tenths of a Munsell hue step, value
+    /// in tenths and chroma in tenths
+    entries: HashMap<[i32; 3], [f32; 2]>,
+    /// The lowest and highest value of the entries
+    values: [f32; 2],
+}
+
+impl MunsellRenotation {
+    /// Constructs a new empty renotation dataset
+    pub fn new() -> Self {
+        return Self {
+            entries: HashMap::new(),
+            values: [f32::INFINITY, f32::NEG_INFINITY],
+        };
+    }
+
+    /// Parses a renotation dataset given as whitespace separated lines of the
+    /// form "h V C x y Y" with a hue like "2.5R", lines not starting with a hue
+    /// like a header are skipped and the Y column is ignored as it follows
+    /// from the value, None is returned if a line with a hue is malformed
+    ///
+    /// # Parameters
+    ///
+    /// text: The content of the dataset
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut renotation = Self::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if !line.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            if columns.len() < 5 {
+                return None;
+            }
+            let color = MunsellColor::from_notation(&format!(
+                "{} {}/{}",
+                columns[0], columns[1], columns[2]
+            ))?;
+            renotation.insert(&color, columns[3].parse().ok()?, columns[4].parse().ok()?);
+        }
+
+        return Some(renotation);
+    }
+
+    /// Adds a renotation entry to the dataset, the hue should be a multiple of
+    /// 2.5 Munsell steps and the chroma a multiple of 2 to be used for
+    /// interpolation
+    ///
+    /// # Parameters
+    ///
+    /// color: The Munsell color of the entry
+    ///
+    /// x: The x chromaticity under illuminant C
+    ///
+    /// y: The y chromaticity under illuminant C
+    pub fn insert(&mut self, color: &MunsellColor, x: f32, y: f32) {
+        self.entries
+            .insert(renotation_key(color.h * 100.0, color.v, color.c), [x, y]);
+        self.values = [self.values[0].min(color.v), self.values[1].max(color.v)];
+    }
+
+    /// Retrieves the number of entries in the dataset
+    pub fn len(&self) -> usize {
+        return self.entries.len();
+    }
+
+    /// Checks if the dataset has no entries
+    pub fn is_empty(&self) -> bool {
+        return self.entries.is_empty();
+    }
+
+    /// Converts a Munsell color to XYZ relative to D65, the color is
+    /// interpolated in the renotation data and adapted from illuminant C using
+    /// the Bradford transform, chromas beyond the dataset are clamped to the
+    /// highest tabulated chroma, None is returned if the dataset has no entries
+    /// for the hue of the color
+    ///
+    /// # Parameters
+    ///
+    /// color: The Munsell color to convert
+    pub fn to_xyza(&self, color: &MunsellColor) -> Option<ColorXYZA> {
+        let [x, y] = self.get_chromaticity(color.h * 100.0, color.v, color.c)?;
+        let xyz = utils::xyy_to_xyz(&ColorxyYA::new(
+            x,
+            y,
+            munsell_value_to_luminance(color.v),
+            color.a,
+        ));
+
+        return Some(utils::adapt_xyz(&xyz, WhitePoint::C, WhitePoint::D65));
+    }
+
+    /// Converts a XYZ color relative to D65 to a Munsell color, the hue and
+    /// chroma are found by iteratively inverting the interpolation of the
+    /// renotation data, colors outside of the dataset result in the closest
+    /// color found on its boundary, None is returned if the dataset is empty
+    ///
+    /// # Parameters
+    ///
+    /// color: The XYZ color to convert
+    pub fn from_xyza(&self, color: &ColorXYZA) -> Option<MunsellColor> {
+        let xyy = utils::xyz_to_xyy(&utils::adapt_xyz(color, WhitePoint::D65, WhitePoint::C));
+        let target = [xyy.get_x(), xyy.get_y()];
+        let value = luminance_to_munsell_value(xyy.get_luminance());
+        let neutral = neutral_chromaticity();
+        if (target[0] - neutral[0]).hypot(target[1] - neutral[1]) < NEUTRAL_TOLERANCE {
+            return Some(MunsellColor::new(0.0, value, 0.0, color.get_alpha()));
+        }
+
+        if self.is_empty() {
+            return None;
+        }
+        let plane = value.round().clamp(self.values[0], self.values[1]);
+        let mut hue = 0.0;
+        let mut chroma = 0.0;
+        let mut best = f32::INFINITY;
+        for (key, xy) in self.entries.iter() {
+            let distance = (xy[0] - target[0]).hypot(xy[1] - target[1]);
+            if key[1] as f32 / 10.0 == plane && distance < best {
+                hue = key[0] as f32 / 10.0;
+                chroma = key[2] as f32 / 10.0;
+                best = distance;
+            }
+        }
+        if best == f32::INFINITY {
+            return None;
+        }
+
+        let error = |hue: f32, chroma: f32| -> Option<[f32; 2]> {
+            let xy = self.get_chromaticity(hue.rem_euclid(100.0), value, chroma)?;
+            return Some([xy[0] - target[0], xy[1] - target[1]]);
+        };
+        let mut current = error(hue, chroma)?;
+        for _ in 0..32 {
+            if current[0].hypot(current[1]) < NEUTRAL_TOLERANCE {
+                break;
+            }
+
+            let delta_hue = 0.01;
+            let delta_chroma = if chroma > 0.01 { -0.01 } else { 0.01 };
+            let hue_error = error(hue + delta_hue, chroma)?;
+            let chroma_error = error(hue, chroma + delta_chroma)?;
+            let jacobian = [
+                [
+                    (hue_error[0] - current[0]) / delta_hue,
+                    (chroma_error[0] - current[0]) / delta_chroma,
+                ],
+                [
+                    (hue_error[1] - current[1]) / delta_hue,
+                    (chroma_error[1] - current[1]) / delta_chroma,
+                ],
+            ];
+            let determinant = jacobian[0][0] * jacobian[1][1] - jacobian[0][1] * jacobian[1][0];
+            if determinant == 0.0 {
+                break;
+            }
+            let step_hue =
+                (jacobian[1][1] * current[0] - jacobian[0][1] * current[1]) / determinant;
+            let step_chroma =
+                (jacobian[0][0] * current[1] - jacobian[1][0] * current[0]) / determinant;
+
+            let mut scale = 1.0;
+            while scale >= 1e-3 {
+                let next_hue = hue - scale * step_hue;
+                let next_chroma = (chroma - scale * step_chroma).clamp(
+                    0.0,
+                    self.get_max_chroma(
+                        next_hue.rem_euclid(100.0),
+                        value.clamp(self.values[0], self.values[1]),
+                    ),
+                );
+                if let Some(next) = error(next_hue, next_chroma) {
+                    if next[0].hypot(next[1]) < current[0].hypot(current[1]) {
+                        hue = next_hue;
+                        chroma = next_chroma;
+                        current = next;
+                        break;
+                    }
+                }
+                scale *= 0.5;
+            }
+            if scale < 1e-3 {
+                break;
+            }
+        }
+
+        return Some(MunsellColor::new(
+            hue / 100.0,
+            value,
+            chroma,
+            color.get_alpha(),
+        ));
+    }
+
+    /// Interpolates the xy chromaticity under illuminant C of a Munsell color,
+    /// the integer value planes are interpolated linearly in luminance and the
+    /// hues and chromas within a plane are interpolated in polar coordinates
+    /// around the neutral point, values and chromas outside of the dataset are
+    /// clamped to the closest tabulated value and chroma
+    ///
+    /// # Parameters
+    ///
+    /// hue: The hue in Munsell hue steps between 0 and 100
+    ///
+    /// value: The Munsell value
+    ///
+    /// chroma: The Munsell chroma
+    fn get_chromaticity(&self, hue: f32, value: f32, chroma: f32) -> Option<[f32; 2]> {
+        if chroma == 0.0 {
+            return Some(neutral_chromaticity());
+        }
+        if self.is_empty() {
+            return None;
+        }
+
+        let value = value.clamp(self.values[0], self.values[1]);
+        let chroma = chroma.min(self.get_max_chroma(hue, value));
+        if chroma == 0.0 {
+            return None;
+        }
+
+        let low = value.floor();
+        let high = value.ceil();
+        let xy_low = self.get_plane_chromaticity(hue, low, chroma)?;
+        if low == high {
+            return Some(xy_low);
+        }
+
+        let xy_high = self.get_plane_chromaticity(hue, high, chroma)?;
+        let luminance_low = munsell_value_to_luminance(low);
+        let t = (munsell_value_to_luminance(value) - luminance_low)
+            / (munsell_value_to_luminance(high) - luminance_low);
+
+        return Some([
+            xy_low[0] + (xy_high[0] - xy_low[0]) * t,
+            xy_low[1] + (xy_high[1] - xy_low[1]) * t,
+        ]);
+    }
+
+    /// Interpolates the xy chromaticity of a Munsell color within a single
+    /// value plane of the renotation data
+    ///
+    /// # Parameters
+    ///
+    /// hue: The hue in Munsell hue steps between 0 and 100
+    ///
+    /// value: The Munsell value of the plane
+    ///
+    /// chroma: The Munsell chroma
+    fn get_plane_chromaticity(&self, hue: f32, value: f32, chroma: f32) -> Option<[f32; 2]> {
+        let hue_low = (hue / RENOTATION_HUE_STEP).floor() * RENOTATION_HUE_STEP;
+        let chroma_low = (chroma / RENOTATION_CHROMA_STEP).floor() * RENOTATION_CHROMA_STEP;
+        let hue_t = (hue - hue_low) / RENOTATION_HUE_STEP;
+        let chroma_t = (chroma - chroma_low) / RENOTATION_CHROMA_STEP;
+
+        let polar_at_hue = |hue: f32| -> Option<[f32; 2]> {
+            let low = self.get_entry_polar(hue, value, chroma_low)?;
+            if chroma_t == 0.0 {
+                return Some(low);
+            }
+
+            let high = self.get_entry_polar(hue, value, chroma_low + RENOTATION_CHROMA_STEP)?;
+            let low = if chroma_low == 0.0 {
+                [high[0], 0.0]
+            } else {
+                low
+            };
+
+            return Some(interpolate_polar(low, high, chroma_t));
+        };
+        let low = polar_at_hue(hue_low)?;
+        let polar = if hue_t == 0.0 {
+            low
+        } else {
+            interpolate_polar(low, polar_at_hue(hue_low + RENOTATION_HUE_STEP)?, hue_t)
+        };
+
+        let neutral = neutral_chromaticity();
+        return Some([
+            neutral[0] + polar[1] * polar[0].cos(),
+            neutral[1] + polar[1] * polar[0].sin(),
+        ]);
+    }
+
+    /// Retrieves the highest chroma which can be interpolated at a hue and
+    /// value, it is the lowest of the highest chromas tabulated without gaps
+    /// at the surrounding hues and value planes, 0 is returned if there are no
+    /// entries
+    ///
+    /// # Parameters
+    ///
+    /// hue: The hue in Munsell hue steps between 0 and 100
+    ///
+    /// value: The Munsell value within the dataset
+    fn get_max_chroma(&self, hue: f32, value: f32) -> f32 {
+        let hue_low = (hue / RENOTATION_HUE_STEP).floor() * RENOTATION_HUE_STEP;
+        let hues = if hue == hue_low {
+            vec![hue_low]
+        } else {
+            vec![hue_low, hue_low + RENOTATION_HUE_STEP]
+        };
+
+        let mut max = f32::INFINITY;
+        for hue in hues.iter() {
+            for value in [value.floor(), value.ceil()].iter() {
+                let mut chroma = 0.0;
+                while self.entries.contains_key(&renotation_key(
+                    *hue,
+                    *value,
+                    chroma + RENOTATION_CHROMA_STEP,
+                )) {
+                    chroma += RENOTATION_CHROMA_STEP;
+                }
+                max = max.min(chroma);
+            }
+        }
+
+        return max;
+    }
+
+    /// Retrieves a renotation entry in polar coordinates around the neutral
+    /// point in the order: angle in radians, radius
+    ///
+    /// # Parameters
+    ///
+    /// hue: The hue in Munsell hue steps
+    ///
+    /// value: The Munsell value
+    ///
+    /// chroma: The Munsell chroma
+    fn get_entry_polar(&self, hue: f32, value: f32, chroma: f32) -> Option<[f32; 2]> {
+        if chroma == 0.0 {
+            return Some([0.0, 0.0]);
+        }
+
+        let xy = self.entries.get(&renotation_key(hue, value, chroma))?;
+        let neutral = neutral_chromaticity();
+        let dx = xy[0] - neutral[0];
+        let dy = xy[1] - neutral[1];
+
+        return Some([dy.atan2(dx), dx.hypot(dy)]);
+    }
+}
+
+impl Default for MunsellRenotation {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// Creates the key of a renotation entry, the hue is wrapped such that 10RP
+/// and 0R are the same
+///
+/// # Parameters
+///
+/// hue: The hue in Munsell hue steps
+///
+/// value: The Munsell value
+///
+/// chroma: The Munsell chroma
+fn renotation_key(hue: f32, value: f32, chroma: f32) -> [i32; 3] {
+    return [
+        ((hue * 10.0).round() as i32).rem_euclid(1000),
+        (value * 10.0).round() as i32,
+        (chroma * 10.0).round() as i32,
+    ];
+}
+
+/// Interpolates between two polar coordinates along the shortest arc
+///
+/// # Parameters
+///
+/// from: The angle in radians and radius to interpolate from
+///
+/// to: The angle in radians and radius to interpolate to
+///
+/// t: The interpolation parameter between 0 and 1
+fn interpolate_polar(from: [f32; 2], to: [f32; 2], t: f32) -> [f32; 2] {
+    let difference = (to[0] - from[0] + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+        - std::f32::consts::PI;
+
+    return [from[0] + difference * t, from[1] + (to[1] - from[1]) * t];
+}
+
+/// Retrieves the xy chromaticity of illuminant C which is the neutral point of
+/// the renotation data
+fn neutral_chromaticity() -> [f32; 2] {
+    let [x, y, z] = WhitePoint::C.get();
+    let sum = x + y + z;
+
+    return [x / sum, y / sum];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A synthetic renotation dataset where the hue is the angle around the
+    /// neutral point and the chroma is proportional to the distance from it
+    fn synthetic_renotation() -> MunsellRenotation {
+        let neutral = neutral_chromaticity();
+        let mut renotation = MunsellRenotation::new();
+        for hue in 0..40 {
+            for value in 1..10 {
+                for chroma in 1..9 {
+                    let hue = hue as f32 * 2.5;
+                    let chroma = chroma as f32 * 2.0;
+                    let angle = hue / 100.0 * std::f32::consts::TAU;
+                    renotation.insert(
+                        &MunsellColor::new_munsell(hue / 100.0, value as f32, chroma),
+                        neutral[0] + 0.01 * chroma * angle.cos(),
+                        neutral[1] + 0.01 * chroma * angle.sin(),
+                    );
+                }
+            }
+        }
+
+        return renotation;
+    }
+
+    /// Rounds the hue, value and chroma of a Munsell color for comparison
+    fn round(color: &MunsellColor) -> [i32; 3] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32 % 1000,
+            (color.get_value() * 100.0).round() as i32,
+            (color.get_chroma() * 100.0).round() as i32,
+        ];
+    }
+
+    /// Test parsing and formatting of Munsell notation
+    #[test]
+    fn notation() {
+        let values = [
+            ("5R 4/14", [0.05, 4.0, 14.0], "5R 4/14"),
+            ("2.5YR 6/8", [0.125, 6.0, 8.0], "2.5YR 6/8"),
+            ("10RP 3/6", [0.0, 3.0, 6.0], "10RP 3/6"),
+            ("7.5PB4.5/11.2", [0.775, 4.5, 11.2], "7.5PB 4.5/11.2"),
+            ("N 5/", [0.0, 5.0, 0.0], "N 5/"),
+            ("N 8.5/0", [0.0, 8.5, 0.0], "N 8.5/"),
+            ("N10", [0.0, 10.0, 0.0], "N 10/"),
+        ];
+
+        for (notation, [h, v, c], formatted) in values.iter() {
+            let color = MunsellColor::from_notation(notation).unwrap();
+
+            assert_eq!(round(&MunsellColor::new_munsell(*h, *v, *c)), round(&color));
+            assert_eq!(*formatted, color.get_notation());
+        }
+
+        for notation in ["", "5X 4/14", "11R 4/14", "5R 4", "5R a/b"].iter() {
+            assert_eq!(None, MunsellColor::from_notation(notation));
+        }
+    }
+
+    /// Test the conversion between Munsell value and luminance
+    #[test]
+    fn value() {
+        let values = [(1.0, 1.18), (5.0, 19.27), (9.0, 76.70), (10.0, 100.0)];
+
+        for (value, luminance) in values.iter() {
+            let luminance: f32 = *luminance;
+            assert_eq!(
+                (luminance * 100.0).round() as i32,
+                (munsell_value_to_luminance(*value) * 10000.0).round() as i32
+            );
+            assert_eq!(
+                (value * 1000.0).round() as i32,
+                (luminance_to_munsell_value(luminance / 100.0) * 1000.0).round() as i32
+            );
+        }
+    }
+
+    /// Test parsing of a renotation dataset
+    #[test]
+    fn parse() {
+        let text = "h V C x y Y\n2.5R 5 2 0.3300 0.3100 19.77\n10RP 5 2 0.3250 0.3050 19.77\n";
+        let renotation = MunsellRenotation::parse(text).unwrap();
+
+        assert_eq!(2, renotation.len());
+        assert_eq!(
+            Some([0.33, 0.31]),
+            renotation.get_chromaticity(2.5, 5.0, 2.0)
+        );
+        assert_eq!(
+            Some([0.325, 0.305]),
+            renotation.get_chromaticity(0.0, 5.0, 2.0)
+        );
+        assert_eq!(None, MunsellRenotation::parse("2.5R 5 2 0.33"));
+    }
+
+    /// Test interpolation of the renotation data
+    #[test]
+    fn to_xyza() {
+        let renotation = synthetic_renotation();
+        let neutral = neutral_chromaticity();
+
+        for [h, v, c] in [[0.037, 5.5, 5.0], [0.99, 2.0, 3.3], [0.5, 8.25, 15.0]].iter() {
+            let xyz = renotation
+                .to_xyza(&MunsellColor::new_munsell(*h, *v, *c))
+                .unwrap();
+            let xyy = utils::xyz_to_xyy(&utils::adapt_xyz(&xyz, WhitePoint::D65, WhitePoint::C));
+            let angle = h * std::f32::consts::TAU;
+
+            assert_eq!(
+                [
+                    neutral[0] + 0.01 * c * angle.cos(),
+                    neutral[1] + 0.01 * c * angle.sin(),
+                    munsell_value_to_luminance(*v)
+                ]
+                .map(|value| (value * 10000.0).round() as i32),
+                [xyy.get_x(), xyy.get_y(), xyy.get_luminance()]
+                    .map(|value| (value * 10000.0).round() as i32)
+            );
+        }
+
+        assert_eq!(
+            renotation.to_xyza(&MunsellColor::new_munsell(0.3, 5.0, 16.0)),
+            renotation.to_xyza(&MunsellColor::new_munsell(0.3, 5.0, 20.0))
+        );
+        assert_eq!(
+            None,
+            MunsellRenotation::new().to_xyza(&MunsellColor::new_munsell(0.3, 5.0, 2.0))
+        );
+    }
+
+    /// Test that colors beyond the dataset are clamped to its boundary
+    #[test]
+    fn clamp() {
+        let renotation = synthetic_renotation();
+        let neutral = neutral_chromaticity();
+        let angle = 0.3 * std::f32::consts::TAU;
+        let xyy = ColorxyYA::new(
+            neutral[0] + 0.01 * 20.0 * angle.cos(),
+            neutral[1] + 0.01 * 20.0 * angle.sin(),
+            munsell_value_to_luminance(5.0),
+            1.0,
+        );
+        let xyz = utils::adapt_xyz(&utils::xyy_to_xyz(&xyy), WhitePoint::C, WhitePoint::D65);
+
+        assert_eq!(
+            [300, 500, 1600],
+            round(&renotation.from_xyza(&xyz).unwrap())
+        );
+        assert_eq!(None, MunsellRenotation::new().from_xyza(&xyz));
+    }
+
+    /// Test that values below the dataset use the chromaticity of the lowest
+    /// value
+    #[test]
+    fn dark() {
+        let renotation = synthetic_renotation();
+        let dark = renotation
+            .to_xyza(&MunsellColor::new_munsell(0.3, 0.5, 2.0))
+            .unwrap();
+        let light = renotation
+            .to_xyza(&MunsellColor::new_munsell(0.3, 1.0, 2.0))
+            .unwrap();
+        let dark_xyy = utils::xyz_to_xyy(&dark);
+        let light_xyy = utils::xyz_to_xyy(&light);
+
+        assert_eq!(
+            [
+                light_xyy.get_x(),
+                light_xyy.get_y(),
+                munsell_value_to_luminance(0.5)
+            ]
+            .map(|value| (value * 10000.0).round() as i32),
+            [dark_xyy.get_x(), dark_xyy.get_y(), dark_xyy.get_luminance()]
+                .map(|value| (value * 10000.0).round() as i32)
+        );
+        assert_eq!([300, 50, 200], round(&renotation.from_xyza(&dark).unwrap()));
+    }
+
+    /// Test conversion of neutral colors without renotation data
+    #[test]
+    fn neutral() {
+        let renotation = MunsellRenotation::new();
+        let xyz = renotation.to_xyza(&MunsellColor::new_neutral(5.0)).unwrap();
+        let [x, y, z] = WhitePoint::D65.get();
+
+        assert_eq!(
+            [x, y, z].map(|value| (value * 0.1927 * 1000.0).round() as i32),
+            [xyz.get_x(), xyz.get_y(), xyz.get_z()].map(|value| (value * 1000.0).round() as i32)
+        );
+        assert_eq!([0, 500, 0], round(&renotation.from_xyza(&xyz).unwrap()));
+    }
+
+    /// Test that converting to XYZ and back returns the original color
+    #[test]
+    fn round_trip() {
+        let renotation = synthetic_renotation();
+
+        for [h, v, c] in [
+            [0.037, 5.5, 5.0],
+            [0.99, 2.0, 3.3],
+            [0.5, 8.25, 15.0],
+            [0.25, 4.0, 1.0],
+        ]
+        .iter()
+        {
+            let color = MunsellColor::new_munsell(*h, *v, *c);
+            let result = renotation
+                .from_xyza(&renotation.to_xyza(&color).unwrap())
+                .unwrap();
+
+            assert_eq!(round(&color), round(&result));
+        }
+    }
+}
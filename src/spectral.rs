@@ -0,0 +1,741 @@
+//!
+//! This module includes spectral power distributions and reflectances sampled
+//! over wavelength together with the CIE standard observers and illuminants
+//! used to integrate them to XYZ
+//!
+
+use crate::{utils, ColorXYZA, WhitePoint};
+
+/// The shortest wavelength in nanometers included when integrating a spectrum
+const INTEGRATION_START: f32 = 360.0;
+/// The longest wavelength in nanometers included when integrating a spectrum
+const INTEGRATION_END: f32 = 830.0;
+/// The number of 1 nm steps used when integrating a spectrum
+const INTEGRATION_STEPS: usize = 470;
+
+/// The second radiation constant of Planck's law in nanometer kelvin
+const PLANCK_C2: f32 = 1.4388e7;
+/// The second radiation constant used in the definition of CIE standard
+/// illuminant A in nanometer kelvin
+const ILLUMINANT_A_C2: f32 = 1.435e7;
+/// The temperature used in the definition of CIE standard illuminant A, it
+/// corresponds to 2856 K with the current value of the radiation constant
+const ILLUMINANT_A_TEMPERATURE: f32 = 2848.0;
+
+/// The wavelength in nanometers of the first entry of the tabulated color
+/// matching functions
+const COLOR_MATCHING_START: f32 = 360.0;
+/// The wavelength step in nanometers of the tabulated color matching functions
+const COLOR_MATCHING_INTERVAL: f32 = 5.0;
+/// The CIE 1931 2 degree color matching functions x, y, z from 360 nm to 830 nm
+/// in steps of 5 nm
+const CIE1931_COLOR_MATCHING: [[f32; 3]; 95] = [
+    [0.0001299, 0.000003917, 0.0006061],
+    [0.0002321, 0.000006965, 0.001086],
+    [0.0004149, 0.00001239, 0.001946],
+    [0.0007416, 0.00002202, 0.003486],
+    [0.001368, 0.000039, 0.00645],
+    [0.002236, 0.000064, 0.01055],
+    [0.004243, 0.00012, 0.02005],
+    [0.00765, 0.000217, 0.03621],
+    [0.01431, 0.000396, 0.06785],
+    [0.02319, 0.00064, 0.1102],
+    [0.04351, 0.00121, 0.2074],
+    [0.07763, 0.00218, 0.3713],
+    [0.13438, 0.004, 0.6456],
+    [0.21477, 0.0073, 1.03905],
+    [0.2839, 0.0116, 1.3856],
+    [0.3285, 0.01684, 1.62296],
+    [0.34828, 0.023, 1.74706],
+    [0.34806, 0.0298, 1.7826],
+    [0.3362, 0.038, 1.77211],
+    [0.3187, 0.048, 1.7441],
+    [0.2908, 0.06, 1.6692],
+    [0.2511, 0.0739, 1.5281],
+    [0.19536, 0.09098, 1.28764],
+    [0.1421, 0.1126, 1.0419],
+    [0.09564, 0.13902, 0.81295],
+    [0.05795, 0.1693, 0.6162],
+    [0.03201, 0.20802, 0.46518],
+    [0.0147, 0.2586, 0.3533],
+    [0.0049, 0.323, 0.272],
+    [0.0024, 0.4073, 0.2123],
+    [0.0093, 0.503, 0.1582],
+    [0.0291, 0.6082, 0.1117],
+    [0.06327, 0.71, 0.07825],
+    [0.1096, 0.7932, 0.05725],
+    [0.1655, 0.862, 0.04216],
+    [0.22575, 0.91485, 0.02984],
+    [0.2904, 0.954, 0.0203],
+    [0.3597, 0.9803, 0.0134],
+    [0.43345, 0.99495, 0.00875],
+    [0.51205, 1.0, 0.00575],
+    [0.5945, 0.995, 0.0039],
+    [0.6784, 0.9786, 0.00275],
+    [0.7621, 0.952, 0.0021],
+    [0.8425, 0.9154, 0.0018],
+    [0.9163, 0.87, 0.00165],
+    [0.9786, 0.8163, 0.0014],
+    [1.0263, 0.757, 0.0011],
+    [1.0567, 0.6949, 0.001],
+    [1.0622, 0.631, 0.0008],
+    [1.0456, 0.5668, 0.0006],
+    [1.0026, 0.503, 0.00034],
+    [0.9384, 0.4412, 0.00024],
+    [0.85445, 0.381, 0.00019],
+    [0.7514, 0.321, 0.0001],
+    [0.6424, 0.265, 0.00005],
+    [0.5419, 0.217, 0.00003],
+    [0.4479, 0.175, 0.00002],
+    [0.3608, 0.1382, 0.00001],
+    [0.2835, 0.107, 0.0],
+    [0.2187, 0.0816, 0.0],
+    [0.1649, 0.061, 0.0],
+    [0.1212, 0.04458, 0.0],
+    [0.0874, 0.032, 0.0],
+    [0.0636, 0.0232, 0.0],
+    [0.04677, 0.017, 0.0],
+    [0.0329, 0.01192, 0.0],
+    [0.0227, 0.00821, 0.0],
+    [0.01584, 0.005723, 0.0],
+    [0.011359, 0.004102, 0.0],
+    [0.008111, 0.002929, 0.0],
+    [0.00579, 0.002091, 0.0],
+    [0.004109, 0.001484, 0.0],
+    [0.002899, 0.001047, 0.0],
+    [0.002049, 0.00074, 0.0],
+    [0.00144, 0.00052, 0.0],
+    [0.001, 0.000361, 0.0],
+    [0.00069, 0.000249, 0.0],
+    [0.000476, 0.000172, 0.0],
+    [0.000332, 0.00012, 0.0],
+    [0.000235, 0.0000848, 0.0],
+    [0.000166, 0.00006, 0.0],
+    [0.000117, 0.0000424, 0.0],
+    [0.000083, 0.00003, 0.0],
+    [0.000059, 0.0000212, 0.0],
+    [0.000042, 0.000015, 0.0],
+    [0.00002984, 0.00001078, 0.0],
+    [0.00002066, 0.000007465, 0.0],
+    [0.00001455, 0.000005257, 0.0],
+    [0.00001025, 0.000003702, 0.0],
+    [0.000007226, 0.000002607, 0.0],
+    [0.000005093, 0.00000184, 0.0],
+    [0.000003597, 0.0000013, 0.0],
+    [0.000002546, 0.0000009198, 0.0],
+    [0.0000018, 0.0000006502, 0.0],
+    [0.000001251, 0.0000004518, 0.0],
+];
+/// The CIE 1964 10 degree color matching functions x, y, z from 360 nm to 830
+/// nm in steps of 5 nm
+const CIE1964_COLOR_MATCHING: [[f32; 3]; 95] = [
+    [0.0000001222, 0.000000013398, 0.000000535027],
+    [0.00000091927, 0.00000010065, 0.0000040283],
+    [0.0000059586, 0.0000006511, 0.0000261437],
+    [0.000033266, 0.000003625, 0.000147023],
+    [0.00016, 0.000017, 0.000705],
+    [0.000662, 0.000072, 0.002928],
+    [0.002362, 0.000253, 0.010482],
+    [0.007242, 0.000769, 0.032344],
+    [0.01911, 0.002004, 0.086011],
+    [0.0434, 0.004509, 0.19712],
+    [0.084736, 0.008756, 0.389366],
+    [0.140638, 0.014456, 0.65676],
+    [0.204492, 0.021391, 0.972542],
+    [0.264737, 0.029497, 1.2825],
+    [0.314679, 0.038676, 1.55348],
+    [0.357719, 0.049602, 1.7985],
+    [0.383734, 0.062077, 1.96728],
+    [0.386726, 0.074704, 2.0273],
+    [0.370702, 0.089456, 1.9948],
+    [0.342957, 0.106256, 1.9007],
+    [0.302273, 0.128201, 1.74537],
+    [0.254085, 0.152761, 1.5549],
+    [0.195618, 0.18519, 1.31756],
+    [0.132349, 0.21994, 1.0302],
+    [0.080507, 0.253589, 0.772125],
+    [0.041072, 0.297665, 0.57006],
+    [0.016172, 0.339133, 0.415254],
+    [0.005132, 0.395379, 0.302356],
+    [0.003816, 0.460777, 0.218502],
+    [0.015444, 0.53136, 0.159249],
+    [0.037465, 0.606741, 0.112044],
+    [0.071358, 0.68566, 0.082248],
+    [0.117749, 0.761757, 0.060709],
+    [0.172953, 0.82333, 0.04305],
+    [0.236491, 0.875211, 0.030451],
+    [0.304213, 0.92381, 0.020584],
+    [0.376772, 0.961988, 0.013676],
+    [0.451584, 0.9822, 0.007918],
+    [0.529826, 0.991761, 0.003988],
+    [0.616053, 0.99911, 0.001091],
+    [0.705224, 0.99734, 0.0],
+    [0.793832, 0.98238, 0.0],
+    [0.878655, 0.955552, 0.0],
+    [0.951162, 0.915175, 0.0],
+    [1.01416, 0.868934, 0.0],
+    [1.0743, 0.825623, 0.0],
+    [1.11852, 0.777405, 0.0],
+    [1.1343, 0.720353, 0.0],
+    [1.12399, 0.658341, 0.0],
+    [1.0891, 0.593878, 0.0],
+    [1.03048, 0.527963, 0.0],
+    [0.95074, 0.461834, 0.0],
+    [0.856297, 0.398057, 0.0],
+    [0.75493, 0.339554, 0.0],
+    [0.647467, 0.283493, 0.0],
+    [0.53511, 0.228254, 0.0],
+    [0.431567, 0.179828, 0.0],
+    [0.34369, 0.140211, 0.0],
+    [0.268329, 0.107633, 0.0],
+    [0.2043, 0.081187, 0.0],
+    [0.152568, 0.060281, 0.0],
+    [0.11221, 0.044096, 0.0],
+    [0.081261, 0.0318, 0.0],
+    [0.05793, 0.022602, 0.0],
+    [0.040851, 0.015905, 0.0],
+    [0.028623, 0.01113, 0.0],
+    [0.019941, 0.007749, 0.0],
+    [0.013842, 0.005375, 0.0],
+    [0.009577, 0.003718, 0.0],
+    [0.006605, 0.002565, 0.0],
+    [0.004553, 0.001768, 0.0],
+    [0.003145, 0.001222, 0.0],
+    [0.002175, 0.000846, 0.0],
+    [0.001506, 0.000586, 0.0],
+    [0.001045, 0.000407, 0.0],
+    [0.000727, 0.000284, 0.0],
+    [0.000508, 0.000199, 0.0],
+    [0.000356, 0.00014, 0.0],
+    [0.000251, 0.000098, 0.0],
+    [0.000178, 0.00007, 0.0],
+    [0.000126, 0.00005, 0.0],
+    [0.00009, 0.000036, 0.0],
+    [0.000065, 0.000025, 0.0],
+    [0.000046, 0.000018, 0.0],
+    [0.000033, 0.000013, 0.0],
+    [0.000023387, 0.0000091843, 0.0],
+    [0.000016479, 0.0000064722, 0.0],
+    [0.00001162, 0.0000045621, 0.0],
+    [0.0000082031, 0.0000032208, 0.0],
+    [0.0000057928, 0.0000022744, 0.0],
+    [0.0000040967, 0.0000016085, 0.0],
+    [0.0000029032, 0.0000011398, 0.0],
+    [0.0000020621, 0.00000080946, 0.0],
+    [0.000001466, 0.00000057547, 0.0],
+    [0.0000010445, 0.0000004101, 0.0],
+];
+/// The mean daylight component S0 of the CIE daylight series from 380 nm to
+/// 780 nm in steps of 10 nm
+const DAYLIGHT_S0: [f32; 41] = [
+    63.4, 65.8, 94.8, 104.8, 105.9, 96.8, 113.9, 125.6, 125.5, 121.3, 121.3, 113.5, 113.1, 110.8,
+    106.5, 108.8, 105.3, 104.4, 100.0, 96.0, 95.1, 89.1, 90.5, 90.3, 88.4, 84.0, 85.1, 81.9, 82.6,
+    84.9, 81.3, 71.9, 74.3, 76.4, 63.3, 71.7, 77.0, 65.2, 47.7, 68.6, 65.0,
+];
+/// The first characteristic vector S1 of the CIE daylight series from 380 nm to
+/// 780 nm in steps of 10 nm
+const DAYLIGHT_S1: [f32; 41] = [
+    38.5, 35.0, 43.4, 46.3, 43.9, 37.1, 36.7, 35.9, 32.6, 27.9, 24.3, 20.1, 16.2, 13.2, 8.6, 6.1,
+    4.2, 1.9, 0.0, -1.6, -3.5, -3.5, -5.8, -7.2, -8.6, -9.5, -10.9, -10.7, -12.0, -14.0, -13.6,
+    -12.0, -13.3, -12.9, -10.6, -11.6, -12.2, -10.2, -7.8, -11.2, -10.4,
+];
+/// The second characteristic vector S2 of the CIE daylight series from 380 nm
+/// to 780 nm in steps of 10 nm
+const DAYLIGHT_S2: [f32; 41] = [
+    3.0, 1.2, -1.1, -0.5, -0.7, -1.2, -2.6, -2.9, -2.8, -2.6, -2.6, -1.8, -1.5, -1.3, -1.2, -1.0,
+    -0.5, -0.3, 0.0, 0.2, 0.5, 2.1, 3.2, 4.1, 4.7, 5.1, 6.7, 7.3, 8.6, 9.8, 10.2, 8.3, 9.6, 8.5,
+    7.0, 7.6, 8.0, 6.7, 5.2, 7.4, 6.8,
+];
+/// The relative spectral power of CIE illuminant F2 from 380 nm to 780 nm in
+/// steps of 5 nm
+const FLUORESCENT_F2: [f32; 81] = [
+    1.18, 1.48, 1.84, 2.15, 3.44, 15.69, 3.85, 3.74, 4.19, 4.62, 5.06, 34.98, 11.81, 6.27, 6.63,
+    6.93, 7.19, 7.40, 7.54, 7.62, 7.65, 7.62, 7.62, 7.45, 7.28, 7.15, 7.05, 7.04, 7.16, 7.47, 8.04,
+    8.88, 10.01, 24.88, 16.64, 14.59, 16.16, 17.56, 18.62, 21.47, 22.79, 19.29, 18.66, 17.73,
+    16.54, 15.21, 13.80, 12.36, 10.95, 9.65, 8.40, 7.32, 6.31, 5.43, 4.68, 4.02, 3.45, 2.96, 2.55,
+    2.19, 1.89, 1.64, 1.53, 1.27, 1.10, 0.99, 0.88, 0.76, 0.68, 0.61, 0.56, 0.54, 0.51, 0.47, 0.47,
+    0.43, 0.46, 0.47, 0.40, 0.33, 0.27,
+];
+/// The relative spectral power of CIE illuminant F11 from 380 nm to 780 nm in
+/// steps of 5 nm
+const FLUORESCENT_F11: [f32; 81] = [
+    0.91, 0.63, 0.46, 0.37, 1.29, 12.68, 1.59, 1.79, 2.46, 3.33, 4.49, 33.94, 12.13, 6.95, 7.19,
+    7.12, 6.72, 6.13, 5.46, 4.79, 5.66, 14.29, 14.96, 8.97, 4.72, 2.33, 1.47, 1.10, 0.89, 0.83,
+    1.18, 4.90, 39.59, 72.84, 32.61, 7.52, 2.83, 1.96, 1.67, 4.43, 11.28, 14.76, 12.73, 9.74, 7.33,
+    9.72, 55.27, 42.58, 13.18, 13.16, 12.26, 5.11, 2.07, 2.34, 3.58, 3.01, 2.48, 2.14, 1.54, 1.33,
+    1.46, 1.94, 2.00, 1.20, 1.35, 4.10, 5.58, 2.51, 0.57, 0.27, 0.23, 0.21, 0.24, 0.24, 0.20, 0.24,
+    0.32, 0.26, 0.16, 0.12, 0.09,
+];
+
+/// A CIE standard colorimetric observer, the color matching functions are
+/// linearly interpolated from the tabulated CIE data
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum Observer {
+    /// The CIE 1931 2 degree standard observer
+    CIE1931,
+    /// The CIE 1964 10 degree supplementary standard observer
+    CIE1964,
+}
+
+impl Observer {
+    /// Retrieves the values of the color matching functions at a wavelength in
+    /// the order: x, y, z, the functions are 0 outside of 360 nm to 830 nm
+    ///
+    /// # Parameters
+    ///
+    /// wavelength: The wavelength in nanometers
+    pub fn get_color_matching(&self, wavelength: f32) -> [f32; 3] {
+        let table = match self {
+            Self::CIE1931 => &CIE1931_COLOR_MATCHING,
+            Self::CIE1964 => &CIE1964_COLOR_MATCHING,
+        };
+
+        let position = (wavelength - COLOR_MATCHING_START) / COLOR_MATCHING_INTERVAL;
+        if !(0.0..=(table.len() - 1) as f32).contains(&position) {
+            return [0.0; 3];
+        }
+
+        let index = (position.floor() as usize).min(table.len() - 2);
+        let fraction = position - index as f32;
+
+        return [0, 1, 2]
+            .map(|i| table[index][i] + (table[index + 1][i] - table[index][i]) * fraction);
+    }
+}
+
+/// The CIE standard illuminants which are bundled with the library, other
+/// illuminants like the LED series can be loaded from their published tables
+/// using Spectrum::new
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum StandardIlluminant {
+    /// CIE standard illuminant A, incandescent light at 2856 K
+    A,
+    /// CIE standard illuminant D50, horizon daylight
+    D50,
+    /// CIE standard illuminant D65, noon daylight
+    D65,
+    /// CIE standard illuminant E, the equal energy illuminant
+    E,
+    /// CIE illuminant F2, cool white fluorescent light
+    F2,
+    /// CIE illuminant F11, narrow band white fluorescent light
+    F11,
+}
+
+impl StandardIlluminant {
+    /// Retrieves the relative spectral power distribution of the illuminant
+    pub fn get_spectrum(&self) -> Spectrum {
+        return match self {
+            Self::A => Spectrum::new_function(INTEGRATION_START, INTEGRATION_END, 5.0, |l| {
+                return 100.0
+                    * (560.0 / l).powi(5)
+                    * planck_ratio(l, ILLUMINANT_A_TEMPERATURE, ILLUMINANT_A_C2);
+            }),
+            Self::D50 => Spectrum::new_daylight(5003.0),
+            Self::D65 => Spectrum::new_daylight(6504.0),
+            Self::E => Spectrum::new(
+                INTEGRATION_START,
+                INTEGRATION_END - INTEGRATION_START,
+                vec![100.0, 100.0],
+            ),
+            Self::F2 => Spectrum::new(380.0, 5.0, FLUORESCENT_F2.to_vec()),
+            Self::F11 => Spectrum::new(380.0, 5.0, FLUORESCENT_F11.to_vec()),
+        };
+    }
+}
+
+/// Calculates the ratio between the spectral radiance of a blackbody at a
+/// wavelength and at 560 nm without the wavelength to the fifth power, it is
+/// written to avoid overflow at low temperatures
+///
+/// # Parameters
+///
+/// wavelength: The wavelength in nanometers
+///
+/// kelvin: The temperature of the blackbody in kelvin
+///
+/// c2: The second radiation constant in nanometer kelvin
+fn planck_ratio(wavelength: f32, kelvin: f32, c2: f32) -> f32 {
+    let reference = c2 / (560.0 * kelvin);
+    let exponent = c2 / (wavelength * kelvin);
+
+    return (reference - exponent).exp() * (-(-reference).exp_m1()) / (-(-exponent).exp_m1());
+}
+
+/// A spectral distribution sampled at evenly spaced wavelengths, it may hold
+/// the spectral power of a light source or the reflectance of a surface,
+/// between the samples the spectrum is interpolated linearly and outside of the
+/// samples it is 0
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct Spectrum {
+    /// The wavelength of the first sample in nanometers
+    start: f32,
+    /// The distance between two samples in nanometers
+    interval: f32,
+    /// The values of the samples
+    values: Vec<f32>,
+}
+
+impl Spectrum {
+    /// Constructs a new spectrum from evenly spaced samples, the interval is
+    /// clamped to be positive
+    ///
+    /// # Parameters
+    ///
+    /// start: The wavelength of the first sample in nanometers
+    ///
+    /// interval: The distance between two samples in nanometers
+    ///
+    /// values: The values of the samples
+    pub fn new(start: f32, interval: f32, values: Vec<f32>) -> Self {
+        return Self {
+            start: start,
+            interval: interval.max(f32::EPSILON),
+            values: values,
+        };
+    }
+
+    /// Constructs a new spectrum by evaluating a function at evenly spaced
+    /// wavelengths, the interval is clamped to be positive
+    ///
+    /// # Parameters
+    ///
+    /// start: The wavelength of the first sample in nanometers
+    ///
+    /// end: The wavelength of the last sample in nanometers
+    ///
+    /// interval: The distance between two samples in nanometers
+    ///
+    /// function: The function giving the value of a sample from its wavelength
+    pub fn new_function(
+        start: f32,
+        end: f32,
+        interval: f32,
+        function: impl Fn(f32) -> f32,
+    ) -> Self {
+        let interval = interval.max(f32::EPSILON);
+        let count = ((end - start) / interval).round().max(0.0) as usize + 1;
+        let values = (0..count)
+            .map(|i| function(start + interval * i as f32))
+            .collect();
+
+        return Self::new(start, interval, values);
+    }
+
+    /// Constructs a new spectrum by resampling measurements taken at arbitrary
+    /// wavelengths like the output of a spectrometer, the measurements are
+    /// interpolated linearly
+    ///
+    /// # Parameters
+    ///
+    /// samples: The measurements given as pairs of wavelength in nanometers
+    /// and value, they must be sorted by wavelength
+    ///
+    /// interval: The distance between two samples of the new spectrum in
+    /// nanometers
+    pub fn new_resampled(samples: &[[f32; 2]], interval: f32) -> Self {
+        if samples.is_empty() {
+            return Self::new(INTEGRATION_START, interval, Vec::new());
+        }
+
+        let start = samples[0][0];
+        let end = samples[samples.len() - 1][0];
+
+        return Self::new_function(start, end, interval, |l| {
+            let index = samples.partition_point(|sample| sample[0] < l);
+            if index == 0 {
+                return samples[0][1];
+            }
+            if index == samples.len() {
+                return samples[samples.len() - 1][1];
+            }
+
+            let [l0, v0] = samples[index - 1];
+            let [l1, v1] = samples[index];
+            let t = if l1 > l0 { (l - l0) / (l1 - l0) } else { 0.0 };
+
+            return v0 + (v1 - v0) * t;
+        });
+    }
+
+    /// Constructs the relative spectral power distribution of a blackbody
+    /// radiator from Planck's law, it is normalized to 100 at 560 nm
+    ///
+    /// # Parameters
+    ///
+    /// kelvin: The temperature of the blackbody in kelvin, it must be positive
+    pub fn new_blackbody(kelvin: f32) -> Self {
+        return Self::new_function(INTEGRATION_START, INTEGRATION_END, 5.0, |l| {
+            return 100.0 * (560.0 / l).powi(5) * planck_ratio(l, kelvin, PLANCK_C2);
+        });
+    }
+
+    /// Constructs the relative spectral power distribution of the CIE daylight
+    /// illuminant with a given correlated color temperature, it is normalized to
+    /// 100 at 560 nm
+    ///
+    /// # Parameters
+    ///
+    /// kelvin: The correlated color temperature of the daylight in kelvin, it is
+    /// clamped to between 4000 and 25000
+    pub fn new_daylight(kelvin: f32) -> Self {
+        let xyy = utils::daylight_to_xyy(kelvin);
+        let x = xyy.get_x();
+        let y = xyy.get_y();
+        let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+        let m1 = (-1.3515 - 1.7703 * x + 5.9114 * y) / m;
+        let m2 = (0.0300 - 31.4424 * x + 30.0717 * y) / m;
+        let values = (0..DAYLIGHT_S0.len())
+            .map(|i| DAYLIGHT_S0[i] + m1 * DAYLIGHT_S1[i] + m2 * DAYLIGHT_S2[i])
+            .collect();
+
+        return Self::new(380.0, 10.0, values);
+    }
+
+    /// Retrieves the wavelength of the first sample in nanometers
+    pub fn get_start(&self) -> f32 {
+        return self.start;
+    }
+
+    /// Retrieves the wavelength of the last sample in nanometers
+    pub fn get_end(&self) -> f32 {
+        return self.start + self.interval * (self.values.len().max(1) - 1) as f32;
+    }
+
+    /// Retrieves the distance between two samples in nanometers
+    pub fn get_interval(&self) -> f32 {
+        return self.interval;
+    }
+
+    /// Retrieves the values of all the samples
+    pub fn get_values(&self) -> &[f32] {
+        return &self.values;
+    }
+
+    /// Retrieves the value of the spectrum at any wavelength, the samples are
+    /// interpolated linearly and the value is 0 outside of the samples
+    ///
+    /// # Parameters
+    ///
+    /// wavelength: The wavelength in nanometers
+    pub fn get_value(&self, wavelength: f32) -> f32 {
+        let position = (wavelength - self.start) / self.interval;
+        if self.values.is_empty() || position < 0.0 || position > (self.values.len() - 1) as f32 {
+            return 0.0;
+        }
+
+        let index = (position as usize).min(self.values.len().saturating_sub(2));
+        if index + 1 >= self.values.len() {
+            return self.values[index];
+        }
+        let t = position - index as f32;
+
+        return self.values[index] * (1.0 - t) + self.values[index + 1] * t;
+    }
+
+    /// Integrates the spectrum weighted by the color matching functions of an
+    /// observer, the result is not normalized
+    ///
+    /// # Parameters
+    ///
+    /// observer: The observer to use the color matching functions of
+    ///
+    /// weight: An additional weight of every wavelength like the power of an
+    /// illuminant
+    fn integrate(&self, observer: Observer, weight: impl Fn(f32) -> f32) -> [f32; 3] {
+        let mut xyz = [0.0; 3];
+        for i in 0..=INTEGRATION_STEPS {
+            let wavelength = INTEGRATION_START + i as f32;
+            let value = self.get_value(wavelength) * weight(wavelength);
+            let matching = observer.get_color_matching(wavelength);
+            for (sum, component) in xyz.iter_mut().zip(matching.iter()) {
+                *sum += value * component;
+            }
+        }
+
+        return xyz;
+    }
+
+    /// Calculates the color of the spectrum seen as a light source, the result
+    /// is relative and normalized such that Y is 1, a spectrum without any
+    /// power is black
+    ///
+    /// # Parameters
+    ///
+    /// observer: The observer to use the color matching functions of
+    pub fn get_xyza(&self, observer: Observer) -> ColorXYZA {
+        let [x, y, z] = self.integrate(observer, |_| 1.0);
+        if y <= 0.0 {
+            return ColorXYZA::new_xyz(0.0, 0.0, 0.0);
+        }
+
+        return ColorXYZA::new_xyz(x / y, 1.0, z / y);
+    }
+
+    /// Calculates the white point of the spectrum seen as a light source, it
+    /// can be used with the color types which take a white point
+    ///
+    /// # Parameters
+    ///
+    /// observer: The observer to use the color matching functions of
+    pub fn get_white_point(&self, observer: Observer) -> WhitePoint {
+        let xyz = self.get_xyza(observer);
+
+        return WhitePoint::new(xyz.get_x(), xyz.get_z());
+    }
+
+    /// Calculates the color of the spectrum seen as the reflectance of a surface
+    /// lit by an illuminant, the result is normalized such that a perfect
+    /// reflector has a Y of 1, no chromatic adaptation is applied so a perfect
+    /// reflector has the chromaticity of the illuminant
+    ///
+    /// # Parameters
+    ///
+    /// illuminant: The spectral power distribution of the light source
+    ///
+    /// observer: The observer to use the color matching functions of
+    pub fn get_reflectance_xyza(&self, illuminant: &Spectrum, observer: Observer) -> ColorXYZA {
+        let [x, y, z] = self.integrate(observer, |l| illuminant.get_value(l));
+        let [_, white, _] = illuminant.integrate(observer, |_| 1.0);
+        if white <= 0.0 {
+            return ColorXYZA::new_xyz(0.0, 0.0, 0.0);
+        }
+
+        return ColorXYZA::new_xyz(x / white, y / white, z / white);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that the chromaticity of a color is within a tolerance of the
+    /// expected chromaticity
+    fn assert_chromaticity(expected: [f32; 2], color: &ColorXYZA, tolerance: f32) {
+        let xyy = utils::xyz_to_xyy(color);
+
+        assert!(
+            (expected[0] - xyy.get_x()).abs() < tolerance
+                && (expected[1] - xyy.get_y()).abs() < tolerance,
+            "expected {:?} but got {:?}",
+            expected,
+            [xyy.get_x(), xyy.get_y()]
+        );
+    }
+
+    /// Test the tabulated color matching functions
+    #[test]
+    fn color_matching() {
+        let peak = Observer::CIE1931.get_color_matching(555.0);
+        let between = Observer::CIE1964.get_color_matching(552.5);
+
+        assert_eq!([0.51205, 1.0, 0.00575], peak);
+        assert_eq!(
+            [572940, 995436, 2540],
+            between.map(|value| (value * 1000000.0).round() as i32)
+        );
+        assert_eq!([0.0; 3], Observer::CIE1931.get_color_matching(355.0));
+        assert_eq!([0.0; 3], Observer::CIE1964.get_color_matching(835.0));
+        assert!(Observer::CIE1964.get_color_matching(830.0)[1] > 0.0);
+    }
+
+    /// Test the chromaticities of the standard illuminants with the 2 degree
+    /// observer
+    #[test]
+    fn standard_illuminants() {
+        let values = [
+            (StandardIlluminant::A, [0.44757, 0.40745]),
+            (StandardIlluminant::D50, [0.34567, 0.35850]),
+            (StandardIlluminant::D65, [0.31271, 0.32902]),
+            (StandardIlluminant::E, [1.0 / 3.0, 1.0 / 3.0]),
+            (StandardIlluminant::F2, [0.37208, 0.37529]),
+            (StandardIlluminant::F11, [0.38052, 0.37713]),
+        ];
+
+        for (illuminant, xy) in values.iter() {
+            assert_chromaticity(
+                *xy,
+                &illuminant.get_spectrum().get_xyza(Observer::CIE1931),
+                0.0003,
+            );
+        }
+    }
+
+    /// Test the chromaticities of the standard illuminants with the 10 degree
+    /// observer
+    #[test]
+    fn supplementary_observer() {
+        let values = [
+            (StandardIlluminant::A, [0.45117, 0.40594]),
+            (StandardIlluminant::D65, [0.31382, 0.33100]),
+        ];
+
+        for (illuminant, xy) in values.iter() {
+            assert_chromaticity(
+                *xy,
+                &illuminant.get_spectrum().get_xyza(Observer::CIE1964),
+                0.0002,
+            );
+        }
+    }
+
+    /// Test that blackbody spectra integrate to the Planckian locus
+    #[test]
+    fn blackbody() {
+        for kelvin in [2000.0, 2856.0, 4000.0, 6500.0, 10000.0].iter() {
+            let xyy = utils::blackbody_to_xyy(*kelvin);
+
+            assert_chromaticity(
+                [xyy.get_x(), xyy.get_y()],
+                &Spectrum::new_blackbody(*kelvin).get_xyza(Observer::CIE1931),
+                0.0005,
+            );
+        }
+    }
+
+    /// Test the colors of reflectances under an illuminant
+    #[test]
+    fn reflectance() {
+        let d65 = StandardIlluminant::D65.get_spectrum();
+        let white = Spectrum::new(380.0, 400.0, vec![1.0, 1.0]);
+        let gray = Spectrum::new(380.0, 400.0, vec![0.2, 0.2]);
+        let red =
+            Spectrum::new_function(380.0, 780.0, 10.0, |l| if l > 600.0 { 0.9 } else { 0.05 });
+
+        assert_eq!(
+            [1000, 1000],
+            [
+                white.get_reflectance_xyza(&d65, Observer::CIE1931).get_y(),
+                gray.get_reflectance_xyza(&d65, Observer::CIE1931).get_y() * 5.0
+            ]
+            .map(|value| (value * 1000.0).round() as i32)
+        );
+        assert_chromaticity(
+            [0.31271, 0.32902],
+            &white.get_reflectance_xyza(&d65, Observer::CIE1931),
+            0.0002,
+        );
+
+        let rgb = utils::xyz_to_rgb(&red.get_reflectance_xyza(&d65, Observer::CIE1931));
+        assert!(rgb.get_red() > 0.8 && rgb.get_green() < 0.4 && rgb.get_blue() < 0.4);
+    }
+
+    /// Test resampling of irregularly sampled spectra
+    #[test]
+    fn resampled() {
+        let spectrum = Spectrum::new_resampled(&[[400.0, 0.0], [420.0, 1.0], [500.0, 0.2]], 10.0);
+
+        assert_eq!(400.0, spectrum.get_start());
+        assert_eq!(500.0, spectrum.get_end());
+        assert_eq!(
+            vec![0, 500, 1000, 900, 800, 700, 600, 500, 400, 300, 200],
+            spectrum
+                .get_values()
+                .iter()
+                .map(|value| (value * 1000.0).round() as i32)
+                .collect::<Vec<i32>>()
+        );
+        assert_eq!(950, (spectrum.get_value(425.0) * 1000.0).round() as i32);
+        assert_eq!(0.0, spectrum.get_value(600.0));
+    }
+}
@@ -1,11 +1,18 @@
-//! 
+//!
 //! This module contains utility functions for use in color conversion.
 //! Currently it contains functions to convert between any base color
 //! representation used in this library. Other utility functions may be added
 //! later.
-//! 
+//!
 
-use crate::{ColorHSIA, ColorHSLA, ColorHSVA, ColorRGBA};
+use crate::{
+    BlackGeneration, CmykSeparation, Color, ColorCAM16A, ColorCAM16UCSA, ColorCMYKA, ColorHPLuvA,
+    ColorHSIA, ColorHSLA, ColorHSLuvA, ColorHSVA, ColorHWBA, ColorICtCpA, ColorJzCzhzA,
+    ColorJzazbzA, ColorLChA, ColorLChuvA, ColorLabA, ColorLinearRGBA, ColorLuvA, ColorOKLChA,
+    ColorOKLabA, ColorOkHSLA, ColorOkHSVA, ColorRGBA, ColorRYBA, ColorSpaceRGBA, ColorXYZA,
+    ColorYCbCrA, ColorYCoCgA, ColorYCoCgRA, ColorYIQA, ColorYUVA, ColorxyYA, RgbSpace,
+    ViewingConditions, WhitePoint, YCbCrMatrix, YCbCrRange,
+};
 
 /// A color in the hue, croma, minimum, alpha space, used to convert between HSx and RGB colors
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -113,8 +120,40 @@ impl ColorHCMA {
         };
     }
 
+    /// Converts from HWB
+    fn from_hwb(color: &ColorHWBA) -> Self {
+        let sum = color.get_whiteness() + color.get_blackness();
+
+        // Normalize the whiteness and blackness if the color is achromatic
+        let (w, b) = if sum > 1.0 {
+            (color.get_whiteness() / sum, color.get_blackness() / sum)
+        } else {
+            (color.get_whiteness(), color.get_blackness())
+        };
+
+        return Self {
+            h: color.get_hue(),
+            c: 1.0 - w - b,
+            m: w,
+            a: color.get_alpha(),
+        };
+    }
+
+    /// Converts from RYB by treating the red, yellow and blue components as the
+    /// primaries of the hexcone
+    fn from_ryb(color: &ColorRYBA) -> Self {
+        return Self::from_rgb(&unsafe {
+            ColorRGBA::new_unsafe(
+                color.get_red(),
+                color.get_yellow(),
+                color.get_blue(),
+                color.get_alpha(),
+            )
+        });
+    }
+
     /// Converts to RGB
-    fn to_rgb(&self) -> ColorRGBA {
+    fn to_rgb(self) -> ColorRGBA {
         // Calculate temporary parameters for use in the calculations
         let hp = self.h * 6.0;
         let z = 1.0 - (hp.rem_euclid(2.0) - 1.0).abs();
@@ -142,7 +181,7 @@ impl ColorHCMA {
     }
 
     /// Converts to HSV
-    fn to_hsv(&self) -> ColorHSVA {
+    fn to_hsv(self) -> ColorHSVA {
         let v = self.m + self.c;
         let s = if v == 0.0 { 0.0 } else { self.c / v };
 
@@ -150,7 +189,7 @@ impl ColorHCMA {
     }
 
     /// Converts to HSL
-    fn to_hsl(&self) -> ColorHSLA {
+    fn to_hsl(self) -> ColorHSLA {
         let l = self.m + 0.5 * self.c;
         let z = 1.0 - (2.0 * l - 1.0).abs();
         let s = if z == 0.0 { 0.0 } else { self.c / z };
@@ -159,508 +198,6004 @@ impl ColorHCMA {
     }
 
     /// Converts to HSI
-    fn to_hsi(&self) -> ColorHSIA {
+    fn to_hsi(self) -> ColorHSIA {
         let z = 1.0 - ((6.0 * self.h).rem_euclid(2.0) - 1.0).abs();
         let i = self.m + self.c * (1.0 + z) / 3.0;
         let s = if i == 0.0 { 0.0 } else { 1.0 - self.m / i };
 
         return unsafe { ColorHSIA::new_unsafe(self.h, s, i, self.a) };
     }
+
+    /// Converts to HWB
+    fn to_hwb(self) -> ColorHWBA {
+        return unsafe { ColorHWBA::new_unsafe(self.h, self.m, 1.0 - self.m - self.c, self.a) };
+    }
+
+    /// Converts to RYB by treating the primaries of the hexcone as the red,
+    /// yellow and blue components
+    fn to_ryb(self) -> ColorRYBA {
+        let rgb = self.to_rgb();
+
+        return unsafe {
+            ColorRYBA::new_unsafe(
+                rgb.get_red(),
+                rgb.get_green(),
+                rgb.get_blue(),
+                rgb.get_alpha(),
+            )
+        };
+    }
+}
+
+impl From<ColorRGBA> for ColorHSVA {
+    fn from(color: ColorRGBA) -> Self {
+        return ColorHCMA::from_rgb(&color).to_hsv();
+    }
+}
+
+impl From<ColorRGBA> for ColorHSLA {
+    fn from(color: ColorRGBA) -> Self {
+        return ColorHCMA::from_rgb(&color).to_hsl();
+    }
+}
+
+impl From<ColorRGBA> for ColorHSIA {
+    fn from(color: ColorRGBA) -> Self {
+        return ColorHCMA::from_rgb(&color).to_hsi();
+    }
+}
+
+impl From<ColorHSVA> for ColorRGBA {
+    fn from(color: ColorHSVA) -> Self {
+        return ColorHCMA::from_hsv(&color).to_rgb();
+    }
+}
+
+impl From<ColorHSVA> for ColorHSLA {
+    fn from(color: ColorHSVA) -> Self {
+        return ColorHCMA::from_hsv(&color).to_hsl();
+    }
+}
+
+impl From<ColorHSVA> for ColorHSIA {
+    fn from(color: ColorHSVA) -> Self {
+        return ColorHCMA::from_hsv(&color).to_hsi();
+    }
+}
+
+impl From<ColorHSLA> for ColorRGBA {
+    fn from(color: ColorHSLA) -> Self {
+        return ColorHCMA::from_hsl(&color).to_rgb();
+    }
+}
+
+impl From<ColorHSLA> for ColorHSVA {
+    fn from(color: ColorHSLA) -> Self {
+        return ColorHCMA::from_hsl(&color).to_hsv();
+    }
+}
+
+impl From<ColorHSLA> for ColorHSIA {
+    fn from(color: ColorHSLA) -> Self {
+        return ColorHCMA::from_hsl(&color).to_hsi();
+    }
+}
+
+impl From<ColorHSIA> for ColorRGBA {
+    fn from(color: ColorHSIA) -> Self {
+        return ColorHCMA::from_hsi(&color).to_rgb();
+    }
+}
+
+impl From<ColorHSIA> for ColorHSVA {
+    fn from(color: ColorHSIA) -> Self {
+        return ColorHCMA::from_hsi(&color).to_hsv();
+    }
+}
+
+impl From<ColorHSIA> for ColorHSLA {
+    fn from(color: ColorHSIA) -> Self {
+        return ColorHCMA::from_hsi(&color).to_hsl();
+    }
 }
 
 /// Converts a RGB color to HSV representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The RGB color to convert
+#[deprecated(note = "use ColorHSVA::from or Color::convert instead")]
 pub fn rgb_to_hsv(color: &ColorRGBA) -> ColorHSVA {
-    return ColorHCMA::from_rgb(color).to_hsv();
+    return ColorHSVA::from(*color);
 }
 
 /// Converts a RGB color to HSL representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The RGB color to convert
+#[deprecated(note = "use ColorHSLA::from or Color::convert instead")]
 pub fn rgb_to_hsl(color: &ColorRGBA) -> ColorHSLA {
-    return ColorHCMA::from_rgb(color).to_hsl();
+    return ColorHSLA::from(*color);
 }
 
 /// Converts a RGB color to HSI representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The RGB color to convert
+#[deprecated(note = "use ColorHSIA::from or Color::convert instead")]
 pub fn rgb_to_hsi(color: &ColorRGBA) -> ColorHSIA {
-    return ColorHCMA::from_rgb(color).to_hsi();
+    return ColorHSIA::from(*color);
 }
 
 /// Converts a HSV color to HSL representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSV color to convert
+#[deprecated(note = "use ColorHSLA::from or Color::convert instead")]
 pub fn hsv_to_hsl(color: &ColorHSVA) -> ColorHSLA {
-    return ColorHCMA::from_hsv(color).to_hsl();
+    return ColorHSLA::from(*color);
 }
 
 /// Converts a HSV color to HSI representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSV color to convert
+#[deprecated(note = "use ColorHSIA::from or Color::convert instead")]
 pub fn hsv_to_hsi(color: &ColorHSVA) -> ColorHSIA {
-    return ColorHCMA::from_hsv(color).to_hsi();
+    return ColorHSIA::from(*color);
 }
 
 /// Converts a HSV color to RGB representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSV color to convert
+#[deprecated(note = "use ColorRGBA::from or Color::convert instead")]
 pub fn hsv_to_rgb(color: &ColorHSVA) -> ColorRGBA {
-    return ColorHCMA::from_hsv(color).to_rgb();
+    return ColorRGBA::from(*color);
 }
 
 /// Converts a HSL color to HSI representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSL color to convert
+#[deprecated(note = "use ColorHSIA::from or Color::convert instead")]
 pub fn hsl_to_hsi(color: &ColorHSLA) -> ColorHSIA {
-    return ColorHCMA::from_hsl(color).to_hsi();
+    return ColorHSIA::from(*color);
 }
 
 /// Converts a HSL color to RGB representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSL color to convert
+#[deprecated(note = "use ColorRGBA::from or Color::convert instead")]
 pub fn hsl_to_rgb(color: &ColorHSLA) -> ColorRGBA {
-    return ColorHCMA::from_hsl(color).to_rgb();
+    return ColorRGBA::from(*color);
 }
 
 /// Converts a HSL color to HSV representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSL color to convert
+#[deprecated(note = "use ColorHSVA::from or Color::convert instead")]
 pub fn hsl_to_hsv(color: &ColorHSLA) -> ColorHSVA {
-    return ColorHCMA::from_hsl(color).to_hsv();
+    return ColorHSVA::from(*color);
 }
 
 /// Converts a HSI color to RGB representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSI color to convert
+#[deprecated(note = "use ColorRGBA::from or Color::convert instead")]
 pub fn hsi_to_rgb(color: &ColorHSIA) -> ColorRGBA {
-    return ColorHCMA::from_hsi(color).to_rgb();
+    return ColorRGBA::from(*color);
 }
 
 /// Converts a HSI color to HSV representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSI color to convert
+#[deprecated(note = "use ColorHSVA::from or Color::convert instead")]
 pub fn hsi_to_hsv(color: &ColorHSIA) -> ColorHSVA {
-    return ColorHCMA::from_hsi(color).to_hsv();
+    return ColorHSVA::from(*color);
 }
 
 /// Converts a HSI color to HSL representation
-/// 
+///
 /// # Parameters
-/// 
+///
 /// color: The HSI color to convert
+#[deprecated(note = "use ColorHSLA::from or Color::convert instead")]
 pub fn hsi_to_hsl(color: &ColorHSIA) -> ColorHSLA {
-    return ColorHCMA::from_hsi(color).to_hsl();
+    return ColorHSLA::from(*color);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Converts a RGB color to HWB representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_hwb(color: &ColorRGBA) -> ColorHWBA {
+    return ColorHCMA::from_rgb(color).to_hwb();
+}
 
-    /// Retrieves all test colors
-    fn get_test_values() -> [(ColorHCMA, ColorRGBA, ColorHSVA, ColorHSLA, ColorHSIA); 19] {
-        return [
-            (
-                ColorHCMA {
-                    h: 0.0,
-                    c: 0.0,
-                    m: 1.0,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
-                ColorHSVA::new_hsv(0.0, 0.0, 1.0),
-                ColorHSLA::new_hsl(0.0, 0.0, 1.0),
-                ColorHSIA::new_hsi(0.0, 0.0, 1.0),
-            ),
-            (
-                ColorHCMA {
-                    h: 0.0,
-                    c: 0.0,
-                    m: 0.5,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
-                ColorHSVA::new_hsv(0.0, 0.0, 0.5),
-                ColorHSLA::new_hsl(0.0, 0.0, 0.5),
-                ColorHSIA::new_hsi(0.0, 0.0, 0.5),
-            ),
-            (
-                ColorHCMA {
-                    h: 0.0,
-                    c: 0.0,
-                    m: 0.0,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
-                ColorHSVA::new_hsv(0.0, 0.0, 0.0),
-                ColorHSLA::new_hsl(0.0, 0.0, 0.0),
-                ColorHSIA::new_hsi(0.0, 0.0, 0.0),
-            ),
-            (
-                ColorHCMA {
-                    h: 0.0,
-                    c: 1.0,
-                    m: 0.0,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
-                ColorHSVA::new_hsv(0.0, 1.0, 1.0),
-                ColorHSLA::new_hsl(0.0, 1.0, 0.5),
-                ColorHSIA::new_hsi(0.0, 1.0, 0.3333),
-            ),
-            (
-                ColorHCMA {
-                    h: 60.0 / 360.0,
-                    c: 0.75,
-                    m: 0.0,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.75, 0.75, 0.0),
-                ColorHSVA::new_hsv(60.0 / 360.0, 1.0, 0.75),
-                ColorHSLA::new_hsl(60.0 / 360.0, 1.0, 0.375),
-                ColorHSIA::new_hsi(60.0 / 360.0, 1.0, 0.5),
-            ),
-            (
-                ColorHCMA {
-                    h: 120.0 / 360.0,
-                    c: 0.5,
-                    m: 0.0,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.0, 0.5, 0.0),
-                ColorHSVA::new_hsv(120.0 / 360.0, 1.0, 0.5),
-                ColorHSLA::new_hsl(120.0 / 360.0, 1.0, 0.25),
-                ColorHSIA::new_hsi(120.0 / 360.0, 1.0, 0.1667),
-            ),
-            (
-                ColorHCMA {
-                    h: 180.0 / 360.0,
-                    c: 0.5,
-                    m: 0.5,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.5, 1.0, 1.0),
-                ColorHSVA::new_hsv(180.0 / 360.0, 0.5, 1.0),
-                ColorHSLA::new_hsl(180.0 / 360.0, 1.0, 0.75),
-                ColorHSIA::new_hsi(180.0 / 360.0, 0.4, 0.833),
-            ),
-            (
-                ColorHCMA {
-                    h: 240.0 / 360.0,
-                    c: 0.5,
-                    m: 0.5,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.5, 0.5, 1.0),
-                ColorHSVA::new_hsv(240.0 / 360.0, 0.5, 1.0),
-                ColorHSLA::new_hsl(240.0 / 360.0, 1.0, 0.75),
-                ColorHSIA::new_hsi(240.0 / 360.0, 0.25, 0.667),
-            ),
-            (
-                ColorHCMA {
-                    h: 300.0 / 360.0,
-                    c: 0.5,
-                    m: 0.25,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.75, 0.25, 0.75),
-                ColorHSVA::new_hsv(300.0 / 360.0, 0.667, 0.75),
-                ColorHSLA::new_hsl(300.0 / 360.0, 0.5, 0.5),
-                ColorHSIA::new_hsi(300.0 / 360.0, 0.571, 0.5834),
-            ),
-            (
-                ColorHCMA {
-                    h: 61.8 / 360.0,
-                    c: 0.501,
-                    m: 0.142,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.628, 0.643, 0.142),
-                ColorHSVA::new_hsv(61.8 / 360.0, 0.779, 0.643),
-                ColorHSLA::new_hsl(61.8 / 360.0, 0.638, 0.3924),
-                ColorHSIA::new_hsi(61.8 / 360.0, 0.699, 0.471),
-            ),
-            (
-                ColorHCMA {
-                    h: 251.1 / 360.0,
-                    c: 0.814,
-                    m: 0.104,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.255, 0.104, 0.918),
-                ColorHSVA::new_hsv(251.1 / 360.0, 0.887, 0.918),
-                ColorHSLA::new_hsl(251.1 / 360.0, 0.832, 0.511),
-                ColorHSIA::new_hsi(251.1 / 360.0, 0.7555, 0.4255),
-            ),
-            (
-                ColorHCMA {
-                    h: 134.9 / 360.0,
-                    c: 0.559,
-                    m: 0.116,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.116, 0.675, 0.255),
-                ColorHSVA::new_hsv(134.9 / 360.0, 0.828, 0.675),
-                ColorHSLA::new_hsl(134.9 / 360.0, 0.7065, 0.3955),
-                ColorHSIA::new_hsi(134.9 / 360.0, 0.667, 0.349),
-            ),
-            (
-                ColorHCMA {
-                    h: 49.5 / 360.0,
-                    c: 0.888,
-                    m: 0.053,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.9405, 0.7855, 0.053),
-                ColorHSVA::new_hsv(49.5 / 360.0, 0.944, 0.941),
-                ColorHSLA::new_hsl(49.5 / 360.0, 0.893, 0.497),
-                ColorHSIA::new_hsi(49.5 / 360.0, 0.911, 0.593),
-            ),
-            (
-                ColorHCMA {
-                    h: 283.7 / 360.0,
-                    c: 0.710,
-                    m: 0.187,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.704, 0.187, 0.897),
-                ColorHSVA::new_hsv(283.7 / 360.0, 0.792, 0.897),
-                ColorHSLA::new_hsl(283.7 / 360.0, 0.775, 0.542),
-                ColorHSIA::new_hsi(283.7 / 360.0, 0.686, 0.596),
-            ),
-            (
-                ColorHCMA {
-                    h: 14.3 / 360.0,
-                    c: 0.615,
-                    m: 0.316,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.931, 0.463, 0.316),
-                ColorHSVA::new_hsv(14.3 / 360.0, 0.661, 0.931),
-                ColorHSLA::new_hsl(14.3 / 360.0, 0.81749, 0.6239),
-                ColorHSIA::new_hsi(14.3 / 360.0, 0.4454, 0.570),
-            ),
-            (
-                ColorHCMA {
-                    h: 56.9 / 360.0,
-                    c: 0.466,
-                    m: 0.532,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.998, 0.974, 0.532),
-                ColorHSVA::new_hsv(56.9 / 360.0, 0.467, 0.998),
+/// Converts a HSV color to HWB representation
+///
+/// # Parameters
+///
+/// color: The HSV color to convert
+pub fn hsv_to_hwb(color: &ColorHSVA) -> ColorHWBA {
+    return ColorHCMA::from_hsv(color).to_hwb();
+}
+
+/// Converts a HSL color to HWB representation
+///
+/// # Parameters
+///
+/// color: The HSL color to convert
+pub fn hsl_to_hwb(color: &ColorHSLA) -> ColorHWBA {
+    return ColorHCMA::from_hsl(color).to_hwb();
+}
+
+/// Converts a HSI color to HWB representation
+///
+/// # Parameters
+///
+/// color: The HSI color to convert
+pub fn hsi_to_hwb(color: &ColorHSIA) -> ColorHWBA {
+    return ColorHCMA::from_hsi(color).to_hwb();
+}
+
+/// Converts a HWB color to RGB representation
+///
+/// # Parameters
+///
+/// color: The HWB color to convert
+pub fn hwb_to_rgb(color: &ColorHWBA) -> ColorRGBA {
+    return ColorHCMA::from_hwb(color).to_rgb();
+}
+
+/// Converts a HWB color to HSV representation
+///
+/// # Parameters
+///
+/// color: The HWB color to convert
+pub fn hwb_to_hsv(color: &ColorHWBA) -> ColorHSVA {
+    return ColorHCMA::from_hwb(color).to_hsv();
+}
+
+/// Converts a HWB color to HSL representation
+///
+/// # Parameters
+///
+/// color: The HWB color to convert
+pub fn hwb_to_hsl(color: &ColorHWBA) -> ColorHSLA {
+    return ColorHCMA::from_hwb(color).to_hsl();
+}
+
+/// Converts a HWB color to HSI representation
+///
+/// # Parameters
+///
+/// color: The HWB color to convert
+pub fn hwb_to_hsi(color: &ColorHWBA) -> ColorHSIA {
+    return ColorHCMA::from_hwb(color).to_hsi();
+}
+
+/// The RGB colors at the corners of the RYB cube given by Gossett and Chen, the
+/// corners are indexed by red + 2 * yellow + 4 * blue such that the first
+/// corner is the white paper and the last is black
+const RYB_CORNERS: [[f32; 3]; 8] = [
+    [1.0, 1.0, 1.0],
+    [1.0, 0.0, 0.0],
+    [1.0, 1.0, 0.0],
+    [1.0, 0.5, 0.0],
+    [0.163, 0.373, 0.6],
+    [0.5, 0.0, 0.5],
+    [0.0, 0.66, 0.2],
+    [0.2, 0.094, 0.0],
+];
+
+/// The largest number of iterations used to invert the RYB cube
+const RYB_ITERATIONS: usize = 32;
+
+/// The damping added to the normal equations when inverting the RYB cube
+const RYB_DAMPING: f32 = 1e-6;
+
+/// The distance in RGB at which the inversion of the RYB cube stops, it is
+/// small as the distance only decreases quadratically towards the folded
+/// edges of the cube
+const RYB_TOLERANCE: f32 = 1e-10;
+
+/// Interpolates the RYB cube trilinearly, the result is returned in the order:
+/// the RGB components, the Jacobian of the RGB components with respect to the
+/// RYB components
+///
+/// # Parameters
+///
+/// ryb: The red, yellow and blue components
+fn ryb_interpolate(ryb: &[f32; 3]) -> ([f32; 3], [[f32; 3]; 3]) {
+    let mut rgb = [0.0; 3];
+    let mut jacobian = [[0.0; 3]; 3];
+    for (corner, color) in RYB_CORNERS.iter().enumerate() {
+        // The weight of the corner along each axis and its derivative
+        let factors = [0, 1, 2].map(|axis| {
+            if (corner >> axis) & 1 == 1 {
+                (ryb[axis], 1.0)
+            } else {
+                (1.0 - ryb[axis], -1.0)
+            }
+        });
+        let weight = factors[0].0 * factors[1].0 * factors[2].0;
+        let slopes = [
+            factors[0].1 * factors[1].0 * factors[2].0,
+            factors[0].0 * factors[1].1 * factors[2].0,
+            factors[0].0 * factors[1].0 * factors[2].1,
+        ];
+
+        for channel in 0..3 {
+            rgb[channel] += weight * color[channel];
+            for axis in 0..3 {
+                jacobian[channel][axis] += slopes[axis] * color[channel];
+            }
+        }
+    }
+
+    return (rgb, jacobian);
+}
+
+/// Converts a RGB color to RYB representation by inverting the trilinear
+/// interpolation of the RYB cube with damped Gauss-Newton iterations, RGB
+/// colors which cannot be mixed from the paints like pure cyan are mapped to
+/// the closest color found
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_ryb(color: &ColorRGBA) -> ColorRYBA {
+    let target = [color.get_red(), color.get_green(), color.get_blue()];
+    let residual = |ryb: &[f32; 3]| -> [f32; 3] {
+        let (rgb, _) = ryb_interpolate(ryb);
+
+        return [0, 1, 2].map(|i| rgb[i] - target[i]);
+    };
+    let norm = |vector: &[f32; 3]| vector[0].hypot(vector[1]).hypot(vector[2]);
+
+    let mut ryb = [0.5; 3];
+    let mut current = residual(&ryb);
+    for _ in 0..RYB_ITERATIONS {
+        if norm(&current) < RYB_TOLERANCE {
+            break;
+        }
+
+        // Solve the damped normal equations as the Jacobian is singular at
+        // some corners of the cube
+        let (_, jacobian) = ryb_interpolate(&ryb);
+        let normal = [0, 1, 2].map(|i| {
+            [0, 1, 2].map(|j| {
+                let damping = if i == j { RYB_DAMPING } else { 0.0 };
+
+                return (0..3).map(|k| jacobian[k][i] * jacobian[k][j]).sum::<f32>() + damping;
+            })
+        });
+        let gradient = [0, 1, 2].map(|i| (0..3).map(|k| jacobian[k][i] * current[k]).sum());
+        let step = mat_mul(&mat_inv(&normal), &gradient);
+        if !step.iter().all(|value| value.is_finite()) {
+            break;
+        }
+
+        // Halve the step until it improves the color to stay within the cube
+        let mut scale = 1.0;
+        let mut improved = false;
+        while scale > 1e-3 {
+            let next = [0, 1, 2].map(|i| (ryb[i] - scale * step[i]).clamp(0.0, 1.0));
+            let next_residual = residual(&next);
+            if norm(&next_residual) < norm(&current) {
+                ryb = next;
+                current = next_residual;
+                improved = true;
+                break;
+            }
+            scale *= 0.5;
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    return unsafe { ColorRYBA::new_unsafe(ryb[0], ryb[1], ryb[2], color.get_alpha()) };
+}
+
+/// Converts a RYB color to RGB representation using trilinear interpolation of
+/// the RYB cube
+///
+/// # Parameters
+///
+/// color: The RYB color to convert
+pub fn ryb_to_rgb(color: &ColorRYBA) -> ColorRGBA {
+    let (rgb, _) = ryb_interpolate(&[color.get_red(), color.get_yellow(), color.get_blue()]);
+
+    return ColorRGBA::new(rgb[0], rgb[1], rgb[2], color.get_alpha());
+}
+
+/// Retrieves the hue of a RYB color on the artist color wheel, 0 is red, 1/3
+/// is yellow and 2/3 is blue with the mixtures in between
+///
+/// # Parameters
+///
+/// color: The RYB color to retrieve the hue of
+pub fn ryb_hue(color: &ColorRYBA) -> f32 {
+    return ColorHCMA::from_ryb(color).h;
+}
+
+/// Rotates the hue of a RYB color on the artist color wheel while keeping the
+/// amount of paint and the gray component
+///
+/// # Parameters
+///
+/// color: The RYB color to rotate
+///
+/// rotation: The rotation given as a fraction of a full turn
+pub fn rotate_ryb_hue(color: &ColorRYBA, rotation: f32) -> ColorRYBA {
+    let mut hcm = ColorHCMA::from_ryb(color);
+    hcm.h = (hcm.h + rotation).rem_euclid(1.0);
+
+    return hcm.to_ryb();
+}
+
+/// Retrieves the complementary color on the artist color wheel, the
+/// complementary of red is green and the complementary of yellow is purple
+///
+/// # Parameters
+///
+/// color: The RYB color to retrieve the complementary color of
+pub fn ryb_complement(color: &ColorRYBA) -> ColorRYBA {
+    return rotate_ryb_hue(color, 0.5);
+}
+
+/// Converts a RGB color to CMYK representation using the naive separation
+/// where the entire gray component is replaced by black
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_cmyk(color: &ColorRGBA) -> ColorCMYKA {
+    return rgb_to_cmyk_separated(color, &CmykSeparation::NAIVE);
+}
+
+/// Converts a RGB color to CMYK representation using the given separation
+/// settings, if the total ink coverage is above the ink limit the cyan,
+/// magenta and yellow components are reduced while black is kept
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// separation: The settings for the black generation and the ink limit
+pub fn rgb_to_cmyk_separated(color: &ColorRGBA, separation: &CmykSeparation) -> ColorCMYKA {
+    let c = 1.0 - color.get_red();
+    let m = 1.0 - color.get_green();
+    let y = 1.0 - color.get_blue();
+
+    // Find the black component from the gray component
+    let gray = c.min(m).min(y);
+    let k = match separation.get_black_generation() {
+        BlackGeneration::Full => gray,
+        BlackGeneration::UnderColorRemoval { start, amount } => {
+            let ramp = if start >= 1.0 {
+                0.0
+            } else {
+                ((gray - start) / (1.0 - start)).clamp(0.0, 1.0)
+            };
+
+            amount * ramp * gray
+        }
+        BlackGeneration::GrayComponentReplacement { amount } => amount * gray,
+    };
+
+    // Remove the black from the other inks
+    let (c, m, y) = if k >= 1.0 {
+        (0.0, 0.0, 0.0)
+    } else {
+        (
+            (c - k) / (1.0 - k),
+            (m - k) / (1.0 - k),
+            (y - k) / (1.0 - k),
+        )
+    };
+
+    // Apply the ink limit
+    let ink_limit = separation.get_ink_limit();
+    let total = c + m + y;
+    let scale = if total + k > ink_limit {
+        (ink_limit - k) / total
+    } else {
+        1.0
+    };
+
+    return unsafe {
+        ColorCMYKA::new_unsafe(c * scale, m * scale, y * scale, k, color.get_alpha())
+    };
+}
+
+/// Converts a CMYK color to RGB representation
+///
+/// # Parameters
+///
+/// color: The CMYK color to convert
+pub fn cmyk_to_rgb(color: &ColorCMYKA) -> ColorRGBA {
+    let white = 1.0 - color.get_black();
+
+    return unsafe {
+        ColorRGBA::new_unsafe(
+            (1.0 - color.get_cyan()) * white,
+            (1.0 - color.get_magenta()) * white,
+            (1.0 - color.get_yellow()) * white,
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Retrieves the offsets and scales of the luma and chroma components in the
+/// order: luma offset, luma scale, chroma offset, chroma scale
+fn ycbcr_quantization(range: YCbCrRange) -> [f32; 4] {
+    return match range {
+        YCbCrRange::Full => [0.0, 1.0, 0.5, 1.0],
+        YCbCrRange::Limited => [16.0 / 255.0, 219.0 / 255.0, 128.0 / 255.0, 224.0 / 255.0],
+    };
+}
+
+/// Converts a RGB color to YCbCr representation, the RGB components are used
+/// directly as the gamma encoded R'G'B' values
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// matrix: The matrix coefficients of the YCbCr color
+///
+/// range: The quantization range of the YCbCr color
+pub fn rgb_to_ycbcr(color: &ColorRGBA, matrix: YCbCrMatrix, range: YCbCrRange) -> ColorYCbCrA {
+    let [kr, kb] = matrix.get_coefficients();
+    let [y_offset, y_scale, c_offset, c_scale] = ycbcr_quantization(range);
+
+    let y = kr * color.get_red() + (1.0 - kr - kb) * color.get_green() + kb * color.get_blue();
+    let pb = (color.get_blue() - y) / (2.0 * (1.0 - kb));
+    let pr = (color.get_red() - y) / (2.0 * (1.0 - kr));
+
+    return unsafe {
+        ColorYCbCrA::new_unsafe(
+            y_offset + y_scale * y,
+            c_offset + c_scale * pb,
+            c_offset + c_scale * pr,
+            color.get_alpha(),
+            matrix,
+            range,
+        )
+    };
+}
+
+/// Converts a YCbCr color to RGB representation, values outside of the nominal
+/// range like super-whites are clipped
+///
+/// # Parameters
+///
+/// color: The YCbCr color to convert
+pub fn ycbcr_to_rgb(color: &ColorYCbCrA) -> ColorRGBA {
+    let [kr, kb] = color.get_matrix().get_coefficients();
+    let [y_offset, y_scale, c_offset, c_scale] = ycbcr_quantization(color.get_range());
+
+    let y = (color.get_luma() - y_offset) / y_scale;
+    let pb = (color.get_blue_difference() - c_offset) / c_scale;
+    let pr = (color.get_red_difference() - c_offset) / c_scale;
+
+    let r = y + 2.0 * (1.0 - kr) * pr;
+    let b = y + 2.0 * (1.0 - kb) * pb;
+    let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+
+    return ColorRGBA::new(r, g, b, color.get_alpha());
+}
+
+/// The maximum absolute value of the U component of a YUV color
+const YUV_U_MAX: f32 = 0.436;
+
+/// The maximum absolute value of the V component of a YUV color
+const YUV_V_MAX: f32 = 0.615;
+
+/// The angle in radians between the UV and IQ axes
+const YIQ_ANGLE: f32 = 33.0 * std::f32::consts::PI / 180.0;
+
+/// Calculates the luma and the two unscaled color differences B' - Y' and
+/// R' - Y' of a RGB color using the BT.601 luma coefficients
+///
+/// # Parameters
+///
+/// color: The RGB color to calculate the values for
+fn rgb_to_color_difference(color: &ColorRGBA) -> [f32; 3] {
+    let [kr, kb] = YCbCrMatrix::BT601.get_coefficients();
+    let y = kr * color.get_red() + (1.0 - kr - kb) * color.get_green() + kb * color.get_blue();
+
+    return [y, color.get_blue() - y, color.get_red() - y];
+}
+
+/// Converts the luma and the two unscaled color differences B' - Y' and
+/// R' - Y' back to a RGB color
+///
+/// # Parameters
+///
+/// y: The luma
+///
+/// db: The blue color difference B' - Y'
+///
+/// dr: The red color difference R' - Y'
+///
+/// a: The alpha component
+fn color_difference_to_rgb(y: f32, db: f32, dr: f32, a: f32) -> ColorRGBA {
+    let [kr, kb] = YCbCrMatrix::BT601.get_coefficients();
+    let r = y + dr;
+    let b = y + db;
+    let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+
+    return ColorRGBA::new(r, g, b, a);
+}
+
+/// Converts a RGB color to YUV representation as used by analog PAL
+/// television
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_yuv(color: &ColorRGBA) -> ColorYUVA {
+    let [kr, kb] = YCbCrMatrix::BT601.get_coefficients();
+    let [y, db, dr] = rgb_to_color_difference(color);
+
+    return unsafe {
+        ColorYUVA::new_unsafe(
+            y,
+            db * YUV_U_MAX / (1.0 - kb),
+            dr * YUV_V_MAX / (1.0 - kr),
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Converts a YUV color to RGB representation
+///
+/// # Parameters
+///
+/// color: The YUV color to convert
+pub fn yuv_to_rgb(color: &ColorYUVA) -> ColorRGBA {
+    let [kr, kb] = YCbCrMatrix::BT601.get_coefficients();
+
+    return color_difference_to_rgb(
+        color.get_luma(),
+        color.get_u() * (1.0 - kb) / YUV_U_MAX,
+        color.get_v() * (1.0 - kr) / YUV_V_MAX,
+        color.get_alpha(),
+    );
+}
+
+/// Converts a RGB color to YIQ representation as used by analog NTSC
+/// television, the IQ axes are the UV axes of YUV rotated by 33 degrees
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_yiq(color: &ColorRGBA) -> ColorYIQA {
+    let yuv = rgb_to_yuv(color);
+    let (sin, cos) = YIQ_ANGLE.sin_cos();
+
+    return unsafe {
+        ColorYIQA::new_unsafe(
+            yuv.get_luma(),
+            -yuv.get_u() * sin + yuv.get_v() * cos,
+            yuv.get_u() * cos + yuv.get_v() * sin,
+            yuv.get_alpha(),
+        )
+    };
+}
+
+/// Converts a YIQ color to RGB representation
+///
+/// # Parameters
+///
+/// color: The YIQ color to convert
+pub fn yiq_to_rgb(color: &ColorYIQA) -> ColorRGBA {
+    let (sin, cos) = YIQ_ANGLE.sin_cos();
+    let u = -color.get_in_phase() * sin + color.get_quadrature() * cos;
+    let v = color.get_in_phase() * cos + color.get_quadrature() * sin;
+
+    return yuv_to_rgb(&unsafe {
+        ColorYUVA::new_unsafe(color.get_luma(), u, v, color.get_alpha())
+    });
+}
+
+/// Converts a RGB color to YCoCg representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_ycocg(color: &ColorRGBA) -> ColorYCoCgA {
+    let r = color.get_red();
+    let g = color.get_green();
+    let b = color.get_blue();
+
+    return unsafe {
+        ColorYCoCgA::new_unsafe(
+            0.25 * r + 0.5 * g + 0.25 * b,
+            0.5 * r - 0.5 * b,
+            -0.25 * r + 0.5 * g - 0.25 * b,
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Converts a YCoCg color to RGB representation
+///
+/// # Parameters
+///
+/// color: The YCoCg color to convert
+pub fn ycocg_to_rgb(color: &ColorYCoCgA) -> ColorRGBA {
+    let base = color.get_luma() - color.get_green_chroma();
+
+    return ColorRGBA::new(
+        base + color.get_orange_chroma(),
+        color.get_luma() + color.get_green_chroma(),
+        base - color.get_orange_chroma(),
+        color.get_alpha(),
+    );
+}
+
+/// Converts a RGB color to YCoCg-R representation, the RGB components are
+/// quantized to 8 bits before the lossless integer transform is applied
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_ycocg_r(color: &ColorRGBA) -> ColorYCoCgRA {
+    let r = (color.get_red() * 255.0).round() as i32;
+    let g = (color.get_green() * 255.0).round() as i32;
+    let b = (color.get_blue() * 255.0).round() as i32;
+
+    let co = r - b;
+    let base = b + (co >> 1);
+    let cg = g - base;
+    let y = base + (cg >> 1);
+
+    return unsafe { ColorYCoCgRA::new_unsafe(y, co, cg, color.get_alpha()) };
+}
+
+/// Converts a YCoCg-R color to RGB representation, the transform is the exact
+/// inverse of rgb_to_ycocg_r for 8 bit colors
+///
+/// # Parameters
+///
+/// color: The YCoCg-R color to convert
+pub fn ycocg_r_to_rgb(color: &ColorYCoCgRA) -> ColorRGBA {
+    let base = color.get_luma() - (color.get_green_chroma() >> 1);
+    let g = color.get_green_chroma() + base;
+    let b = base - (color.get_orange_chroma() >> 1);
+    let r = b + color.get_orange_chroma();
+
+    return ColorRGBA::new(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        color.get_alpha(),
+    );
+}
+
+/// The matrix converting linear sRGB to XYZ relative to D65
+const SRGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.072175],
+    [0.0193339, 0.119192, 0.9503041],
+];
+
+/// The matrix converting XYZ relative to D65 to linear sRGB
+const XYZ_TO_SRGB: [[f32; 3]; 3] = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.969266, 1.8760108, 0.041556],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+/// The xy chromaticity of the D65 reference white, used for colors without
+/// any luminance
+const D65_CHROMATICITY: [f32; 2] = [0.31271, 0.32902];
+
+/// Multiplies a 3x3 matrix with a 3 component vector
+fn mat_mul(matrix: &[[f32; 3]; 3], vector: &[f32; 3]) -> [f32; 3] {
+    return [
+        matrix[0][0] * vector[0] + matrix[0][1] * vector[1] + matrix[0][2] * vector[2],
+        matrix[1][0] * vector[0] + matrix[1][1] * vector[1] + matrix[1][2] * vector[2],
+        matrix[2][0] * vector[0] + matrix[2][1] * vector[1] + matrix[2][2] * vector[2],
+    ];
+}
+
+/// Converts a gamma encoded sRGB component to linear light using the exact
+/// piecewise sRGB transfer function
+///
+/// # Parameters
+///
+/// value: The gamma encoded component to convert
+pub fn srgb_to_linear(value: f32) -> f32 {
+    return if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    };
+}
+
+/// Converts a linear light component to gamma encoded sRGB using the exact
+/// piecewise sRGB transfer function
+///
+/// # Parameters
+///
+/// value: The linear light component to convert
+pub fn linear_to_srgb(value: f32) -> f32 {
+    return if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+}
+
+/// Converts a gamma encoded RGB color to linear light
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_linear_rgb(color: &ColorRGBA) -> ColorLinearRGBA {
+    return unsafe {
+        ColorLinearRGBA::new_unsafe(
+            srgb_to_linear(color.get_red()),
+            srgb_to_linear(color.get_green()),
+            srgb_to_linear(color.get_blue()),
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Converts a linear light RGB color to gamma encoded RGB
+///
+/// # Parameters
+///
+/// color: The linear RGB color to convert
+pub fn linear_rgb_to_rgb(color: &ColorLinearRGBA) -> ColorRGBA {
+    return unsafe {
+        ColorRGBA::new_unsafe(
+            linear_to_srgb(color.get_red()),
+            linear_to_srgb(color.get_green()),
+            linear_to_srgb(color.get_blue()),
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Converts a RGB color to XYZ representation, the conversion goes through
+/// linear sRGB
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_xyz(color: &ColorRGBA) -> ColorXYZA {
+    let linear = [
+        srgb_to_linear(color.get_red()),
+        srgb_to_linear(color.get_green()),
+        srgb_to_linear(color.get_blue()),
+    ];
+    let xyz = mat_mul(&SRGB_TO_XYZ, &linear);
+
+    return unsafe { ColorXYZA::new_unsafe(xyz[0], xyz[1], xyz[2], color.get_alpha()) };
+}
+
+/// Converts a XYZ color to RGB representation, the conversion goes through
+/// linear sRGB and colors outside of the sRGB gamut are clipped
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+pub fn xyz_to_rgb(color: &ColorXYZA) -> ColorRGBA {
+    let linear = mat_mul(&XYZ_TO_SRGB, &[color.get_x(), color.get_y(), color.get_z()]);
+
+    return ColorRGBA::new(
+        linear_to_srgb(linear[0].clamp(0.0, 1.0)),
+        linear_to_srgb(linear[1].clamp(0.0, 1.0)),
+        linear_to_srgb(linear[2].clamp(0.0, 1.0)),
+        color.get_alpha(),
+    );
+}
+
+/// Converts a XYZ color to xyY representation, colors without any luminance
+/// get the chromaticity of the D65 reference white
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+pub fn xyz_to_xyy(color: &ColorXYZA) -> ColorxyYA {
+    let sum = color.get_x() + color.get_y() + color.get_z();
+
+    if sum == 0.0 {
+        return unsafe {
+            ColorxyYA::new_unsafe(
+                D65_CHROMATICITY[0],
+                D65_CHROMATICITY[1],
+                0.0,
+                color.get_alpha(),
+            )
+        };
+    }
+
+    return unsafe {
+        ColorxyYA::new_unsafe(
+            color.get_x() / sum,
+            color.get_y() / sum,
+            color.get_y(),
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Converts a xyY color to XYZ representation
+///
+/// # Parameters
+///
+/// color: The xyY color to convert
+pub fn xyy_to_xyz(color: &ColorxyYA) -> ColorXYZA {
+    if color.get_y() == 0.0 {
+        return unsafe { ColorXYZA::new_unsafe(0.0, 0.0, 0.0, color.get_alpha()) };
+    }
+
+    let scale = color.get_luminance() / color.get_y();
+
+    return unsafe {
+        ColorXYZA::new_unsafe(
+            color.get_x() * scale,
+            color.get_luminance(),
+            (1.0 - color.get_x() - color.get_y()) * scale,
+            color.get_alpha(),
+        )
+    };
+}
+
+/// The Bradford matrix converting XYZ to cone response
+const XYZ_TO_BRADFORD: [[f32; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// The Bradford matrix converting cone response to XYZ
+const BRADFORD_TO_XYZ: [[f32; 3]; 3] = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+/// The CIE constant epsilon = (6/29)^3 used in the L*a*b* conversions
+const LAB_EPSILON: f32 = 216.0 / 24389.0;
+
+/// The CIE constant kappa = (29/3)^3 used in the L*a*b* conversions
+const LAB_KAPPA: f32 = 24389.0 / 27.0;
+
+/// Adapts a XYZ color seen under one white point to the corresponding color
+/// under another white point using the Bradford transform
+///
+/// # Parameters
+///
+/// color: The XYZ color to adapt
+///
+/// source: The white point the color is currently relative to
+///
+/// target: The white point to adapt the color to
+pub fn adapt_xyz(color: &ColorXYZA, source: WhitePoint, target: WhitePoint) -> ColorXYZA {
+    if source == target {
+        return *color;
+    }
+
+    let source_cone = mat_mul(&XYZ_TO_BRADFORD, &source.get());
+    let target_cone = mat_mul(&XYZ_TO_BRADFORD, &target.get());
+    let cone = mat_mul(
+        &XYZ_TO_BRADFORD,
+        &[color.get_x(), color.get_y(), color.get_z()],
+    );
+    let adapted = [
+        cone[0] * target_cone[0] / source_cone[0],
+        cone[1] * target_cone[1] / source_cone[1],
+        cone[2] * target_cone[2] / source_cone[2],
+    ];
+    let xyz = mat_mul(&BRADFORD_TO_XYZ, &adapted);
+
+    return unsafe { ColorXYZA::new_unsafe(xyz[0], xyz[1], xyz[2], color.get_alpha()) };
+}
+
+/// Converts a hue angle given in radians to a hue between 0 and 1
+fn angle_to_hue(angle: f32) -> f32 {
+    return (angle / std::f32::consts::TAU).rem_euclid(1.0);
+}
+
+/// Converts a hue between 0 and 1 to a hue angle given in radians
+fn hue_to_angle(hue: f32) -> f32 {
+    return hue * std::f32::consts::TAU;
+}
+
+/// Converts a XYZ color to L*a*b* representation relative to the given white
+/// point, the color is chromatically adapted from D65 to the white point
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+///
+/// white: The reference white of the L*a*b* color
+pub fn xyz_to_lab(color: &ColorXYZA, white: WhitePoint) -> ColorLabA {
+    let adapted = adapt_xyz(color, WhitePoint::D65, white);
+
+    let f = |t: f32| -> f32 {
+        return if t > LAB_EPSILON {
+            t.cbrt()
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        };
+    };
+    let fx = f(adapted.get_x() / white.get_x());
+    let fy = f(adapted.get_y());
+    let fz = f(adapted.get_z() / white.get_z());
+
+    return unsafe {
+        ColorLabA::new_unsafe(
+            116.0 * fy - 16.0,
+            500.0 * (fx - fy),
+            200.0 * (fy - fz),
+            color.get_alpha(),
+            white,
+        )
+    };
+}
+
+/// Converts a L*a*b* color to XYZ representation, the color is chromatically
+/// adapted from its white point to D65
+///
+/// # Parameters
+///
+/// color: The L*a*b* color to convert
+pub fn lab_to_xyz(color: &ColorLabA) -> ColorXYZA {
+    let white = color.get_white_point();
+    let fy = (color.get_lightness() + 16.0) / 116.0;
+    let fx = fy + color.get_a() / 500.0;
+    let fz = fy - color.get_b() / 200.0;
+
+    let f_inv = |t: f32| -> f32 {
+        let t3 = t * t * t;
+        return if t3 > LAB_EPSILON {
+            t3
+        } else {
+            (116.0 * t - 16.0) / LAB_KAPPA
+        };
+    };
+    let y = if color.get_lightness() > LAB_KAPPA * LAB_EPSILON {
+        fy * fy * fy
+    } else {
+        color.get_lightness() / LAB_KAPPA
+    };
+
+    let xyz = unsafe {
+        ColorXYZA::new_unsafe(
+            f_inv(fx) * white.get_x(),
+            y,
+            f_inv(fz) * white.get_z(),
+            color.get_alpha(),
+        )
+    };
+
+    return adapt_xyz(&xyz, white, WhitePoint::D65);
+}
+
+/// Converts a RGB color to L*a*b* representation relative to the given white
+/// point
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// white: The reference white of the L*a*b* color
+pub fn rgb_to_lab(color: &ColorRGBA, white: WhitePoint) -> ColorLabA {
+    return xyz_to_lab(&rgb_to_xyz(color), white);
+}
+
+/// Converts a L*a*b* color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The L*a*b* color to convert
+pub fn lab_to_rgb(color: &ColorLabA) -> ColorRGBA {
+    return xyz_to_rgb(&lab_to_xyz(color));
+}
+
+/// Converts a L*a*b* color to LCh(ab) representation with the same white point
+///
+/// # Parameters
+///
+/// color: The L*a*b* color to convert
+pub fn lab_to_lch(color: &ColorLabA) -> ColorLChA {
+    let c = color.get_a().hypot(color.get_b());
+    let h = if c == 0.0 {
+        0.0
+    } else {
+        angle_to_hue(color.get_b().atan2(color.get_a()))
+    };
+
+    return unsafe {
+        ColorLChA::new_unsafe(
+            color.get_lightness(),
+            c,
+            h,
+            color.get_alpha(),
+            color.get_white_point(),
+        )
+    };
+}
+
+/// Converts a LCh(ab) color to L*a*b* representation with the same white point
+///
+/// # Parameters
+///
+/// color: The LCh(ab) color to convert
+pub fn lch_to_lab(color: &ColorLChA) -> ColorLabA {
+    let angle = hue_to_angle(color.get_hue());
+
+    return unsafe {
+        ColorLabA::new_unsafe(
+            color.get_lightness(),
+            color.get_chroma() * angle.cos(),
+            color.get_chroma() * angle.sin(),
+            color.get_alpha(),
+            color.get_white_point(),
+        )
+    };
+}
+
+/// Converts a RGB color to LCh(ab) representation relative to the given white
+/// point
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// white: The reference white of the LCh(ab) color
+pub fn rgb_to_lch(color: &ColorRGBA, white: WhitePoint) -> ColorLChA {
+    return lab_to_lch(&rgb_to_lab(color, white));
+}
+
+/// Converts a LCh(ab) color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The LCh(ab) color to convert
+pub fn lch_to_rgb(color: &ColorLChA) -> ColorRGBA {
+    return lab_to_rgb(&lch_to_lab(color));
+}
+
+/// The matrix converting XYZ relative to D65 to the OKLab cone response
+#[allow(clippy::excessive_precision)]
+const XYZ_TO_OKLAB_LMS: [[f32; 3]; 3] = [
+    [0.8189330101, 0.3618667424, -0.1288597137],
+    [0.0329845436, 0.9293118715, 0.0361456387],
+    [0.0482003018, 0.2643662691, 0.6338517070],
+];
+
+/// The matrix converting the OKLab cone response to XYZ relative to D65
+#[allow(clippy::excessive_precision)]
+const OKLAB_LMS_TO_XYZ: [[f32; 3]; 3] = [
+    [1.2270138511, -0.5577999807, 0.2812561490],
+    [-0.0405801784, 1.1122568696, -0.0716766787],
+    [-0.0763812845, -0.4214819784, 1.5861632204],
+];
+
+/// The matrix converting the non-linear OKLab cone response to OKLab
+#[allow(clippy::excessive_precision)]
+const OKLAB_LMS_TO_LAB: [[f32; 3]; 3] = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+/// The matrix converting OKLab to the non-linear OKLab cone response
+#[allow(clippy::excessive_precision)]
+const OKLAB_LAB_TO_LMS: [[f32; 3]; 3] = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+/// Converts a XYZ color to OKLab representation
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+pub fn xyz_to_oklab(color: &ColorXYZA) -> ColorOKLabA {
+    let lms = mat_mul(
+        &XYZ_TO_OKLAB_LMS,
+        &[color.get_x(), color.get_y(), color.get_z()],
+    );
+    let lab = mat_mul(
+        &OKLAB_LMS_TO_LAB,
+        &[lms[0].cbrt(), lms[1].cbrt(), lms[2].cbrt()],
+    );
+
+    return unsafe { ColorOKLabA::new_unsafe(lab[0], lab[1], lab[2], color.get_alpha()) };
+}
+
+/// Converts a OKLab color to XYZ representation
+///
+/// # Parameters
+///
+/// color: The OKLab color to convert
+pub fn oklab_to_xyz(color: &ColorOKLabA) -> ColorXYZA {
+    let lms = mat_mul(
+        &OKLAB_LAB_TO_LMS,
+        &[color.get_lightness(), color.get_a(), color.get_b()],
+    );
+    let xyz = mat_mul(
+        &OKLAB_LMS_TO_XYZ,
+        &[
+            lms[0] * lms[0] * lms[0],
+            lms[1] * lms[1] * lms[1],
+            lms[2] * lms[2] * lms[2],
+        ],
+    );
+
+    return unsafe { ColorXYZA::new_unsafe(xyz[0], xyz[1], xyz[2], color.get_alpha()) };
+}
+
+/// Converts a RGB color to OKLab representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_oklab(color: &ColorRGBA) -> ColorOKLabA {
+    return xyz_to_oklab(&rgb_to_xyz(color));
+}
+
+/// Converts a OKLab color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The OKLab color to convert
+pub fn oklab_to_rgb(color: &ColorOKLabA) -> ColorRGBA {
+    return xyz_to_rgb(&oklab_to_xyz(color));
+}
+
+/// Converts a OKLab color to OKLCh representation
+///
+/// # Parameters
+///
+/// color: The OKLab color to convert
+pub fn oklab_to_oklch(color: &ColorOKLabA) -> ColorOKLChA {
+    let c = color.get_a().hypot(color.get_b());
+    let h = if c == 0.0 {
+        0.0
+    } else {
+        angle_to_hue(color.get_b().atan2(color.get_a()))
+    };
+
+    return unsafe { ColorOKLChA::new_unsafe(color.get_lightness(), c, h, color.get_alpha()) };
+}
+
+/// Converts a OKLCh color to OKLab representation
+///
+/// # Parameters
+///
+/// color: The OKLCh color to convert
+pub fn oklch_to_oklab(color: &ColorOKLChA) -> ColorOKLabA {
+    let angle = hue_to_angle(color.get_hue());
+
+    return unsafe {
+        ColorOKLabA::new_unsafe(
+            color.get_lightness(),
+            color.get_chroma() * angle.cos(),
+            color.get_chroma() * angle.sin(),
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Converts a RGB color to OKLCh representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_oklch(color: &ColorRGBA) -> ColorOKLChA {
+    return oklab_to_oklch(&rgb_to_oklab(color));
+}
+
+/// Converts a OKLCh color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The OKLCh color to convert
+pub fn oklch_to_rgb(color: &ColorOKLChA) -> ColorRGBA {
+    return oklab_to_rgb(&oklch_to_oklab(color));
+}
+
+/// The matrix converting linear sRGB directly to the OKLab cone response
+#[allow(clippy::excessive_precision)]
+const LINEAR_SRGB_TO_OKLAB_LMS: [[f32; 3]; 3] = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+
+/// The matrix converting the OKLab cone response directly to linear sRGB
+#[allow(clippy::excessive_precision)]
+const OKLAB_LMS_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+/// The parameters of the toe function used to estimate L_r from L in Okhsv and
+/// Okhsl
+const OK_TOE_K1: f32 = 0.206;
+const OK_TOE_K2: f32 = 0.03;
+const OK_TOE_K3: f32 = (1.0 + OK_TOE_K1) / (1.0 + OK_TOE_K2);
+
+/// Converts a linear sRGB color to OKLab, returns the lightness, a and b
+fn linear_srgb_to_oklab(rgb: &[f32; 3]) -> [f32; 3] {
+    let lms = mat_mul(&LINEAR_SRGB_TO_OKLAB_LMS, rgb);
+
+    return mat_mul(
+        &OKLAB_LMS_TO_LAB,
+        &[lms[0].cbrt(), lms[1].cbrt(), lms[2].cbrt()],
+    );
+}
+
+/// Converts an OKLab color given as lightness, a and b to linear sRGB
+fn oklab_to_linear_srgb(lab: &[f32; 3]) -> [f32; 3] {
+    let lms = mat_mul(&OKLAB_LAB_TO_LMS, lab);
+
+    return mat_mul(
+        &OKLAB_LMS_TO_LINEAR_SRGB,
+        &[
+            lms[0] * lms[0] * lms[0],
+            lms[1] * lms[1] * lms[1],
+            lms[2] * lms[2] * lms[2],
+        ],
+    );
+}
+
+/// Maps the OKLab lightness to an estimate of the CIE L* lightness scaled to
+/// between 0 and 1
+fn ok_toe(x: f32) -> f32 {
+    let y = OK_TOE_K3 * x - OK_TOE_K1;
+
+    return 0.5 * (y + (y * y + 4.0 * OK_TOE_K2 * OK_TOE_K3 * x).sqrt());
+}
+
+/// The inverse of the toe function
+fn ok_toe_inv(x: f32) -> f32 {
+    return (x * x + OK_TOE_K1 * x) / (OK_TOE_K3 * (x + OK_TOE_K2));
+}
+
+/// Finds the maximum saturation C / L possible for the given normalized hue
+/// direction such that the color stays within the sRGB gamut
+///
+/// # Parameters
+///
+/// a: The normalized a direction of the hue
+///
+/// b: The normalized b direction of the hue
+#[allow(clippy::excessive_precision)]
+fn ok_max_saturation(a: f32, b: f32) -> f32 {
+    // Select the coefficients for the component which first goes below zero
+    let (k, w) = if -1.88170328 * a - 0.80936493 * b > 1.0 {
+        (
+            [1.19086277, 1.76576728, 0.59662641, 0.75515197, 0.56771245],
+            OKLAB_LMS_TO_LINEAR_SRGB[0],
+        )
+    } else if 1.81444104 * a - 1.19445276 * b > 1.0 {
+        (
+            [0.73956515, -0.45954404, 0.08285427, 0.12541070, 0.14503204],
+            OKLAB_LMS_TO_LINEAR_SRGB[1],
+        )
+    } else {
+        (
+            [
+                1.35733652,
+                -0.00915799,
+                -1.15130210,
+                -0.50559606,
+                0.00692167,
+            ],
+            OKLAB_LMS_TO_LINEAR_SRGB[2],
+        )
+    };
+
+    // Approximate the max saturation using a polynomial
+    let s = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+
+    // Do one step of Halley's method to get closer
+    let k_l = OKLAB_LAB_TO_LMS[0][1] * a + OKLAB_LAB_TO_LMS[0][2] * b;
+    let k_m = OKLAB_LAB_TO_LMS[1][1] * a + OKLAB_LAB_TO_LMS[1][2] * b;
+    let k_s = OKLAB_LAB_TO_LMS[2][1] * a + OKLAB_LAB_TO_LMS[2][2] * b;
+
+    let l_ = 1.0 + s * k_l;
+    let m_ = 1.0 + s * k_m;
+    let s_ = 1.0 + s * k_s;
+
+    let f = w[0] * l_ * l_ * l_ + w[1] * m_ * m_ * m_ + w[2] * s_ * s_ * s_;
+    let f1 = w[0] * 3.0 * k_l * l_ * l_ + w[1] * 3.0 * k_m * m_ * m_ + w[2] * 3.0 * k_s * s_ * s_;
+    let f2 =
+        w[0] * 6.0 * k_l * k_l * l_ + w[1] * 6.0 * k_m * k_m * m_ + w[2] * 6.0 * k_s * k_s * s_;
+
+    return s - f * f1 / (f1 * f1 - 0.5 * f * f2);
+}
+
+/// Finds the lightness and chroma of the cusp of the sRGB gamut for the given
+/// normalized hue direction, returns the lightness and chroma
+///
+/// # Parameters
+///
+/// a: The normalized a direction of the hue
+///
+/// b: The normalized b direction of the hue
+fn ok_find_cusp(a: f32, b: f32) -> [f32; 2] {
+    let s_cusp = ok_max_saturation(a, b);
+    let rgb = oklab_to_linear_srgb(&[1.0, s_cusp * a, s_cusp * b]);
+    let l_cusp = (1.0 / rgb[0].max(rgb[1]).max(rgb[2])).cbrt();
+
+    return [l_cusp, l_cusp * s_cusp];
+}
+
+/// Finds the intersection of the line from (L0, 0) to (L1, C1) with the sRGB
+/// gamut boundary for the given normalized hue direction, returns the fraction
+/// t along the line at the intersection
+///
+/// # Parameters
+///
+/// a: The normalized a direction of the hue
+///
+/// b: The normalized b direction of the hue
+///
+/// l1: The lightness of the end point of the line
+///
+/// c1: The chroma of the end point of the line
+///
+/// l0: The lightness of the start point of the line
+///
+/// cusp: The lightness and chroma of the cusp for this hue
+fn ok_find_gamut_intersection(a: f32, b: f32, l1: f32, c1: f32, l0: f32, cusp: [f32; 2]) -> f32 {
+    // Check if the intersection is in the lower half
+    if (l1 - l0) * cusp[1] - (cusp[0] - l0) * c1 <= 0.0 {
+        return cusp[1] * l0 / (c1 * cusp[0] + cusp[1] * (l0 - l1));
+    }
+
+    // Find the intersection with the upper triangle
+    let t = cusp[1] * (l0 - 1.0) / (c1 * (cusp[0] - 1.0) + cusp[1] * (l0 - l1));
+
+    // Do one step of Halley's method for each component to get closer
+    let k_l = OKLAB_LAB_TO_LMS[0][1] * a + OKLAB_LAB_TO_LMS[0][2] * b;
+    let k_m = OKLAB_LAB_TO_LMS[1][1] * a + OKLAB_LAB_TO_LMS[1][2] * b;
+    let k_s = OKLAB_LAB_TO_LMS[2][1] * a + OKLAB_LAB_TO_LMS[2][2] * b;
+
+    let l_dt = l1 - l0 + c1 * k_l;
+    let m_dt = l1 - l0 + c1 * k_m;
+    let s_dt = l1 - l0 + c1 * k_s;
+
+    let l = l0 * (1.0 - t) + t * l1;
+    let c = t * c1;
+
+    let l_ = l + c * k_l;
+    let m_ = l + c * k_m;
+    let s_ = l + c * k_s;
+
+    let lms = [l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_];
+    let lms_dt = [
+        3.0 * l_dt * l_ * l_,
+        3.0 * m_dt * m_ * m_,
+        3.0 * s_dt * s_ * s_,
+    ];
+    let lms_dt2 = [
+        6.0 * l_dt * l_dt * l_,
+        6.0 * m_dt * m_dt * m_,
+        6.0 * s_dt * s_dt * s_,
+    ];
+
+    let rgb = mat_mul(&OKLAB_LMS_TO_LINEAR_SRGB, &lms);
+    let rgb_dt = mat_mul(&OKLAB_LMS_TO_LINEAR_SRGB, &lms_dt);
+    let rgb_dt2 = mat_mul(&OKLAB_LMS_TO_LINEAR_SRGB, &lms_dt2);
+
+    let step = (0..3)
+        .map(|i| {
+            let f = rgb[i] - 1.0;
+            let u = rgb_dt[i] / (rgb_dt[i] * rgb_dt[i] - 0.5 * f * rgb_dt2[i]);
+
+            return if u >= 0.0 { -f * u } else { f32::MAX };
+        })
+        .fold(f32::MAX, f32::min);
+
+    return t + step;
+}
+
+/// Converts the cusp given as lightness and chroma to the slopes S and T of
+/// the triangle approximating the gamut
+fn ok_cusp_to_st(cusp: [f32; 2]) -> [f32; 2] {
+    return [cusp[1] / cusp[0], cusp[1] / (1.0 - cusp[0])];
+}
+
+/// Retrieves a smooth approximation of the slopes S and T for the given
+/// normalized hue direction
+///
+/// # Parameters
+///
+/// a: The normalized a direction of the hue
+///
+/// b: The normalized b direction of the hue
+#[allow(clippy::excessive_precision)]
+fn ok_st_mid(a: f32, b: f32) -> [f32; 2] {
+    let s = 0.11516993
+        + 1.0
+            / (7.44778970
+                + 4.15901240 * b
+                + a * (-2.19557347
+                    + 1.75198401 * b
+                    + a * (-2.13704948 - 10.02301043 * b
+                        + a * (-4.24894561 + 5.38770819 * b + 4.69891013 * a))));
+    let t = 0.11239642
+        + 1.0
+            / (1.61320320 - 0.68124379 * b
+                + a * (0.40370612
+                    + 0.90148123 * b
+                    + a * (-0.27087943
+                        + 0.61223990 * b
+                        + a * (0.00299215 - 0.45399568 * b - 0.14661872 * a))));
+
+    return [s, t];
+}
+
+/// Retrieves the three chroma values C_0, C_mid and C_max used to map between
+/// chroma and saturation in Okhsl
+///
+/// # Parameters
+///
+/// l: The OKLab lightness
+///
+/// a: The normalized a direction of the hue
+///
+/// b: The normalized b direction of the hue
+fn ok_chroma_scales(l: f32, a: f32, b: f32) -> [f32; 3] {
+    let cusp = ok_find_cusp(a, b);
+    let c_max = ok_find_gamut_intersection(a, b, l, 1.0, l, cusp);
+    let st_max = ok_cusp_to_st(cusp);
+
+    // Scale factor to compensate for the curved part of the gamut shape
+    let k = c_max / (l * st_max[0]).min((1.0 - l) * st_max[1]);
+
+    // Use a soft minimum function to get a smooth value for the chroma
+    let st_mid = ok_st_mid(a, b);
+    let c_a = l * st_mid[0];
+    let c_b = (1.0 - l) * st_mid[1];
+    let c_mid = 0.9
+        * k
+        * (1.0 / (1.0 / c_a.powi(4) + 1.0 / c_b.powi(4)))
+            .sqrt()
+            .sqrt();
+
+    // The shape is independent of hue for C_0 so the average slopes are used
+    let c_a = l * 0.4;
+    let c_b = (1.0 - l) * 0.8;
+    let c_0 = (1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b))).sqrt();
+
+    return [c_0, c_mid, c_max];
+}
+
+/// Converts a RGB color to Okhsv representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_okhsv(color: &ColorRGBA) -> ColorOkHSVA {
+    let lab = linear_srgb_to_oklab(&[
+        srgb_to_linear(color.get_red()),
+        srgb_to_linear(color.get_green()),
+        srgb_to_linear(color.get_blue()),
+    ]);
+    let c = lab[1].hypot(lab[2]);
+
+    // Stop if it is grayscale
+    if c < 1e-6 || lab[0] <= 0.0 {
+        return unsafe {
+            ColorOkHSVA::new_unsafe(0.0, 0.0, ok_toe(lab[0]).clamp(0.0, 1.0), color.get_alpha())
+        };
+    }
+
+    let a_ = lab[1] / c;
+    let b_ = lab[2] / c;
+    let h = angle_to_hue(lab[2].atan2(lab[1]));
+
+    let st_max = ok_cusp_to_st(ok_find_cusp(a_, b_));
+    let s_0 = 0.5;
+    let k = 1.0 - s_0 / st_max[0];
+
+    // Find the lightness and chroma at value 1 along the same line
+    let t = st_max[1] / (c + lab[0] * st_max[1]);
+    let l_v = t * lab[0];
+    let c_v = t * c;
+    let l_vt = ok_toe_inv(l_v);
+    let c_vt = c_v * l_vt / l_v;
+
+    // Invert the compensation for the toe and the curved top of the gamut
+    let rgb_scale = oklab_to_linear_srgb(&[l_vt, a_ * c_vt, b_ * c_vt]);
+    let scale_l = (1.0 / rgb_scale[0].max(rgb_scale[1]).max(rgb_scale[2]).max(0.0)).cbrt();
+    let l = ok_toe(lab[0] / scale_l);
+
+    // Compute the value and saturation
+    let v = l / l_v;
+    let s = (s_0 + st_max[1]) * c_v / (st_max[1] * s_0 + st_max[1] * k * c_v);
+
+    return unsafe {
+        ColorOkHSVA::new_unsafe(h, s.clamp(0.0, 1.0), v.clamp(0.0, 1.0), color.get_alpha())
+    };
+}
+
+/// Converts a Okhsv color to RGB representation
+///
+/// # Parameters
+///
+/// color: The Okhsv color to convert
+pub fn okhsv_to_rgb(color: &ColorOkHSVA) -> ColorRGBA {
+    // Stop if it is black
+    if color.get_value() <= 0.0 {
+        return unsafe { ColorRGBA::new_unsafe(0.0, 0.0, 0.0, color.get_alpha()) };
+    }
+
+    let angle = hue_to_angle(color.get_hue());
+    let a_ = angle.cos();
+    let b_ = angle.sin();
+    let s = color.get_saturation();
+    let v = color.get_value();
+
+    let st_max = ok_cusp_to_st(ok_find_cusp(a_, b_));
+    let s_0 = 0.5;
+    let k = 1.0 - s_0 / st_max[0];
+
+    // Compute the lightness and chroma as if the gamut is a perfect triangle
+    let l_v = 1.0 - s * s_0 / (s_0 + st_max[1] - st_max[1] * k * s);
+    let c_v = s * st_max[1] * s_0 / (s_0 + st_max[1] - st_max[1] * k * s);
+    let l = v * l_v;
+    let c = v * c_v;
+
+    // Compensate for the toe and the curved top of the gamut
+    let l_vt = ok_toe_inv(l_v);
+    let c_vt = c_v * l_vt / l_v;
+    let l_new = ok_toe_inv(l);
+    let c = c * l_new / l;
+    let l = l_new;
+
+    let rgb_scale = oklab_to_linear_srgb(&[l_vt, a_ * c_vt, b_ * c_vt]);
+    let scale_l = (1.0 / rgb_scale[0].max(rgb_scale[1]).max(rgb_scale[2]).max(0.0)).cbrt();
+    let rgb = oklab_to_linear_srgb(&[l * scale_l, c * scale_l * a_, c * scale_l * b_]);
+
+    return ColorRGBA::new(
+        linear_to_srgb(rgb[0].clamp(0.0, 1.0)),
+        linear_to_srgb(rgb[1].clamp(0.0, 1.0)),
+        linear_to_srgb(rgb[2].clamp(0.0, 1.0)),
+        color.get_alpha(),
+    );
+}
+
+/// Converts a RGB color to Okhsl representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_okhsl(color: &ColorRGBA) -> ColorOkHSLA {
+    let lab = linear_srgb_to_oklab(&[
+        srgb_to_linear(color.get_red()),
+        srgb_to_linear(color.get_green()),
+        srgb_to_linear(color.get_blue()),
+    ]);
+    let c = lab[1].hypot(lab[2]);
+    let l = ok_toe(lab[0]).clamp(0.0, 1.0);
+
+    // Stop if it is grayscale
+    if c < 1e-6 || l <= 0.0 || l >= 1.0 {
+        return unsafe { ColorOkHSLA::new_unsafe(0.0, 0.0, l, color.get_alpha()) };
+    }
+
+    let a_ = lab[1] / c;
+    let b_ = lab[2] / c;
+    let h = angle_to_hue(lab[2].atan2(lab[1]));
+
+    let [c_0, c_mid, c_max] = ok_chroma_scales(lab[0], a_, b_);
+    let mid = 0.8;
+    let mid_inv = 1.25;
+
+    let s = if c < c_mid {
+        let k_1 = mid * c_0;
+        let k_2 = 1.0 - k_1 / c_mid;
+        let t = c / (k_1 + k_2 * c);
+
+        t * mid
+    } else {
+        let k_0 = c_mid;
+        let k_1 = (1.0 - mid) * c_mid * c_mid * mid_inv * mid_inv / c_0;
+        let k_2 = 1.0 - k_1 / (c_max - c_mid);
+        let t = (c - k_0) / (k_1 + k_2 * (c - k_0));
+
+        mid + (1.0 - mid) * t
+    };
+
+    return unsafe { ColorOkHSLA::new_unsafe(h, s.clamp(0.0, 1.0), l, color.get_alpha()) };
+}
+
+/// Converts a Okhsl color to RGB representation
+///
+/// # Parameters
+///
+/// color: The Okhsl color to convert
+pub fn okhsl_to_rgb(color: &ColorOkHSLA) -> ColorRGBA {
+    // Stop if it is black or white
+    if color.get_lightness() <= 0.0 || color.get_lightness() >= 1.0 {
+        let v = color.get_lightness().clamp(0.0, 1.0);
+
+        return unsafe { ColorRGBA::new_unsafe(v, v, v, color.get_alpha()) };
+    }
+
+    let angle = hue_to_angle(color.get_hue());
+    let a_ = angle.cos();
+    let b_ = angle.sin();
+    let s = color.get_saturation();
+    let l = ok_toe_inv(color.get_lightness());
+
+    let [c_0, c_mid, c_max] = ok_chroma_scales(l, a_, b_);
+    let mid = 0.8;
+    let mid_inv = 1.25;
+
+    let c = if s < mid {
+        let t = mid_inv * s;
+        let k_1 = mid * c_0;
+        let k_2 = 1.0 - k_1 / c_mid;
+
+        t * k_1 / (1.0 - k_2 * t)
+    } else {
+        let t = (s - mid) / (1.0 - mid);
+        let k_0 = c_mid;
+        let k_1 = (1.0 - mid) * c_mid * c_mid * mid_inv * mid_inv / c_0;
+        let k_2 = 1.0 - k_1 / (c_max - c_mid);
+
+        k_0 + t * k_1 / (1.0 - k_2 * t)
+    };
+
+    let rgb = oklab_to_linear_srgb(&[l, c * a_, c * b_]);
+
+    return ColorRGBA::new(
+        linear_to_srgb(rgb[0].clamp(0.0, 1.0)),
+        linear_to_srgb(rgb[1].clamp(0.0, 1.0)),
+        linear_to_srgb(rgb[2].clamp(0.0, 1.0)),
+        color.get_alpha(),
+    );
+}
+
+/// Retrieves the u' and v' chromaticity coordinates of a XYZ color given as
+/// tristimulus values, black has the coordinates of the white point
+fn xyz_to_uv_prime(xyz: &[f32; 3], white: WhitePoint) -> [f32; 2] {
+    let denominator = xyz[0] + 15.0 * xyz[1] + 3.0 * xyz[2];
+
+    if denominator == 0.0 {
+        let white = white.get();
+        let denominator = white[0] + 15.0 * white[1] + 3.0 * white[2];
+
+        return [4.0 * white[0] / denominator, 9.0 * white[1] / denominator];
+    }
+
+    return [4.0 * xyz[0] / denominator, 9.0 * xyz[1] / denominator];
+}
+
+/// Converts a XYZ color to L*u*v* representation relative to the given white
+/// point, the color is chromatically adapted from D65 to the white point
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+///
+/// white: The reference white of the L*u*v* color
+pub fn xyz_to_luv(color: &ColorXYZA, white: WhitePoint) -> ColorLuvA {
+    let adapted = adapt_xyz(color, WhitePoint::D65, white);
+    let y = adapted.get_y();
+    let uv = xyz_to_uv_prime(&[adapted.get_x(), y, adapted.get_z()], white);
+    let uv_white = xyz_to_uv_prime(&white.get(), white);
+
+    let l = if y > LAB_EPSILON {
+        116.0 * y.cbrt() - 16.0
+    } else {
+        LAB_KAPPA * y
+    };
+
+    return unsafe {
+        ColorLuvA::new_unsafe(
+            l,
+            13.0 * l * (uv[0] - uv_white[0]),
+            13.0 * l * (uv[1] - uv_white[1]),
+            color.get_alpha(),
+            white,
+        )
+    };
+}
+
+/// Converts a L*u*v* color to XYZ representation, the color is chromatically
+/// adapted from its white point to D65
+///
+/// # Parameters
+///
+/// color: The L*u*v* color to convert
+pub fn luv_to_xyz(color: &ColorLuvA) -> ColorXYZA {
+    let l = color.get_lightness();
+
+    // Stop if it is black
+    if l <= 0.0 {
+        return unsafe { ColorXYZA::new_unsafe(0.0, 0.0, 0.0, color.get_alpha()) };
+    }
+
+    let white = color.get_white_point();
+    let uv_white = xyz_to_uv_prime(&white.get(), white);
+    let u = color.get_u() / (13.0 * l) + uv_white[0];
+    let v = color.get_v() / (13.0 * l) + uv_white[1];
+
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        ((l + 16.0) / 116.0).powi(3)
+    } else {
+        l / LAB_KAPPA
+    };
+    let x = y * 9.0 * u / (4.0 * v);
+    let z = y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v);
+
+    let xyz = unsafe { ColorXYZA::new_unsafe(x, y, z, color.get_alpha()) };
+
+    return adapt_xyz(&xyz, white, WhitePoint::D65);
+}
+
+/// Converts a RGB color to L*u*v* representation relative to the given white
+/// point
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// white: The reference white of the L*u*v* color
+pub fn rgb_to_luv(color: &ColorRGBA, white: WhitePoint) -> ColorLuvA {
+    return xyz_to_luv(&rgb_to_xyz(color), white);
+}
+
+/// Converts a L*u*v* color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The L*u*v* color to convert
+pub fn luv_to_rgb(color: &ColorLuvA) -> ColorRGBA {
+    return xyz_to_rgb(&luv_to_xyz(color));
+}
+
+/// Converts a L*u*v* color to LCh(uv) representation with the same white point
+///
+/// # Parameters
+///
+/// color: The L*u*v* color to convert
+pub fn luv_to_lchuv(color: &ColorLuvA) -> ColorLChuvA {
+    let c = color.get_u().hypot(color.get_v());
+    let h = if c == 0.0 {
+        0.0
+    } else {
+        angle_to_hue(color.get_v().atan2(color.get_u()))
+    };
+
+    return unsafe {
+        ColorLChuvA::new_unsafe(
+            color.get_lightness(),
+            c,
+            h,
+            color.get_alpha(),
+            color.get_white_point(),
+        )
+    };
+}
+
+/// Converts a LCh(uv) color to L*u*v* representation with the same white point
+///
+/// # Parameters
+///
+/// color: The LCh(uv) color to convert
+pub fn lchuv_to_luv(color: &ColorLChuvA) -> ColorLuvA {
+    let angle = hue_to_angle(color.get_hue());
+
+    return unsafe {
+        ColorLuvA::new_unsafe(
+            color.get_lightness(),
+            color.get_chroma() * angle.cos(),
+            color.get_chroma() * angle.sin(),
+            color.get_alpha(),
+            color.get_white_point(),
+        )
+    };
+}
+
+/// Converts a RGB color to LCh(uv) representation relative to the given white
+/// point
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// white: The reference white of the LCh(uv) color
+pub fn rgb_to_lchuv(color: &ColorRGBA, white: WhitePoint) -> ColorLChuvA {
+    return luv_to_lchuv(&rgb_to_luv(color, white));
+}
+
+/// Converts a LCh(uv) color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The LCh(uv) color to convert
+pub fn lchuv_to_rgb(color: &ColorLChuvA) -> ColorRGBA {
+    return luv_to_rgb(&lchuv_to_luv(color));
+}
+
+/// Retrieves the six lines bounding the sRGB gamut in the uv plane at the given
+/// lightness, each line is given as slope and intercept
+///
+/// # Parameters
+///
+/// l: The L*u*v* lightness between 0 and 100
+fn luv_gamut_bounds(l: f32) -> [[f32; 2]; 6] {
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        ((l + 16.0) / 116.0).powi(3)
+    } else {
+        l / LAB_KAPPA
+    };
+    let uv_white = xyz_to_uv_prime(&WhitePoint::D65.get(), WhitePoint::D65);
+
+    let mut bounds = [[0.0; 2]; 6];
+
+    // Each line is where one of the linear sRGB components is either 0 or 1
+    for (channel, row) in XYZ_TO_SRGB.iter().enumerate() {
+        for t in 0..2 {
+            let a = (9.0 * row[0] - 3.0 * row[2]) * y;
+            let b = (4.0 * row[1] - 20.0 * row[2]) * y - 4.0 * t as f32;
+            let c = a * uv_white[0] + b * uv_white[1] + 12.0 * row[2] * y;
+
+            bounds[2 * channel + t] = [-a / b, -13.0 * l * c / b];
+        }
+    }
+
+    return bounds;
+}
+
+/// Retrieves the maximum LCh(uv) chroma inside the sRGB gamut for the given
+/// lightness and hue
+///
+/// # Parameters
+///
+/// l: The L*u*v* lightness between 0 and 100
+///
+/// h: The hue between 0 and 1
+fn luv_max_chroma(l: f32, h: f32) -> f32 {
+    let angle = hue_to_angle(h);
+
+    return luv_gamut_bounds(l)
+        .iter()
+        .map(|bound| {
+            return bound[1] / (angle.sin() - bound[0] * angle.cos());
+        })
+        .filter(|length| {
+            return *length >= 0.0;
+        })
+        .fold(f32::MAX, f32::min);
+}
+
+/// Retrieves the maximum LCh(uv) chroma inside the sRGB gamut for the given
+/// lightness for all hues
+///
+/// # Parameters
+///
+/// l: The L*u*v* lightness between 0 and 100
+fn luv_max_safe_chroma(l: f32) -> f32 {
+    return luv_gamut_bounds(l)
+        .iter()
+        .map(|bound| {
+            return bound[1].abs() / (bound[0] * bound[0] + 1.0).sqrt();
+        })
+        .fold(f32::MAX, f32::min);
+}
+
+/// Retrieves the lightness and chroma of a LCh(uv) color relative to D65,
+/// colors with other white points are adapted to D65
+fn lchuv_d65(color: &ColorLChuvA) -> ColorLChuvA {
+    if color.get_white_point() == WhitePoint::D65 {
+        return *color;
+    }
+
+    return luv_to_lchuv(&xyz_to_luv(
+        &luv_to_xyz(&lchuv_to_luv(color)),
+        WhitePoint::D65,
+    ));
+}
+
+/// Converts a LCh(uv) color to HSLuv representation, colors with a white point
+/// other than D65 are adapted to D65
+///
+/// # Parameters
+///
+/// color: The LCh(uv) color to convert
+pub fn lchuv_to_hsluv(color: &ColorLChuvA) -> ColorHSLuvA {
+    let color = lchuv_d65(color);
+    let l = color.get_lightness();
+
+    // The hue is not defined for black and white
+    let (h, s) = if !(0.00001..=99.99999).contains(&l) {
+        (0.0, 0.0)
+    } else {
+        (
+            color.get_hue(),
+            color.get_chroma() / luv_max_chroma(l, color.get_hue()),
+        )
+    };
+
+    return unsafe { ColorHSLuvA::new_unsafe(h, s.clamp(0.0, 1.0), l / 100.0, color.get_alpha()) };
+}
+
+/// Converts a HSLuv color to LCh(uv) representation relative to D65
+///
+/// # Parameters
+///
+/// color: The HSLuv color to convert
+pub fn hsluv_to_lchuv(color: &ColorHSLuvA) -> ColorLChuvA {
+    let l = color.get_lightness() * 100.0;
+
+    let c = if !(0.00001..=99.99999).contains(&l) {
+        0.0
+    } else {
+        luv_max_chroma(l, color.get_hue()) * color.get_saturation()
+    };
+
+    return unsafe {
+        ColorLChuvA::new_unsafe(l, c, color.get_hue(), color.get_alpha(), WhitePoint::D65)
+    };
+}
+
+/// Converts a RGB color to HSLuv representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_hsluv(color: &ColorRGBA) -> ColorHSLuvA {
+    return lchuv_to_hsluv(&rgb_to_lchuv(color, WhitePoint::D65));
+}
+
+/// Converts a HSLuv color to RGB representation
+///
+/// # Parameters
+///
+/// color: The HSLuv color to convert
+pub fn hsluv_to_rgb(color: &ColorHSLuvA) -> ColorRGBA {
+    return lchuv_to_rgb(&hsluv_to_lchuv(color));
+}
+
+/// Converts a LCh(uv) color to HPLuv representation, colors with a white point
+/// other than D65 are adapted to D65
+///
+/// # Parameters
+///
+/// color: The LCh(uv) color to convert
+pub fn lchuv_to_hpluv(color: &ColorLChuvA) -> ColorHPLuvA {
+    let color = lchuv_d65(color);
+    let l = color.get_lightness();
+
+    // The hue is not defined for black and white
+    let (h, p) = if !(0.00001..=99.99999).contains(&l) {
+        (0.0, 0.0)
+    } else {
+        (color.get_hue(), color.get_chroma() / luv_max_safe_chroma(l))
+    };
+
+    return unsafe { ColorHPLuvA::new_unsafe(h, p, l / 100.0, color.get_alpha()) };
+}
+
+/// Converts a HPLuv color to LCh(uv) representation relative to D65
+///
+/// # Parameters
+///
+/// color: The HPLuv color to convert
+pub fn hpluv_to_lchuv(color: &ColorHPLuvA) -> ColorLChuvA {
+    let l = color.get_lightness() * 100.0;
+
+    let c = if !(0.00001..=99.99999).contains(&l) {
+        0.0
+    } else {
+        luv_max_safe_chroma(l) * color.get_perceived_saturation()
+    };
+
+    return unsafe {
+        ColorLChuvA::new_unsafe(l, c, color.get_hue(), color.get_alpha(), WhitePoint::D65)
+    };
+}
+
+/// Converts a RGB color to HPLuv representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_hpluv(color: &ColorRGBA) -> ColorHPLuvA {
+    return lchuv_to_hpluv(&rgb_to_lchuv(color, WhitePoint::D65));
+}
+
+/// Converts a HPLuv color to RGB representation, colors with a perceived
+/// saturation above 1 may be outside of the sRGB gamut and are then clipped
+///
+/// # Parameters
+///
+/// color: The HPLuv color to convert
+pub fn hpluv_to_rgb(color: &ColorHPLuvA) -> ColorRGBA {
+    return lchuv_to_rgb(&hpluv_to_lchuv(color));
+}
+
+/// Calculates the inverse of a 3x3 matrix
+fn mat_inv(matrix: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let [[a, b, c], [d, e, f], [g, h, i]] = *matrix;
+    let det = a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g);
+
+    return [
+        [
+            (e * i - f * h) / det,
+            (c * h - b * i) / det,
+            (b * f - c * e) / det,
+        ],
+        [
+            (f * g - d * i) / det,
+            (a * i - c * g) / det,
+            (c * d - a * f) / det,
+        ],
+        [
+            (d * h - e * g) / det,
+            (b * g - a * h) / det,
+            (a * e - b * d) / det,
+        ],
+    ];
+}
+
+/// Calculates the matrix converting linear RGB values of an RGB color space
+/// to XYZ relative to the white point of the space
+///
+/// # Parameters
+///
+/// space: The RGB color space to calculate the matrix for
+pub fn rgb_space_to_xyz_matrix(space: &RgbSpace) -> [[f32; 3]; 3] {
+    let primaries = [
+        space.get_red_chromaticity(),
+        space.get_green_chromaticity(),
+        space.get_blue_chromaticity(),
+    ];
+    let columns = primaries.map(|[x, y]| [x / y, 1.0, (1.0 - x - y) / y]);
+    let primary_matrix = [
+        [columns[0][0], columns[1][0], columns[2][0]],
+        [columns[0][1], columns[1][1], columns[2][1]],
+        [columns[0][2], columns[1][2], columns[2][2]],
+    ];
+    let scale = mat_mul(&mat_inv(&primary_matrix), &space.get_white_point().get());
+
+    return primary_matrix.map(|row| [row[0] * scale[0], row[1] * scale[1], row[2] * scale[2]]);
+}
+
+/// Calculates the matrix converting XYZ relative to the white point of an RGB
+/// color space to linear RGB values of the space
+///
+/// # Parameters
+///
+/// space: The RGB color space to calculate the matrix for
+pub fn xyz_to_rgb_space_matrix(space: &RgbSpace) -> [[f32; 3]; 3] {
+    return mat_inv(&rgb_space_to_xyz_matrix(space));
+}
+
+/// Converts a color in an arbitrary RGB color space to XYZ representation, the
+/// result is not clipped so colors of spaces with primaries outside of the
+/// spectral locus like AP0 may have negative tristimulus values
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn space_rgb_to_xyz(color: &ColorSpaceRGBA) -> ColorXYZA {
+    let space = color.get_space();
+    let transfer = space.get_transfer_function();
+    let linear = [
+        transfer.decode(color.get_red()),
+        transfer.decode(color.get_green()),
+        transfer.decode(color.get_blue()),
+    ];
+    let xyz = mat_mul(&rgb_space_to_xyz_matrix(&space), &linear);
+    let adapted = adapt_xyz(
+        &unsafe { ColorXYZA::new_unsafe(xyz[0], xyz[1], xyz[2], color.get_alpha()) },
+        space.get_white_point(),
+        WhitePoint::D65,
+    );
+
+    return adapted;
+}
+
+/// Converts a XYZ color to an arbitrary RGB color space, colors outside of the
+/// gamut of the space are clipped, scene referred spaces are only clipped at 0
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+///
+/// space: The RGB color space to convert to
+pub fn xyz_to_space_rgb(color: &ColorXYZA, space: RgbSpace) -> ColorSpaceRGBA {
+    let transfer = space.get_transfer_function();
+    let adapted = adapt_xyz(color, WhitePoint::D65, space.get_white_point());
+    let linear = mat_mul(
+        &xyz_to_rgb_space_matrix(&space),
+        &[adapted.get_x(), adapted.get_y(), adapted.get_z()],
+    );
+
+    return ColorSpaceRGBA::new(
+        transfer.encode(space.clamp_linear(linear[0])),
+        transfer.encode(space.clamp_linear(linear[1])),
+        transfer.encode(space.clamp_linear(linear[2])),
+        color.get_alpha(),
+        space,
+    );
+}
+
+/// Converts a RGB color to an arbitrary RGB color space
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// space: The RGB color space to convert to
+pub fn rgb_to_space_rgb(color: &ColorRGBA, space: RgbSpace) -> ColorSpaceRGBA {
+    return xyz_to_space_rgb(&rgb_to_xyz(color), space);
+}
+
+/// Converts a color in an arbitrary RGB color space to RGB representation,
+/// colors outside of the sRGB gamut are clipped
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn space_rgb_to_rgb(color: &ColorSpaceRGBA) -> ColorRGBA {
+    return xyz_to_rgb(&space_rgb_to_xyz(color));
+}
+
+/// Converts a color from one RGB color space to another, colors outside of
+/// the gamut of the target space are clipped
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// space: The RGB color space to convert to
+pub fn change_rgb_space(color: &ColorSpaceRGBA, space: RgbSpace) -> ColorSpaceRGBA {
+    return xyz_to_space_rgb(&space_rgb_to_xyz(color), space);
+}
+
+/// The luminance in nits of the reference white, this is the luminance of a
+/// XYZ color with a Y value of 1 as given by ITU-R BT.2408
+pub const REFERENCE_WHITE_LUMINANCE: f32 = 203.0;
+
+/// The relative scene light which the HLG encoding maps to the reference white
+/// signal level of 0.75
+pub const HLG_REFERENCE_SCENE_LIGHT: f32 = 0.26496256;
+
+/// The largest luminance in nits which can be encoded with PQ
+const PQ_MAX_LUMINANCE: f32 = 10000.0;
+
+/// The PQ constant m1
+const PQ_M1: f32 = 2610.0 / 16384.0;
+
+/// The PQ constant m2
+const PQ_M2: f32 = 2523.0 / 4096.0 * 128.0;
+
+/// The PQ constant c1
+const PQ_C1: f32 = 3424.0 / 4096.0;
+
+/// The PQ constant c2
+const PQ_C2: f32 = 2413.0 / 4096.0 * 32.0;
+
+/// The PQ constant c3
+const PQ_C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+/// The HLG constant a
+const HLG_A: f32 = 0.17883277;
+
+/// The HLG constant b
+const HLG_B: f32 = 0.28466892;
+
+/// The HLG constant c
+#[allow(clippy::excessive_precision)]
+const HLG_C: f32 = 0.55991073;
+
+/// The matrix converting XYZ to the LMS cone space of ICtCp, it is the BT.2100
+/// matrix from linear BT.2020 RGB to LMS applied after the conversion from XYZ
+/// to linear BT.2020 RGB
+const XYZ_TO_ICTCP_LMS: [[f32; 3]; 3] = [
+    [0.35922107, 0.6976275, -0.035870023],
+    [-0.19211008, 1.1004957, 0.07540125],
+    [0.0070791403, 0.0748337, 0.8435089],
+];
+
+/// The matrix converting the LMS cone space of ICtCp to XYZ
+const ICTCP_LMS_TO_XYZ: [[f32; 3]; 3] = [
+    [2.0703337, -1.3264781, 0.20661433],
+    [0.3648197, 0.6804959, -0.04531565],
+    [-0.04974102, -0.049239196, 1.1878102],
+];
+
+/// The matrix converting PQ encoded LMS to ICtCp
+const ICTCP_LMS_TO_ICTCP: [[f32; 3]; 3] = [
+    [0.5, 0.5, 0.0],
+    [6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0],
+    [17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0],
+];
+
+/// The matrix converting ICtCp to PQ encoded LMS
+const ICTCP_TO_ICTCP_LMS: [[f32; 3]; 3] = [
+    [1.0, 0.008609037, 0.111029625],
+    [1.0, -0.008609037, -0.111029625],
+    [1.0, 0.56003135, -0.32062718],
+];
+
+/// Encodes an absolute luminance using the SMPTE ST 2084 perceptual quantizer
+///
+/// # Parameters
+///
+/// luminance: The luminance in nits to encode, it is clamped to between 0 and
+/// 10000
+pub fn pq_encode(luminance: f32) -> f32 {
+    let value = (luminance / PQ_MAX_LUMINANCE).clamp(0.0, 1.0).powf(PQ_M1);
+
+    return ((PQ_C1 + PQ_C2 * value) / (1.0 + PQ_C3 * value)).powf(PQ_M2);
+}
+
+/// Decodes a SMPTE ST 2084 perceptual quantizer signal to absolute luminance
+/// in nits
+///
+/// # Parameters
+///
+/// value: The PQ signal to decode, it is clamped to between 0 and 1
+pub fn pq_decode(value: f32) -> f32 {
+    // The root is close to 1 so it is stored as the difference from 1 to avoid
+    // cancellation in the denominator
+    let offset = (value.clamp(0.0, 1.0).ln() / PQ_M2).exp_m1();
+    let numerator = (1.0 - PQ_C1 + offset).max(0.0);
+    let denominator = PQ_C2 - PQ_C3 - PQ_C3 * offset;
+
+    return PQ_MAX_LUMINANCE * (numerator / denominator).powf(1.0 / PQ_M1);
+}
+
+/// Encodes relative scene light using the ARIB STD-B67 hybrid log-gamma
+/// OETF
+///
+/// # Parameters
+///
+/// value: The relative scene light to encode, it is clamped to between 0 and 1
+pub fn hlg_encode(value: f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+
+    return if value <= 1.0 / 12.0 {
+        (3.0 * value).sqrt()
+    } else {
+        HLG_A * (12.0 * value - HLG_B).ln() + HLG_C
+    };
+}
+
+/// Decodes an ARIB STD-B67 hybrid log-gamma signal to relative scene light
+///
+/// # Parameters
+///
+/// value: The HLG signal to decode, it is clamped to between 0 and 1
+pub fn hlg_decode(value: f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+
+    return if value <= 0.5 {
+        value * value / 3.0
+    } else {
+        (((value - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+    };
+}
+
+/// Calculates the system gamma of the HLG OOTF for a display
+fn hlg_system_gamma(peak_luminance: f32) -> f32 {
+    return 1.2 + 0.42 * (peak_luminance / 1000.0).log10();
+}
+
+/// Converts a HLG encoded BT.2020 RGB signal to the absolute display light in
+/// nits using the HLG OOTF of ITU-R BT.2100 for a display with zero black level
+///
+/// # Parameters
+///
+/// signal: The HLG encoded red, green and blue signals
+///
+/// peak_luminance: The peak luminance of the display in nits
+pub fn hlg_to_display_light(signal: [f32; 3], peak_luminance: f32) -> [f32; 3] {
+    let scene = signal.map(hlg_decode);
+    let luminance = 0.2627 * scene[0] + 0.678 * scene[1] + 0.0593 * scene[2];
+    let scale = peak_luminance * luminance.powf(hlg_system_gamma(peak_luminance) - 1.0);
+
+    return scene.map(|value| value * scale);
+}
+
+/// Converts absolute BT.2020 RGB display light in nits to a HLG encoded signal
+/// using the inverse HLG OOTF of ITU-R BT.2100 for a display with zero black
+/// level
+///
+/// # Parameters
+///
+/// light: The red, green and blue display light in nits
+///
+/// peak_luminance: The peak luminance of the display in nits
+pub fn display_light_to_hlg(light: [f32; 3], peak_luminance: f32) -> [f32; 3] {
+    let luminance = (0.2627 * light[0] + 0.678 * light[1] + 0.0593 * light[2]) / peak_luminance;
+    if luminance <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let gamma = hlg_system_gamma(peak_luminance);
+    let scale = luminance.powf((1.0 - gamma) / gamma) / peak_luminance;
+
+    return light.map(|value| hlg_encode(value * scale));
+}
+
+/// Converts a XYZ color to ICtCp representation, a Y value of 1 is mapped to
+/// the reference white luminance
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+pub fn xyz_to_ictcp(color: &ColorXYZA) -> ColorICtCpA {
+    let lms = mat_mul(
+        &XYZ_TO_ICTCP_LMS,
+        &[color.get_x(), color.get_y(), color.get_z()],
+    );
+    let encoded = lms.map(|value| pq_encode(value * REFERENCE_WHITE_LUMINANCE));
+    let ictcp = mat_mul(&ICTCP_LMS_TO_ICTCP, &encoded);
+
+    return unsafe { ColorICtCpA::new_unsafe(ictcp[0], ictcp[1], ictcp[2], color.get_alpha()) };
+}
+
+/// Converts an ICtCp color to XYZ representation
+///
+/// # Parameters
+///
+/// color: The ICtCp color to convert
+pub fn ictcp_to_xyz(color: &ColorICtCpA) -> ColorXYZA {
+    let encoded = mat_mul(
+        &ICTCP_TO_ICTCP_LMS,
+        &[
+            color.get_intensity(),
+            color.get_tritan(),
+            color.get_protan(),
+        ],
+    );
+    let lms = encoded.map(|value| pq_decode(value) / REFERENCE_WHITE_LUMINANCE);
+    let xyz = mat_mul(&ICTCP_LMS_TO_XYZ, &lms);
+
+    return ColorXYZA::new(xyz[0], xyz[1], xyz[2], color.get_alpha());
+}
+
+/// Converts a RGB color to ICtCp representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_ictcp(color: &ColorRGBA) -> ColorICtCpA {
+    return xyz_to_ictcp(&rgb_to_xyz(color));
+}
+
+/// Converts an ICtCp color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The ICtCp color to convert
+pub fn ictcp_to_rgb(color: &ColorICtCpA) -> ColorRGBA {
+    return xyz_to_rgb(&ictcp_to_xyz(color));
+}
+
+/// Calculates the color difference Delta E ITP of ITU-R BT.2124 between two
+/// ICtCp colors, a difference of 1 is roughly one just noticeable difference
+///
+/// # Parameters
+///
+/// color1: The first color
+///
+/// color2: The second color
+pub fn delta_e_itp(color1: &ColorICtCpA, color2: &ColorICtCpA) -> f32 {
+    let i = color1.get_intensity() - color2.get_intensity();
+    let t = 0.5 * (color1.get_tritan() - color2.get_tritan());
+    let p = color1.get_protan() - color2.get_protan();
+
+    return 720.0 * (i * i + t * t + p * p).sqrt();
+}
+
+/// The Jzazbz constant b used to adjust the X tristimulus value
+const JZ_B: f32 = 1.15;
+
+/// The Jzazbz constant g used to adjust the Y tristimulus value
+const JZ_G: f32 = 0.66;
+
+/// The exponent of the perceptual quantizer of Jzazbz
+const JZ_P: f32 = 1.7 * 2523.0 / 32.0;
+
+/// The Jzazbz constant d controlling the lightness
+const JZ_D: f32 = -0.56;
+
+/// The Jzazbz offset making the lightness of black 0
+#[allow(clippy::excessive_precision)]
+const JZ_D0: f32 = 1.6295499532821566e-11;
+
+/// The matrix converting adjusted XYZ to the LMS cone space of Jzazbz
+#[allow(clippy::excessive_precision)]
+const JZ_XYZ_TO_LMS: [[f32; 3]; 3] = [
+    [0.41478972, 0.579999, 0.014648],
+    [-0.20151, 1.120649, 0.0531008],
+    [-0.0166008, 0.2648, 0.6684799],
+];
+
+/// The matrix converting the LMS cone space of Jzazbz to adjusted XYZ
+const JZ_LMS_TO_XYZ: [[f32; 3]; 3] = [
+    [1.9242264, -1.0047923, 0.037651405],
+    [0.35031676, 0.7264812, -0.065384425],
+    [-0.09098281, -0.3127283, 1.5227666],
+];
+
+/// The matrix converting perceptually quantized LMS to Izazbz
+const JZ_LMS_TO_IAB: [[f32; 3]; 3] = [
+    [0.5, 0.5, 0.0],
+    [3.524, -4.066708, 0.542708],
+    [0.199076, 1.096799, -1.295875],
+];
+
+/// The matrix converting Izazbz to perceptually quantized LMS
+const JZ_IAB_TO_LMS: [[f32; 3]; 3] = [
+    [1.0, 0.13860504, 0.058047317],
+    [1.0, -0.13860504, -0.058047317],
+    [1.0, -0.096019246, -0.8118919],
+];
+
+/// Applies the perceptual quantizer of Jzazbz to an absolute cone response
+fn jz_quantize(value: f32) -> f32 {
+    let value = (value / PQ_MAX_LUMINANCE).max(0.0).powf(PQ_M1);
+
+    return ((PQ_C1 + PQ_C2 * value) / (1.0 + PQ_C3 * value)).powf(JZ_P);
+}
+
+/// Inverts the perceptual quantizer of Jzazbz to retrieve an absolute cone
+/// response
+fn jz_dequantize(value: f32) -> f32 {
+    let value = value.max(0.0).powf(1.0 / JZ_P);
+
+    return PQ_MAX_LUMINANCE
+        * ((value - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * value)).powf(1.0 / PQ_M1);
+}
+
+/// Converts a XYZ color with absolute tristimulus values in cd/m² to Jzazbz
+/// representation
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert, a Y value of 1 is a luminance of 1 cd/m²
+pub fn xyz_nits_to_jzazbz(color: &ColorXYZA) -> ColorJzazbzA {
+    let x = color.get_x();
+    let y = color.get_y();
+    let z = color.get_z();
+    let adjusted = [JZ_B * x - (JZ_B - 1.0) * z, JZ_G * y - (JZ_G - 1.0) * x, z];
+    let lms = mat_mul(&JZ_XYZ_TO_LMS, &adjusted).map(jz_quantize);
+    let iab = mat_mul(&JZ_LMS_TO_IAB, &lms);
+    let jz = (1.0 + JZ_D) * iab[0] / (1.0 + JZ_D * iab[0]) - JZ_D0;
+
+    return unsafe { ColorJzazbzA::new_unsafe(jz, iab[1], iab[2], color.get_alpha()) };
+}
+
+/// Converts a Jzazbz color to XYZ representation with absolute tristimulus
+/// values in cd/m²
+///
+/// # Parameters
+///
+/// color: The Jzazbz color to convert
+pub fn jzazbz_to_xyz_nits(color: &ColorJzazbzA) -> ColorXYZA {
+    let jz = color.get_lightness() + JZ_D0;
+    let iz = jz / (1.0 + JZ_D - JZ_D * jz);
+    let lms = mat_mul(&JZ_IAB_TO_LMS, &[iz, color.get_a(), color.get_b()]).map(jz_dequantize);
+    let adjusted = mat_mul(&JZ_LMS_TO_XYZ, &lms);
+    let z = adjusted[2];
+    let x = (adjusted[0] + (JZ_B - 1.0) * z) / JZ_B;
+    let y = (adjusted[1] + (JZ_G - 1.0) * x) / JZ_G;
+
+    return ColorXYZA::new(x, y, z, color.get_alpha());
+}
+
+/// Converts a XYZ color to Jzazbz representation, a Y value of 1 is mapped to
+/// the reference white luminance
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+pub fn xyz_to_jzazbz(color: &ColorXYZA) -> ColorJzazbzA {
+    return xyz_nits_to_jzazbz(&unsafe {
+        ColorXYZA::new_unsafe(
+            color.get_x() * REFERENCE_WHITE_LUMINANCE,
+            color.get_y() * REFERENCE_WHITE_LUMINANCE,
+            color.get_z() * REFERENCE_WHITE_LUMINANCE,
+            color.get_alpha(),
+        )
+    });
+}
+
+/// Converts a Jzazbz color to XYZ representation, the reference white
+/// luminance is mapped to a Y value of 1
+///
+/// # Parameters
+///
+/// color: The Jzazbz color to convert
+pub fn jzazbz_to_xyz(color: &ColorJzazbzA) -> ColorXYZA {
+    let xyz = jzazbz_to_xyz_nits(color);
+
+    return unsafe {
+        ColorXYZA::new_unsafe(
+            xyz.get_x() / REFERENCE_WHITE_LUMINANCE,
+            xyz.get_y() / REFERENCE_WHITE_LUMINANCE,
+            xyz.get_z() / REFERENCE_WHITE_LUMINANCE,
+            xyz.get_alpha(),
+        )
+    };
+}
+
+/// Converts a RGB color to Jzazbz representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_jzazbz(color: &ColorRGBA) -> ColorJzazbzA {
+    return xyz_to_jzazbz(&rgb_to_xyz(color));
+}
+
+/// Converts a Jzazbz color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The Jzazbz color to convert
+pub fn jzazbz_to_rgb(color: &ColorJzazbzA) -> ColorRGBA {
+    return xyz_to_rgb(&jzazbz_to_xyz(color));
+}
+
+/// Converts a Jzazbz color to JzCzhz representation
+///
+/// # Parameters
+///
+/// color: The Jzazbz color to convert
+pub fn jzazbz_to_jzczhz(color: &ColorJzazbzA) -> ColorJzCzhzA {
+    let c = color.get_a().hypot(color.get_b());
+    let h = if c == 0.0 {
+        0.0
+    } else {
+        angle_to_hue(color.get_b().atan2(color.get_a()))
+    };
+
+    return unsafe { ColorJzCzhzA::new_unsafe(color.get_lightness(), c, h, color.get_alpha()) };
+}
+
+/// Converts a JzCzhz color to Jzazbz representation
+///
+/// # Parameters
+///
+/// color: The JzCzhz color to convert
+pub fn jzczhz_to_jzazbz(color: &ColorJzCzhzA) -> ColorJzazbzA {
+    let angle = hue_to_angle(color.get_hue());
+
+    return unsafe {
+        ColorJzazbzA::new_unsafe(
+            color.get_lightness(),
+            color.get_chroma() * angle.cos(),
+            color.get_chroma() * angle.sin(),
+            color.get_alpha(),
+        )
+    };
+}
+
+/// Converts a XYZ color with absolute tristimulus values in cd/m² to JzCzhz
+/// representation
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert, a Y value of 1 is a luminance of 1 cd/m²
+pub fn xyz_nits_to_jzczhz(color: &ColorXYZA) -> ColorJzCzhzA {
+    return jzazbz_to_jzczhz(&xyz_nits_to_jzazbz(color));
+}
+
+/// Converts a JzCzhz color to XYZ representation with absolute tristimulus
+/// values in cd/m²
+///
+/// # Parameters
+///
+/// color: The JzCzhz color to convert
+pub fn jzczhz_to_xyz_nits(color: &ColorJzCzhzA) -> ColorXYZA {
+    return jzazbz_to_xyz_nits(&jzczhz_to_jzazbz(color));
+}
+
+/// Converts a RGB color to JzCzhz representation
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+pub fn rgb_to_jzczhz(color: &ColorRGBA) -> ColorJzCzhzA {
+    return jzazbz_to_jzczhz(&rgb_to_jzazbz(color));
+}
+
+/// Converts a JzCzhz color to RGB representation, colors outside of the sRGB
+/// gamut are clipped
+///
+/// # Parameters
+///
+/// color: The JzCzhz color to convert
+pub fn jzczhz_to_rgb(color: &ColorJzCzhzA) -> ColorRGBA {
+    return jzazbz_to_rgb(&jzczhz_to_jzazbz(color));
+}
+
+/// The matrix converting XYZ to the sharpened cone space of CAM16
+const XYZ_TO_CAM16_RGB: [[f32; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+/// The matrix converting the sharpened cone space of CAM16 to XYZ
+const CAM16_RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [1.8620678, -1.0112547, 0.14918678],
+    [0.38752654, 0.62144744, -0.008973985],
+    [-0.015841499, -0.034122936, 1.0499644],
+];
+
+/// The parameters of CAM16 derived from the viewing conditions
+struct Cam16Environment {
+    /// The impact of surround
+    c: f32,
+    /// The chromatic induction factor
+    nc: f32,
+    /// The luminance level adaptation factor
+    fl: f32,
+    /// The background induction factor
+    n: f32,
+    /// The base exponential nonlinearity
+    z: f32,
+    /// The brightness and chromatic background induction factor
+    nbb: f32,
+    /// The degree of adaptation scale of the red, green and blue cone responses
+    d_rgb: [f32; 3],
+    /// The achromatic response of the white
+    aw: f32,
+}
+
+/// Calculates the CAM16 parameters of some viewing conditions
+///
+/// # Parameters
+///
+/// conditions: The viewing conditions to calculate the parameters for
+fn cam16_environment(conditions: &ViewingConditions) -> Cam16Environment {
+    let [f, c, nc] = conditions.get_surround().get_parameters();
+    let la = conditions.get_adapting_luminance();
+    let white = conditions
+        .get_white_point()
+        .get()
+        .map(|value| value * 100.0);
+
+    let k = 1.0 / (5.0 * la + 1.0);
+    let k4 = k * k * k * k;
+    let fl = 0.2 * k4 * 5.0 * la + 0.1 * (1.0 - k4) * (1.0 - k4) * (5.0 * la).cbrt();
+    let n = conditions.get_background_luminance() / 100.0;
+    let z = 1.48 + n.sqrt();
+    let nbb = 0.725 * n.powf(-0.2);
+    let d = if conditions.is_discounting() {
+        1.0
+    } else {
+        (f * (1.0 - (-(la + 42.0) / 92.0).exp() / 3.6)).clamp(0.0, 1.0)
+    };
+
+    let rgb_w = mat_mul(&XYZ_TO_CAM16_RGB, &white);
+    let d_rgb = rgb_w.map(|value| d * 100.0 / value + 1.0 - d);
+    let rgb_aw = [0, 1, 2].map(|i| cam16_compress(d_rgb[i] * rgb_w[i], fl));
+    let aw = (2.0 * rgb_aw[0] + rgb_aw[1] + rgb_aw[2] / 20.0 - 0.305) * nbb;
+
+    return Cam16Environment {
+        c: c,
+        nc: nc,
+        fl: fl,
+        n: n,
+        z: z,
+        nbb: nbb,
+        d_rgb: d_rgb,
+        aw: aw,
+    };
+}
+
+/// Applies the post adaptation nonlinear response compression of CAM16
+fn cam16_compress(value: f32, fl: f32) -> f32 {
+    let factor = (fl * value.abs() / 100.0).powf(0.42);
+
+    return (400.0 * factor / (factor + 27.13)).copysign(value) + 0.1;
+}
+
+/// Inverts the post adaptation nonlinear response compression of CAM16
+fn cam16_decompress(value: f32, fl: f32) -> f32 {
+    let value = value - 0.1;
+    let factor = 27.13 * value.abs() / (400.0 - value.abs());
+
+    return (100.0 / fl * factor.powf(1.0 / 0.42)).copysign(value);
+}
+
+/// Calculates the chroma scale of CAM16 which converts the sqrt(J/100) scaled
+/// t value into chroma
+fn cam16_chroma_scale(environment: &Cam16Environment) -> f32 {
+    return (1.64 - 0.29_f32.powf(environment.n)).powf(0.73);
+}
+
+/// Calculates the eccentricity factor of CAM16 for a hue angle in radians
+fn cam16_eccentricity(angle: f32) -> f32 {
+    return 0.25 * ((angle + 2.0).cos() + 3.8);
+}
+
+/// Converts a XYZ color to its CAM16 appearance under the given viewing
+/// conditions
+///
+/// # Parameters
+///
+/// color: The XYZ color to convert
+///
+/// conditions: The viewing conditions the color is seen under
+pub fn xyz_to_cam16(color: &ColorXYZA, conditions: ViewingConditions) -> ColorCAM16A {
+    let environment = cam16_environment(&conditions);
+    let rgb = mat_mul(
+        &XYZ_TO_CAM16_RGB,
+        &[
+            color.get_x() * 100.0,
+            color.get_y() * 100.0,
+            color.get_z() * 100.0,
+        ],
+    );
+    let [r, g, b] =
+        [0, 1, 2].map(|i| cam16_compress(environment.d_rgb[i] * rgb[i], environment.fl));
+
+    let a = r - 12.0 * g / 11.0 + b / 11.0;
+    let bb = (r + g - 2.0 * b) / 9.0;
+    let angle = bb.atan2(a);
+    let achromatic = (2.0 * r + g + b / 20.0 - 0.305) * environment.nbb;
+    let j = 100.0 * (achromatic.max(0.0) / environment.aw).powf(environment.c * environment.z);
+    let t =
+        50000.0 / 13.0 * environment.nc * environment.nbb * cam16_eccentricity(angle) * a.hypot(bb)
+            / (r + g + 21.0 / 20.0 * b);
+    let c = t.powf(0.9) * (j / 100.0).sqrt() * cam16_chroma_scale(&environment);
+
+    return unsafe {
+        ColorCAM16A::new_unsafe(j, c, angle_to_hue(angle), color.get_alpha(), conditions)
+    };
+}
+
+/// Converts a CAM16 color appearance to XYZ representation
+///
+/// # Parameters
+///
+/// color: The CAM16 color to convert
+pub fn cam16_to_xyz(color: &ColorCAM16A) -> ColorXYZA {
+    let environment = cam16_environment(&color.get_viewing_conditions());
+    let j = color.get_lightness();
+    let angle = hue_to_angle(color.get_hue());
+
+    let t = if j > 0.0 {
+        (color.get_chroma() / ((j / 100.0).sqrt() * cam16_chroma_scale(&environment)))
+            .powf(1.0 / 0.9)
+    } else {
+        0.0
+    };
+    let achromatic = environment.aw * (j / 100.0).powf(1.0 / (environment.c * environment.z));
+    let p2 = achromatic / environment.nbb + 0.305;
+    let p3 = 21.0 / 20.0;
+
+    let (a, b) = if t == 0.0 {
+        (0.0, 0.0)
+    } else {
+        let p1 = 50000.0 / 13.0 * environment.nc * environment.nbb * cam16_eccentricity(angle) / t;
+        let (sin, cos) = angle.sin_cos();
+
+        if sin.abs() >= cos.abs() {
+            let p4 = p1 / sin;
+            let b = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                / (p4 + (2.0 + p3) * (220.0 / 1403.0) * (cos / sin) - 27.0 / 1403.0
+                    + p3 * (6300.0 / 1403.0));
+
+            (b * cos / sin, b)
+        } else {
+            let p5 = p1 / cos;
+            let a = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                / (p5 + (2.0 + p3) * (220.0 / 1403.0)
+                    - (27.0 / 1403.0 - p3 * (6300.0 / 1403.0)) * (sin / cos));
+
+            (a, a * sin / cos)
+        }
+    };
+
+    let compressed = [
+        (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0,
+        (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0,
+        (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0,
+    ];
+    let rgb =
+        [0, 1, 2].map(|i| cam16_decompress(compressed[i], environment.fl) / environment.d_rgb[i]);
+    let xyz = mat_mul(&CAM16_RGB_TO_XYZ, &rgb);
+
+    return ColorXYZA::new(
+        xyz[0] / 100.0,
+        xyz[1] / 100.0,
+        xyz[2] / 100.0,
+        color.get_alpha(),
+    );
+}
+
+/// Converts a RGB color to its CAM16 appearance under the given viewing
+/// conditions
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// conditions: The viewing conditions the color is seen under
+pub fn rgb_to_cam16(color: &ColorRGBA, conditions: ViewingConditions) -> ColorCAM16A {
+    return xyz_to_cam16(&rgb_to_xyz(color), conditions);
+}
+
+/// Converts a CAM16 color appearance to RGB representation, colors outside of
+/// the sRGB gamut are clipped
+///
+/// # Parameters
+///
+/// color: The CAM16 color to convert
+pub fn cam16_to_rgb(color: &ColorCAM16A) -> ColorRGBA {
+    return xyz_to_rgb(&cam16_to_xyz(color));
+}
+
+/// Calculates the brightness correlate Q of a CAM16 color
+///
+/// # Parameters
+///
+/// color: The CAM16 color to calculate the brightness of
+pub fn cam16_brightness(color: &ColorCAM16A) -> f32 {
+    let environment = cam16_environment(&color.get_viewing_conditions());
+
+    return 4.0 / environment.c
+        * (color.get_lightness() / 100.0).sqrt()
+        * (environment.aw + 4.0)
+        * environment.fl.powf(0.25);
+}
+
+/// Calculates the colorfulness correlate M of a CAM16 color
+///
+/// # Parameters
+///
+/// color: The CAM16 color to calculate the colorfulness of
+pub fn cam16_colorfulness(color: &ColorCAM16A) -> f32 {
+    let environment = cam16_environment(&color.get_viewing_conditions());
+
+    return color.get_chroma() * environment.fl.powf(0.25);
+}
+
+/// Calculates the saturation correlate s of a CAM16 color
+///
+/// # Parameters
+///
+/// color: The CAM16 color to calculate the saturation of
+pub fn cam16_saturation(color: &ColorCAM16A) -> f32 {
+    let brightness = cam16_brightness(color);
+    if brightness == 0.0 {
+        return 0.0;
+    }
+
+    return 100.0 * (cam16_colorfulness(color) / brightness).sqrt();
+}
+
+/// Converts a CAM16 color appearance to CAM16-UCS representation
+///
+/// # Parameters
+///
+/// color: The CAM16 color to convert
+pub fn cam16_to_cam16_ucs(color: &ColorCAM16A) -> ColorCAM16UCSA {
+    let j = 1.7 * color.get_lightness() / (1.0 + 0.007 * color.get_lightness());
+    let m = (0.0228 * cam16_colorfulness(color)).ln_1p() / 0.0228;
+    let angle = hue_to_angle(color.get_hue());
+
+    return unsafe {
+        ColorCAM16UCSA::new_unsafe(
+            j,
+            m * angle.cos(),
+            m * angle.sin(),
+            color.get_alpha(),
+            color.get_viewing_conditions(),
+        )
+    };
+}
+
+/// Converts a CAM16-UCS color to its CAM16 appearance
+///
+/// # Parameters
+///
+/// color: The CAM16-UCS color to convert
+pub fn cam16_ucs_to_cam16(color: &ColorCAM16UCSA) -> ColorCAM16A {
+    let environment = cam16_environment(&color.get_viewing_conditions());
+    let j = color.get_lightness() / (1.7 - 0.007 * color.get_lightness());
+    let m = (0.0228 * color.get_a().hypot(color.get_b())).exp_m1() / 0.0228;
+    let h = if m == 0.0 {
+        0.0
+    } else {
+        angle_to_hue(color.get_b().atan2(color.get_a()))
+    };
+
+    return ColorCAM16A::new(
+        j,
+        m / environment.fl.powf(0.25),
+        h,
+        color.get_alpha(),
+        color.get_viewing_conditions(),
+    );
+}
+
+/// Converts a RGB color to CAM16-UCS representation under the given viewing
+/// conditions
+///
+/// # Parameters
+///
+/// color: The RGB color to convert
+///
+/// conditions: The viewing conditions the color is seen under
+pub fn rgb_to_cam16_ucs(color: &ColorRGBA, conditions: ViewingConditions) -> ColorCAM16UCSA {
+    return cam16_to_cam16_ucs(&rgb_to_cam16(color, conditions));
+}
+
+/// Converts a CAM16-UCS color to RGB representation, colors outside of the
+/// sRGB gamut are clipped
+///
+/// # Parameters
+///
+/// color: The CAM16-UCS color to convert
+pub fn cam16_ucs_to_rgb(color: &ColorCAM16UCSA) -> ColorRGBA {
+    return cam16_to_rgb(&cam16_ucs_to_cam16(color));
+}
+
+/// Calculates the color difference between two CAM16-UCS colors as their
+/// euclidean distance
+///
+/// # Parameters
+///
+/// color1: The first color
+///
+/// color2: The second color
+pub fn delta_e_cam16_ucs(color1: &ColorCAM16UCSA, color2: &ColorCAM16UCSA) -> f32 {
+    let j = color1.get_lightness() - color2.get_lightness();
+    let a = color1.get_a() - color2.get_a();
+    let b = color1.get_b() - color2.get_b();
+
+    return (j * j + a * a + b * b).sqrt();
+}
+
+/// The lowest temperature in kelvin supported by the approximation of the
+/// Planckian locus
+const PLANCKIAN_MIN_TEMPERATURE: f32 = 1000.0;
+
+/// The highest temperature in kelvin supported by the approximation of the
+/// Planckian locus
+const PLANCKIAN_MAX_TEMPERATURE: f32 = 15000.0;
+
+/// The coefficients of the rational approximation of Krystek for the u
+/// coordinate of the Planckian locus, the numerator is followed by the
+/// denominator and the coefficients are given in increasing order
+#[allow(clippy::excessive_precision)]
+const PLANCKIAN_U: [[f32; 3]; 2] = [
+    [0.860117757, 1.54118254e-4, 1.28641212e-7],
+    [1.0, 8.42420235e-4, 7.08145163e-7],
+];
+
+/// The coefficients of the rational approximation of Krystek for the v
+/// coordinate of the Planckian locus, the numerator is followed by the
+/// denominator and the coefficients are given in increasing order
+#[allow(clippy::excessive_precision)]
+const PLANCKIAN_V: [[f32; 3]; 2] = [
+    [0.317398726, 4.22806245e-5, 4.20481691e-8],
+    [1.0, -2.89741816e-5, 1.61456053e-7],
+];
+
+/// Evaluates a rational function of two quadratic polynomials and its
+/// derivative, the result is returned in the order: value, derivative
+fn rational_quadratic(coefficients: &[[f32; 3]; 2], t: f32) -> [f32; 2] {
+    let [[n0, n1, n2], [d0, d1, d2]] = *coefficients;
+    let numerator = n0 + n1 * t + n2 * t * t;
+    let denominator = d0 + d1 * t + d2 * t * t;
+    let numerator_slope = n1 + 2.0 * n2 * t;
+    let denominator_slope = d1 + 2.0 * d2 * t;
+
+    return [
+        numerator / denominator,
+        (numerator_slope * denominator - numerator * denominator_slope)
+            / (denominator * denominator),
+    ];
+}
+
+/// Calculates the CIE 1960 uv chromaticity of a blackbody and its derivative
+/// with respect to the temperature using the rational approximation of
+/// Krystek, the result is returned in the order: uv, derivative of uv
+///
+/// # Parameters
+///
+/// kelvin: The temperature of the blackbody in kelvin
+fn planckian_uv(kelvin: f32) -> [[f32; 2]; 2] {
+    let [u, du] = rational_quadratic(&PLANCKIAN_U, kelvin);
+    let [v, dv] = rational_quadratic(&PLANCKIAN_V, kelvin);
+
+    return [[u, v], [du, dv]];
+}
+
+/// Converts a CIE 1960 uv chromaticity to a xy chromaticity
+fn uv_to_xy(uv: [f32; 2]) -> [f32; 2] {
+    let denominator = 2.0 * uv[0] - 8.0 * uv[1] + 4.0;
+
+    return [3.0 * uv[0] / denominator, 2.0 * uv[1] / denominator];
+}
+
+/// Converts a xy chromaticity to a CIE 1960 uv chromaticity
+fn xy_to_uv(xy: [f32; 2]) -> [f32; 2] {
+    let denominator = -2.0 * xy[0] + 12.0 * xy[1] + 3.0;
+
+    return [4.0 * xy[0] / denominator, 6.0 * xy[1] / denominator];
+}
+
+/// Converts a chromaticity to the brightest RGB color of that chromaticity,
+/// the linear components are scaled such that the largest is 1 and colors
+/// outside of the sRGB gamut are clipped
+fn chromaticity_to_rgb(xy: [f32; 2]) -> ColorRGBA {
+    let xyz = xyy_to_xyz(&unsafe { ColorxyYA::new_unsafe(xy[0], xy[1], 1.0, 1.0) });
+    let linear =
+        mat_mul(&XYZ_TO_SRGB, &[xyz.get_x(), xyz.get_y(), xyz.get_z()]).map(|value| value.max(0.0));
+    let scale = linear[0].max(linear[1]).max(linear[2]);
+
+    return ColorRGBA::new(
+        linear_to_srgb(linear[0] / scale),
+        linear_to_srgb(linear[1] / scale),
+        linear_to_srgb(linear[2] / scale),
+        1.0,
+    );
+}
+
+/// Calculates the chromaticity of a color with a given correlated color
+/// temperature and distance from the Planckian locus, the color has a
+/// luminance of 1
+///
+/// # Parameters
+///
+/// kelvin: The correlated color temperature in kelvin, it is clamped to between
+/// 1000 and 15000
+///
+/// duv: The signed distance from the Planckian locus in the CIE 1960 UCS,
+/// positive values are above the locus towards green
+pub fn cct_to_xyy(kelvin: f32, duv: f32) -> ColorxyYA {
+    let kelvin = kelvin.clamp(PLANCKIAN_MIN_TEMPERATURE, PLANCKIAN_MAX_TEMPERATURE);
+    let [[u, v], [du, dv]] = planckian_uv(kelvin);
+    let length = du.hypot(dv);
+    let [x, y] = uv_to_xy([u + duv * dv / length, v - duv * du / length]);
+
+    return ColorxyYA::new(x, y, 1.0, 1.0);
+}
+
+/// Calculates the chromaticity of a blackbody radiator, the color has a
+/// luminance of 1
+///
+/// # Parameters
+///
+/// kelvin: The temperature of the blackbody in kelvin, it is clamped to between
+/// 1000 and 15000
+pub fn blackbody_to_xyy(kelvin: f32) -> ColorxyYA {
+    return cct_to_xyy(kelvin, 0.0);
+}
+
+/// Calculates the chromaticity of CIE daylight, the color has a luminance of 1
+///
+/// # Parameters
+///
+/// kelvin: The correlated color temperature of the daylight in kelvin, it is
+/// clamped to between 4000 and 25000
+pub fn daylight_to_xyy(kelvin: f32) -> ColorxyYA {
+    let t = kelvin.clamp(4000.0, 25000.0);
+    let x = if t <= 7000.0 {
+        -4.607e9 / (t * t * t) + 2.9678e6 / (t * t) + 99.11 / t + 0.244063
+    } else {
+        -2.0064e9 / (t * t * t) + 1.9018e6 / (t * t) + 247.48 / t + 0.23704
+    };
+    let y = -3.0 * x * x + 2.87 * x - 0.275;
+
+    return ColorxyYA::new(x, y, 1.0, 1.0);
+}
+
+/// Calculates the color of a blackbody radiator as the brightest RGB color of
+/// its chromaticity, colors outside of the sRGB gamut are clipped
+///
+/// # Parameters
+///
+/// kelvin: The temperature of the blackbody in kelvin, it is clamped to between
+/// 1000 and 15000
+pub fn blackbody_to_rgb(kelvin: f32) -> ColorRGBA {
+    let color = blackbody_to_xyy(kelvin);
+
+    return chromaticity_to_rgb([color.get_x(), color.get_y()]);
+}
+
+/// Calculates the color of CIE daylight as the brightest RGB color of its
+/// chromaticity, colors outside of the sRGB gamut are clipped
+///
+/// # Parameters
+///
+/// kelvin: The correlated color temperature of the daylight in kelvin, it is
+/// clamped to between 4000 and 25000
+pub fn daylight_to_rgb(kelvin: f32) -> ColorRGBA {
+    let color = daylight_to_xyy(kelvin);
+
+    return chromaticity_to_rgb([color.get_x(), color.get_y()]);
+}
+
+/// The ratio between neighbouring temperatures in the table of the Planckian
+/// locus used to estimate the correlated color temperature
+const CCT_TABLE_RATIO: f32 = 1.01;
+
+/// The correction factor of Ohno for temperatures found by the parabolic
+/// solution with a table in steps of 1 %
+const CCT_CORRECTION: f32 = 0.99991;
+
+/// The distance from the Planckian locus from which the parabolic solution is
+/// used instead of the triangular solution
+const CCT_PARABOLIC_DUV: f32 = 0.002;
+
+/// The largest distance from the Planckian locus for which the correlated color
+/// temperature is defined
+const CCT_MAX_DUV: f32 = 0.05;
+
+/// Estimates the correlated color temperature and the distance from the
+/// Planckian locus of a XYZ color using the method of Ohno (2013). The
+/// Planckian locus of Krystek is tabulated in steps of 1 % and the temperature
+/// is found by the triangular solution around the closest entry, or by the
+/// parabolic solution with the correction factor of Ohno when the distance is
+/// at least 0.002, both are accurate to about 0.01 %. The result is
+/// returned in the order: temperature in kelvin, Duv. Returns None if the color
+/// has no luminance, if the temperature is outside of 1000 to 15000 or if the
+/// distance from the locus is larger than 0.05 where the temperature is not
+/// defined
+///
+/// # Parameters
+///
+/// color: The XYZ color to estimate the temperature of
+pub fn xyz_to_cct(color: &ColorXYZA) -> Option<[f32; 2]> {
+    if color.get_y() <= 0.0 {
+        return None;
+    }
+
+    let xyy = xyz_to_xyy(color);
+    let point = xy_to_uv([xyy.get_x(), xyy.get_y()]);
+
+    // Every entry holds the temperature, the uv chromaticity and the distance
+    // to the color, the table extends one step beyond the supported range
+    let count = ((PLANCKIAN_MAX_TEMPERATURE / PLANCKIAN_MIN_TEMPERATURE).ln()
+        / CCT_TABLE_RATIO.ln())
+    .ceil() as i32
+        + 2;
+    let table: Vec<[f32; 4]> = (0..count)
+        .map(|i| {
+            let kelvin = PLANCKIAN_MIN_TEMPERATURE * CCT_TABLE_RATIO.powi(i - 1);
+            let [[u, v], _] = planckian_uv(kelvin);
+
+            return [kelvin, u, v, (point[0] - u).hypot(point[1] - v)];
+        })
+        .collect();
+    let closest = (0..table.len())
+        .min_by(|a, b| table[*a][3].total_cmp(&table[*b][3]))
+        .unwrap();
+    if closest == 0 || closest == table.len() - 1 {
+        return None;
+    }
+
+    let [previous, middle, next] = [table[closest - 1], table[closest], table[closest + 1]];
+
+    // The differences of the squared distances are factorized to keep the
+    // precision
+    let length = (next[1] - previous[1]).hypot(next[2] - previous[2]);
+    let along =
+        ((previous[3] - next[3]) * (previous[3] + next[3]) + length * length) / (2.0 * length);
+    let fraction = along / length;
+    let sign = 1.0f32.copysign(point[1] - (previous[2] + (next[2] - previous[2]) * fraction));
+    let mut kelvin = previous[0] + (next[0] - previous[0]) * fraction;
+    let mut duv = ((previous[3] - along) * (previous[3] + along))
+        .max(0.0)
+        .sqrt()
+        * sign;
+
+    let parabolic = duv.abs() >= CCT_PARABOLIC_DUV;
+    if parabolic {
+        // The parabola is fitted to temperatures relative to the middle entry
+        let low = previous[0] - middle[0];
+        let high = next[0] - middle[0];
+        let denominator = -high * (low - high) * low;
+        let a = (low * (next[3] - middle[3]) + high * (middle[3] - previous[3])) / denominator;
+        let b = -(low * low * (next[3] - middle[3]) + high * high * (middle[3] - previous[3]))
+            / denominator;
+        let offset = -b / (2.0 * a);
+
+        kelvin = middle[0] + offset;
+        duv = (a * offset * offset + b * offset + middle[3]) * sign;
+    }
+
+    if !(PLANCKIAN_MIN_TEMPERATURE..=PLANCKIAN_MAX_TEMPERATURE).contains(&kelvin)
+        || duv.abs() > CCT_MAX_DUV
+    {
+        return None;
+    }
+
+    if parabolic {
+        kelvin =
+            (kelvin * CCT_CORRECTION).clamp(PLANCKIAN_MIN_TEMPERATURE, PLANCKIAN_MAX_TEMPERATURE);
+    }
+
+    return Some([kelvin, duv]);
+}
+
+/// Estimates the correlated color temperature and the distance from the
+/// Planckian locus of any color, the result is returned in the order:
+/// temperature in kelvin, Duv. Returns None under the same conditions as
+/// xyz_to_cct
+///
+/// # Parameters
+///
+/// color: The color to estimate the temperature of
+pub fn color_to_cct<T: Color>(color: &T) -> Option<[f32; 2]> {
+    return xyz_to_cct(&color.get_xyza());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Retrieves all test colors
+    fn get_test_values() -> [(ColorHCMA, ColorRGBA, ColorHSVA, ColorHSLA, ColorHSIA); 19] {
+        return [
+            (
+                ColorHCMA {
+                    h: 0.0,
+                    c: 0.0,
+                    m: 1.0,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorHSVA::new_hsv(0.0, 0.0, 1.0),
+                ColorHSLA::new_hsl(0.0, 0.0, 1.0),
+                ColorHSIA::new_hsi(0.0, 0.0, 1.0),
+            ),
+            (
+                ColorHCMA {
+                    h: 0.0,
+                    c: 0.0,
+                    m: 0.5,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorHSVA::new_hsv(0.0, 0.0, 0.5),
+                ColorHSLA::new_hsl(0.0, 0.0, 0.5),
+                ColorHSIA::new_hsi(0.0, 0.0, 0.5),
+            ),
+            (
+                ColorHCMA {
+                    h: 0.0,
+                    c: 0.0,
+                    m: 0.0,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorHSVA::new_hsv(0.0, 0.0, 0.0),
+                ColorHSLA::new_hsl(0.0, 0.0, 0.0),
+                ColorHSIA::new_hsi(0.0, 0.0, 0.0),
+            ),
+            (
+                ColorHCMA {
+                    h: 0.0,
+                    c: 1.0,
+                    m: 0.0,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorHSVA::new_hsv(0.0, 1.0, 1.0),
+                ColorHSLA::new_hsl(0.0, 1.0, 0.5),
+                ColorHSIA::new_hsi(0.0, 1.0, 0.3333),
+            ),
+            (
+                ColorHCMA {
+                    h: 60.0 / 360.0,
+                    c: 0.75,
+                    m: 0.0,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.75, 0.75, 0.0),
+                ColorHSVA::new_hsv(60.0 / 360.0, 1.0, 0.75),
+                ColorHSLA::new_hsl(60.0 / 360.0, 1.0, 0.375),
+                ColorHSIA::new_hsi(60.0 / 360.0, 1.0, 0.5),
+            ),
+            (
+                ColorHCMA {
+                    h: 120.0 / 360.0,
+                    c: 0.5,
+                    m: 0.0,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.0, 0.5, 0.0),
+                ColorHSVA::new_hsv(120.0 / 360.0, 1.0, 0.5),
+                ColorHSLA::new_hsl(120.0 / 360.0, 1.0, 0.25),
+                ColorHSIA::new_hsi(120.0 / 360.0, 1.0, 0.1667),
+            ),
+            (
+                ColorHCMA {
+                    h: 180.0 / 360.0,
+                    c: 0.5,
+                    m: 0.5,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.5, 1.0, 1.0),
+                ColorHSVA::new_hsv(180.0 / 360.0, 0.5, 1.0),
+                ColorHSLA::new_hsl(180.0 / 360.0, 1.0, 0.75),
+                ColorHSIA::new_hsi(180.0 / 360.0, 0.4, 0.833),
+            ),
+            (
+                ColorHCMA {
+                    h: 240.0 / 360.0,
+                    c: 0.5,
+                    m: 0.5,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.5, 0.5, 1.0),
+                ColorHSVA::new_hsv(240.0 / 360.0, 0.5, 1.0),
+                ColorHSLA::new_hsl(240.0 / 360.0, 1.0, 0.75),
+                ColorHSIA::new_hsi(240.0 / 360.0, 0.25, 0.667),
+            ),
+            (
+                ColorHCMA {
+                    h: 300.0 / 360.0,
+                    c: 0.5,
+                    m: 0.25,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.75, 0.25, 0.75),
+                ColorHSVA::new_hsv(300.0 / 360.0, 0.667, 0.75),
+                ColorHSLA::new_hsl(300.0 / 360.0, 0.5, 0.5),
+                ColorHSIA::new_hsi(300.0 / 360.0, 0.571, 0.5834),
+            ),
+            (
+                ColorHCMA {
+                    h: 61.8 / 360.0,
+                    c: 0.501,
+                    m: 0.142,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.628, 0.643, 0.142),
+                ColorHSVA::new_hsv(61.8 / 360.0, 0.779, 0.643),
+                ColorHSLA::new_hsl(61.8 / 360.0, 0.638, 0.3924),
+                ColorHSIA::new_hsi(61.8 / 360.0, 0.699, 0.471),
+            ),
+            (
+                ColorHCMA {
+                    h: 251.1 / 360.0,
+                    c: 0.814,
+                    m: 0.104,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.255, 0.104, 0.918),
+                ColorHSVA::new_hsv(251.1 / 360.0, 0.887, 0.918),
+                ColorHSLA::new_hsl(251.1 / 360.0, 0.832, 0.511),
+                ColorHSIA::new_hsi(251.1 / 360.0, 0.7555, 0.4255),
+            ),
+            (
+                ColorHCMA {
+                    h: 134.9 / 360.0,
+                    c: 0.559,
+                    m: 0.116,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.116, 0.675, 0.255),
+                ColorHSVA::new_hsv(134.9 / 360.0, 0.828, 0.675),
+                ColorHSLA::new_hsl(134.9 / 360.0, 0.7065, 0.3955),
+                ColorHSIA::new_hsi(134.9 / 360.0, 0.667, 0.349),
+            ),
+            (
+                ColorHCMA {
+                    h: 49.5 / 360.0,
+                    c: 0.888,
+                    m: 0.053,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.9405, 0.7855, 0.053),
+                ColorHSVA::new_hsv(49.5 / 360.0, 0.944, 0.941),
+                ColorHSLA::new_hsl(49.5 / 360.0, 0.893, 0.497),
+                ColorHSIA::new_hsi(49.5 / 360.0, 0.911, 0.593),
+            ),
+            (
+                ColorHCMA {
+                    h: 283.7 / 360.0,
+                    c: 0.710,
+                    m: 0.187,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.704, 0.187, 0.897),
+                ColorHSVA::new_hsv(283.7 / 360.0, 0.792, 0.897),
+                ColorHSLA::new_hsl(283.7 / 360.0, 0.775, 0.542),
+                ColorHSIA::new_hsi(283.7 / 360.0, 0.686, 0.596),
+            ),
+            (
+                ColorHCMA {
+                    h: 14.3 / 360.0,
+                    c: 0.615,
+                    m: 0.316,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.931, 0.463, 0.316),
+                ColorHSVA::new_hsv(14.3 / 360.0, 0.661, 0.931),
+                ColorHSLA::new_hsl(14.3 / 360.0, 0.81749, 0.6239),
+                ColorHSIA::new_hsi(14.3 / 360.0, 0.4454, 0.570),
+            ),
+            (
+                ColorHCMA {
+                    h: 56.9 / 360.0,
+                    c: 0.466,
+                    m: 0.532,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.998, 0.974, 0.532),
+                ColorHSVA::new_hsv(56.9 / 360.0, 0.467, 0.998),
                 ColorHSLA::new_hsl(56.9 / 360.0, 0.991, 0.765),
                 ColorHSIA::new_hsi(56.9 / 360.0, 0.3625, 0.8345),
             ),
             (
-                ColorHCMA {
-                    h: 162.4 / 360.0,
-                    c: 0.696,
-                    m: 0.099,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.099, 0.795, 0.591),
-                ColorHSVA::new_hsv(162.4 / 360.0, 0.875, 0.795),
-                ColorHSLA::new_hsl(162.4 / 360.0, 0.779, 0.447),
-                ColorHSIA::new_hsi(162.4 / 360.0, 0.800, 0.495),
+                ColorHCMA {
+                    h: 162.4 / 360.0,
+                    c: 0.696,
+                    m: 0.099,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.099, 0.795, 0.591),
+                ColorHSVA::new_hsv(162.4 / 360.0, 0.875, 0.795),
+                ColorHSLA::new_hsl(162.4 / 360.0, 0.779, 0.447),
+                ColorHSIA::new_hsi(162.4 / 360.0, 0.800, 0.495),
+            ),
+            (
+                ColorHCMA {
+                    h: 248.3 / 360.0,
+                    c: 0.448,
+                    m: 0.149,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.211, 0.149, 0.597),
+                ColorHSVA::new_hsv(248.3 / 360.0, 0.750, 0.597),
+                ColorHSLA::new_hsl(248.3 / 360.0, 0.601, 0.373),
+                ColorHSIA::new_hsi(248.3 / 360.0, 0.533, 0.319),
+            ),
+            (
+                ColorHCMA {
+                    h: 240.5 / 360.0,
+                    c: 0.228,
+                    m: 0.493,
+                    a: 1.0,
+                },
+                ColorRGBA::new_rgb(0.495, 0.493, 0.721),
+                ColorHSVA::new_hsv(240.5 / 360.0, 0.316, 0.721),
+                ColorHSLA::new_hsl(240.5 / 360.0, 0.290, 0.607),
+                ColorHSIA::new_hsi(240.5 / 360.0, 0.1345, 0.5695),
+            ),
+        ];
+    }
+
+    /// Rounds the HCM color for comparisons
+    fn round_hcm(color: &ColorHCMA) -> [i32; 4] {
+        return [
+            (color.h * 1000.0).round() as i32,
+            (color.c * 1000.0).round() as i32,
+            (color.m * 1000.0).round() as i32,
+            (color.a * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the RGB color for comparisons
+    fn round_rgb(color: &ColorRGBA) -> [i32; 4] {
+        return [
+            (color.get_red() * 1000.0).round() as i32,
+            (color.get_green() * 1000.0).round() as i32,
+            (color.get_blue() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the HSV color for comparisons
+    fn round_hsv(color: &ColorHSVA) -> [i32; 4] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_saturation() * 1000.0).round() as i32,
+            (color.get_value() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the HSL color for comparisons
+    fn round_hsl(color: &ColorHSLA) -> [i32; 4] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_saturation() * 1000.0).round() as i32,
+            (color.get_lightness() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the HSI color for comparisons
+    fn round_hsi(color: &ColorHSIA) -> [i32; 4] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_saturation() * 1000.0).round() as i32,
+            (color.get_intensity() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod conversion {
+        use super::*;
+
+        #[test]
+        fn from_rgb() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let rgb = &values.1;
+                let hcm = ColorHCMA::from_rgb(rgb);
+
+                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+            }
+        }
+
+        #[test]
+        fn from_hsv() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let hsv = &values.2;
+                let hcm = ColorHCMA::from_hsv(hsv);
+
+                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+            }
+        }
+
+        #[test]
+        fn from_hsl() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let hsl = &values.3;
+                let hcm = ColorHCMA::from_hsl(hsl);
+
+                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+            }
+        }
+
+        #[test]
+        fn from_hsi() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let hsi = &values.4;
+                let hcm = ColorHCMA::from_hsi(hsi);
+
+                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+            }
+        }
+
+        #[test]
+        fn to_rgb() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let hcm = &values.0;
+                let rgb = hcm.to_rgb();
+
+                assert_eq!(round_rgb(&values.1), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn to_hsv() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let hcm = &values.0;
+                let hsv = hcm.to_hsv();
+
+                assert_eq!(round_hsv(&values.2), round_hsv(&hsv));
+            }
+        }
+
+        #[test]
+        fn to_hsl() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let hcm = &values.0;
+                let hsl = hcm.to_hsl();
+
+                assert_eq!(round_hsl(&values.3), round_hsl(&hsl));
+            }
+        }
+
+        #[test]
+        fn to_hsi() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let hcm = &values.0;
+                let hsi = hcm.to_hsi();
+
+                assert_eq!(round_hsi(&values.4), round_hsi(&hsi));
+            }
+        }
+
+        #[test]
+        fn from_hwb() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let rgb = &values.1;
+                let max = rgb.get_red().max(rgb.get_green()).max(rgb.get_blue());
+                let min = rgb.get_red().min(rgb.get_green()).min(rgb.get_blue());
+                let hwb = ColorHWBA::new_hwb(values.0.h, min, 1.0 - max);
+                let hcm = ColorHCMA::from_hwb(&hwb);
+
+                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+            }
+        }
+
+        #[test]
+        fn from_hwb_achromatic() {
+            let hwb = ColorHWBA::new_hwb(0.3, 0.6, 0.6);
+            let hcm = ColorHCMA::from_hwb(&hwb);
+
+            assert_eq!(round_hcm(&hcm), [300, 0, 500, 1000]);
+        }
+
+        #[test]
+        fn to_hwb() {
+            let test_values = get_test_values();
+
+            for values in test_values.iter() {
+                let rgb = &values.1;
+                let max = rgb.get_red().max(rgb.get_green()).max(rgb.get_blue());
+                let min = rgb.get_red().min(rgb.get_green()).min(rgb.get_blue());
+                let hwb = values.0.to_hwb();
+
+                assert_eq!(
+                    [
+                        (values.0.h * 1000.0).round() as i32,
+                        (min * 1000.0).round() as i32,
+                        ((1.0 - max) * 1000.0).round() as i32,
+                        1000,
+                    ],
+                    [
+                        (hwb.get_hue() * 1000.0).round() as i32,
+                        (hwb.get_whiteness() * 1000.0).round() as i32,
+                        (hwb.get_blackness() * 1000.0).round() as i32,
+                        (hwb.get_alpha() * 1000.0).round() as i32,
+                    ]
+                );
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the XYZ conversions
+    fn get_test_values_xyz() -> [(ColorRGBA, ColorXYZA, ColorxyYA); 6] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorXYZA::new_xyz(0.95047, 1.0, 1.08883),
+                ColorxyYA::new_xyy(0.3127, 0.3290, 1.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorXYZA::new_xyz(0.20344, 0.21404, 0.233054),
+                ColorxyYA::new_xyy(0.3127, 0.3290, 0.21404),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorXYZA::new_xyz(0.412456, 0.212673, 0.019334),
+                ColorxyYA::new_xyy(0.64, 0.33, 0.212673),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorXYZA::new_xyz(0.357576, 0.715152, 0.119192),
+                ColorxyYA::new_xyy(0.3, 0.6, 0.715152),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorXYZA::new_xyz(0.180438, 0.072175, 0.950304),
+                ColorxyYA::new_xyy(0.15, 0.06, 0.072175),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorXYZA::new(0.302536, 0.225828, 0.058971, 0.5),
+                ColorxyYA::new(0.5151, 0.384496, 0.225828, 0.5),
+            ),
+        ];
+    }
+
+    /// Rounds the XYZ color for comparisons
+    fn round_xyz(color: &ColorXYZA) -> [i32; 4] {
+        return [
+            (color.get_x() * 1000.0).round() as i32,
+            (color.get_y() * 1000.0).round() as i32,
+            (color.get_z() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the xyY color for comparisons
+    fn round_xyy(color: &ColorxyYA) -> [i32; 4] {
+        return [
+            (color.get_x() * 1000.0).round() as i32,
+            (color.get_y() * 1000.0).round() as i32,
+            (color.get_luminance() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod xyz {
+        use super::*;
+
+        #[test]
+        fn rgb_to_xyz() {
+            for values in get_test_values_xyz().iter() {
+                let xyz = super::super::rgb_to_xyz(&values.0);
+
+                assert_eq!(round_xyz(&values.1), round_xyz(&xyz));
+            }
+        }
+
+        #[test]
+        fn xyz_to_rgb() {
+            for values in get_test_values_xyz().iter() {
+                let rgb = super::super::xyz_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn xyz_to_xyy() {
+            for values in get_test_values_xyz().iter() {
+                let xyy = super::super::xyz_to_xyy(&values.1);
+
+                assert_eq!(round_xyy(&values.2), round_xyy(&xyy));
+            }
+        }
+
+        #[test]
+        fn xyy_to_xyz() {
+            for values in get_test_values_xyz().iter() {
+                let xyz = super::super::xyy_to_xyz(&values.2);
+
+                assert_eq!(round_xyz(&values.1), round_xyz(&xyz));
+            }
+        }
+
+        #[test]
+        fn xyz_to_xyy_black() {
+            let xyy = super::super::xyz_to_xyy(&ColorXYZA::new_xyz(0.0, 0.0, 0.0));
+
+            assert_eq!(round_xyy(&xyy), [313, 329, 0, 1000]);
+        }
+    }
+
+    /// Retrieves all test colors for the L*a*b* conversions
+    fn get_test_values_lab() -> [(ColorRGBA, ColorLabA, ColorLabA); 6] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorLabA::new_lab(100.0, 0.0, 0.0, WhitePoint::D65),
+                ColorLabA::new_lab(100.0, 0.0, 0.0, WhitePoint::D50),
+            ),
+            (
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorLabA::new_lab(53.389, 0.0, 0.0, WhitePoint::D65),
+                ColorLabA::new_lab(53.389, 0.0, 0.0, WhitePoint::D50),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorLabA::new_lab(53.2408, 80.0925, 67.2032, WhitePoint::D65),
+                ColorLabA::new_lab(54.2917, 80.8124, 69.8851, WhitePoint::D50),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorLabA::new_lab(87.7347, -86.1827, 83.1793, WhitePoint::D65),
+                ColorLabA::new_lab(87.8181, -79.2873, 80.9903, WhitePoint::D50),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorLabA::new_lab(32.297, 79.1875, -107.8602, WhitePoint::D65),
+                ColorLabA::new_lab(29.5676, 68.2987, -112.0294, WhitePoint::D50),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorLabA::new(54.64, 36.9064, 46.1234, 0.5, WhitePoint::D65),
+                ColorLabA::new(55.2379, 38.8302, 47.0055, 0.5, WhitePoint::D50),
+            ),
+        ];
+    }
+
+    /// Retrieves all test colors for the LCh(ab) conversions
+    fn get_test_values_lch() -> [(ColorLabA, ColorLChA); 4] {
+        return [
+            (
+                ColorLabA::new_lab(53.2408, 80.0925, 67.2032, WhitePoint::D65),
+                ColorLChA::new_lch(53.2408, 104.5518, 0.111108, WhitePoint::D65),
+            ),
+            (
+                ColorLabA::new_lab(87.8181, -79.2873, 80.9903, WhitePoint::D50),
+                ColorLChA::new_lch(87.8181, 113.3398, 0.373309, WhitePoint::D50),
+            ),
+            (
+                ColorLabA::new_lab(32.297, 79.1875, -107.8602, WhitePoint::D65),
+                ColorLChA::new_lch(32.297, 133.8076, 0.850791, WhitePoint::D65),
+            ),
+            (
+                ColorLabA::new(54.64, 36.9064, 46.1234, 0.5, WhitePoint::D65),
+                ColorLChA::new(54.64, 59.0715, 0.142595, 0.5, WhitePoint::D65),
+            ),
+        ];
+    }
+
+    /// Rounds the L*a*b* color for comparisons
+    fn round_lab(color: &ColorLabA) -> [i32; 4] {
+        return [
+            (color.get_lightness() * 100.0).round() as i32,
+            (color.get_a() * 100.0).round() as i32,
+            (color.get_b() * 100.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the LCh(ab) color for comparisons
+    fn round_lch(color: &ColorLChA) -> [i32; 4] {
+        return [
+            (color.get_lightness() * 100.0).round() as i32,
+            (color.get_chroma() * 100.0).round() as i32,
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod lab {
+        use super::*;
+
+        #[test]
+        fn rgb_to_lab() {
+            for values in get_test_values_lab().iter() {
+                let lab_d65 = super::super::rgb_to_lab(&values.0, WhitePoint::D65);
+                let lab_d50 = super::super::rgb_to_lab(&values.0, WhitePoint::D50);
+
+                assert_eq!(round_lab(&values.1), round_lab(&lab_d65));
+                assert_eq!(round_lab(&values.2), round_lab(&lab_d50));
+                assert_eq!(lab_d50.get_white_point(), WhitePoint::D50);
+            }
+        }
+
+        #[test]
+        fn lab_to_rgb() {
+            for values in get_test_values_lab().iter() {
+                let rgb_d65 = super::super::lab_to_rgb(&values.1);
+                let rgb_d50 = super::super::lab_to_rgb(&values.2);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb_d65));
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb_d50));
+            }
+        }
+
+        #[test]
+        fn lab_to_lch() {
+            for values in get_test_values_lch().iter() {
+                let lch = super::super::lab_to_lch(&values.0);
+
+                assert_eq!(round_lch(&values.1), round_lch(&lch));
+                assert_eq!(lch.get_white_point(), values.0.get_white_point());
+            }
+        }
+
+        #[test]
+        fn lch_to_lab() {
+            for values in get_test_values_lch().iter() {
+                let lab = super::super::lch_to_lab(&values.1);
+
+                assert_eq!(round_lab(&values.0), round_lab(&lab));
+            }
+        }
+
+        #[test]
+        fn adapt_xyz_roundtrip() {
+            let xyz = ColorXYZA::new(0.3, 0.25, 0.06, 0.5);
+            let adapted = adapt_xyz(&xyz, WhitePoint::D65, WhitePoint::A);
+            let restored = adapt_xyz(&adapted, WhitePoint::A, WhitePoint::D65);
+            let white = adapt_xyz(
+                &ColorXYZA::new_xyz(0.95047, 1.0, 1.08883),
+                WhitePoint::D65,
+                WhitePoint::D50,
+            );
+
+            assert_eq!(round_xyz(&xyz), round_xyz(&restored));
+            assert_eq!(round_xyz(&white), [964, 1000, 825, 1000]);
+        }
+    }
+
+    /// Retrieves all test colors for the OKLab conversions
+    fn get_test_values_oklab() -> [(ColorRGBA, ColorOKLabA, ColorOKLChA); 5] {
+        return [
+            (
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorOKLabA::new_oklab(0.598181, 0.0, 0.0),
+                ColorOKLChA::new_oklch(0.598181, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorOKLabA::new_oklab(0.627987, 0.22484, 0.125799),
+                ColorOKLChA::new_oklch(0.627987, 0.25764, 0.081187),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorOKLabA::new_oklab(0.866433, -0.233916, 0.179418),
+                ColorOKLChA::new_oklch(0.866433, 0.294801, 0.395864),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorOKLabA::new_oklab(0.451978, -0.03243, -0.311611),
+                ColorOKLChA::new_oklch(0.451978, 0.313294, 0.733496),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorOKLabA::new(0.62279, 0.102084, 0.102295, 0.5),
+                ColorOKLChA::new(0.62279, 0.144517, 0.125164, 0.5),
+            ),
+        ];
+    }
+
+    /// Rounds the OKLab color for comparisons
+    fn round_oklab(color: &ColorOKLabA) -> [i32; 4] {
+        return [
+            (color.get_lightness() * 1000.0).round() as i32,
+            (color.get_a() * 1000.0).round() as i32,
+            (color.get_b() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the OKLCh color for comparisons, the hue is ignored for gray
+    /// colors
+    fn round_oklch(color: &ColorOKLChA) -> [i32; 4] {
+        let c = (color.get_chroma() * 1000.0).round() as i32;
+
+        return [
+            (color.get_lightness() * 1000.0).round() as i32,
+            c,
+            if c == 0 {
+                0
+            } else {
+                (color.get_hue() * 1000.0).round() as i32
+            },
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod oklab {
+        use super::*;
+
+        #[test]
+        fn rgb_to_oklab() {
+            for values in get_test_values_oklab().iter() {
+                let oklab = super::super::rgb_to_oklab(&values.0);
+
+                assert_eq!(round_oklab(&values.1), round_oklab(&oklab));
+            }
+        }
+
+        #[test]
+        fn oklab_to_rgb() {
+            for values in get_test_values_oklab().iter() {
+                let rgb = super::super::oklab_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn rgb_to_oklch() {
+            for values in get_test_values_oklab().iter() {
+                let oklch = super::super::rgb_to_oklch(&values.0);
+
+                assert_eq!(round_oklch(&values.2), round_oklch(&oklch));
+            }
+        }
+
+        #[test]
+        fn oklch_to_rgb() {
+            for values in get_test_values_oklab().iter() {
+                let rgb = super::super::oklch_to_rgb(&values.2);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the Okhsv and Okhsl conversions
+    fn get_test_values_okhsx() -> [(ColorRGBA, ColorOkHSVA, ColorOkHSLA); 8] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorOkHSVA::new_okhsv(0.0, 0.0, 1.0),
+                ColorOkHSLA::new_okhsl(0.0, 0.0, 1.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorOkHSVA::new_okhsv(0.0, 0.0, 0.53376),
+                ColorOkHSLA::new_okhsl(0.0, 0.0, 0.53376),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorOkHSVA::new_okhsv(0.0, 0.0, 0.0),
+                ColorOkHSLA::new_okhsl(0.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorOkHSVA::new_okhsv(0.081205, 1.0, 1.0),
+                ColorOkHSLA::new_okhsl(0.081205, 1.0, 0.568085),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorOkHSVA::new_okhsv(0.39582, 1.0, 1.0),
+                ColorOkHSLA::new_okhsl(0.39582, 1.0, 0.844529),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorOkHSVA::new_okhsv(0.733478, 1.0, 1.0),
+                ColorOkHSLA::new_okhsl(0.733478, 1.0, 0.366565),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorOkHSVA::new(0.125193, 0.812157, 0.812032, 0.5),
+                ColorOkHSLA::new(0.125193, 0.778923, 0.562107, 0.5),
+            ),
+            (
+                ColorRGBA::new_rgb(0.2, 0.5, 0.7),
+                ColorOkHSVA::new_okhsv(0.670959, 0.780353, 0.715755),
+                ColorOkHSLA::new_okhsl(0.670959, 0.709667, 0.506026),
+            ),
+        ];
+    }
+
+    /// Rounds the Okhsv color for comparisons
+    fn round_okhsv(color: &ColorOkHSVA) -> [i32; 4] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_saturation() * 1000.0).round() as i32,
+            (color.get_value() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the Okhsl color for comparisons
+    fn round_okhsl(color: &ColorOkHSLA) -> [i32; 4] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_saturation() * 1000.0).round() as i32,
+            (color.get_lightness() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod okhsx {
+        use super::*;
+
+        #[test]
+        fn rgb_to_okhsv() {
+            for values in get_test_values_okhsx().iter() {
+                let okhsv = super::super::rgb_to_okhsv(&values.0);
+
+                assert_eq!(round_okhsv(&values.1), round_okhsv(&okhsv));
+            }
+        }
+
+        #[test]
+        fn okhsv_to_rgb() {
+            for values in get_test_values_okhsx().iter() {
+                let rgb = super::super::okhsv_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn rgb_to_okhsl() {
+            for values in get_test_values_okhsx().iter() {
+                let okhsl = super::super::rgb_to_okhsl(&values.0);
+
+                assert_eq!(round_okhsl(&values.2), round_okhsl(&okhsl));
+            }
+        }
+
+        #[test]
+        fn okhsl_to_rgb() {
+            for values in get_test_values_okhsx().iter() {
+                let rgb = super::super::okhsl_to_rgb(&values.2);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the L*u*v* based conversions
+    fn get_test_values_luv() -> [(ColorRGBA, ColorLuvA, ColorLuvA, ColorLChuvA); 5] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorLuvA::new_luv(53.24079, 175.015, 37.756447, WhitePoint::D65),
+                ColorLuvA::new_luv(54.291733, 175.04253, 25.958103, WhitePoint::D50),
+                ColorLChuvA::new_lchuv(53.24079, 179.04134, 0.033816654, WhitePoint::D65),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorLuvA::new_luv(87.734726, -83.07756, 107.39849, WhitePoint::D65),
+                ColorLuvA::new_luv(87.81813, -84.936554, 87.243645, WhitePoint::D50),
+                ColorLChuvA::new_lchuv(87.734726, 135.7804, 0.35478768, WhitePoint::D65),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorLuvA::new_luv(32.297012, -9.405393, -130.34233, WhitePoint::D65),
+                ColorLuvA::new_luv(29.567581, -11.539593, -121.96857, WhitePoint::D50),
+                ColorLChuvA::new_lchuv(32.297012, 130.68123, 0.7385354, WhitePoint::D65),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorLuvA::new(54.64, 81.76659, 40.680317, 0.5, WhitePoint::D65),
+                ColorLuvA::new(55.237946, 83.21489, 30.569162, 0.5, WhitePoint::D50),
+                ColorLChuvA::new(54.64, 91.32723, 0.07347543, 0.5, WhitePoint::D65),
+            ),
+            (
+                ColorRGBA::new_rgb(0.2, 0.5, 0.7),
+                ColorLuvA::new_luv(50.97216, -28.383093, -50.28567, WhitePoint::D65),
+                ColorLuvA::new_luv(50.448044, -31.719013, -43.00556, WhitePoint::D50),
+                ColorLChuvA::new_lchuv(50.97216, 57.742954, 0.6682167, WhitePoint::D65),
+            ),
+        ];
+    }
+
+    /// Retrieves all test colors for the HSLuv and HPLuv conversions
+    fn get_test_values_hsluv() -> [(ColorRGBA, ColorHSLuvA, ColorHPLuvA); 6] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorHSLuvA::new_hsluv(0.0, 0.0, 1.0),
+                ColorHPLuvA::new_hpluv(0.0, 0.0, 1.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorHSLuvA::new_hsluv(0.0, 0.0, 0.0),
+                ColorHPLuvA::new_hpluv(0.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorHSLuvA::new_hsluv(0.033816654, 1.0, 0.53240794),
+                ColorHPLuvA::new_hpluv(0.033816654, 4.2664237, 0.53240794),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 0.0),
+                ColorHSLuvA::new_hsluv(0.23853539, 1.0, 0.9713927),
+                ColorHPLuvA::new_hpluv(0.23853539, 17.839718, 0.9713927),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorHSLuvA::new(0.07347543, 0.83548045, 0.5464, 0.5),
+                ColorHPLuvA::new(0.07347543, 2.1205328, 0.5464, 0.5),
+            ),
+            (
+                ColorRGBA::new_rgb(0.2, 0.5, 0.7),
+                ColorHSLuvA::new_hsluv(0.6682167, 0.85585207, 0.5097216),
+                ColorHPLuvA::new_hpluv(0.6682167, 1.4372131, 0.5097216),
+            ),
+        ];
+    }
+
+    /// Rounds the L*u*v* color for comparisons
+    fn round_luv(color: &ColorLuvA) -> [i32; 4] {
+        return [
+            (color.get_lightness() * 100.0).round() as i32,
+            (color.get_u() * 100.0).round() as i32,
+            (color.get_v() * 100.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the LCh(uv) color for comparisons
+    fn round_lchuv(color: &ColorLChuvA) -> [i32; 4] {
+        return [
+            (color.get_lightness() * 100.0).round() as i32,
+            (color.get_chroma() * 100.0).round() as i32,
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the HSLuv color for comparisons
+    fn round_hsluv(color: &ColorHSLuvA) -> [i32; 4] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_saturation() * 1000.0).round() as i32,
+            (color.get_lightness() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the HPLuv color for comparisons
+    fn round_hpluv(color: &ColorHPLuvA) -> [i32; 4] {
+        return [
+            (color.get_hue() * 1000.0).round() as i32,
+            (color.get_perceived_saturation() * 1000.0).round() as i32,
+            (color.get_lightness() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod luv {
+        use super::*;
+
+        #[test]
+        fn rgb_to_luv() {
+            for values in get_test_values_luv().iter() {
+                let luv_d65 = super::super::rgb_to_luv(&values.0, WhitePoint::D65);
+                let luv_d50 = super::super::rgb_to_luv(&values.0, WhitePoint::D50);
+
+                assert_eq!(round_luv(&values.1), round_luv(&luv_d65));
+                assert_eq!(round_luv(&values.2), round_luv(&luv_d50));
+            }
+        }
+
+        #[test]
+        fn luv_to_rgb() {
+            for values in get_test_values_luv().iter() {
+                let rgb_d65 = super::super::luv_to_rgb(&values.1);
+                let rgb_d50 = super::super::luv_to_rgb(&values.2);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb_d65));
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb_d50));
+            }
+        }
+
+        #[test]
+        fn luv_to_lchuv() {
+            for values in get_test_values_luv().iter() {
+                let lchuv = super::super::luv_to_lchuv(&values.1);
+
+                assert_eq!(round_lchuv(&values.3), round_lchuv(&lchuv));
+            }
+        }
+
+        #[test]
+        fn lchuv_to_luv() {
+            for values in get_test_values_luv().iter() {
+                let luv = super::super::lchuv_to_luv(&values.3);
+
+                assert_eq!(round_luv(&values.1), round_luv(&luv));
+            }
+        }
+
+        #[test]
+        fn rgb_to_hsluv() {
+            for values in get_test_values_hsluv().iter() {
+                let hsluv = super::super::rgb_to_hsluv(&values.0);
+
+                assert_eq!(round_hsluv(&values.1), round_hsluv(&hsluv));
+            }
+        }
+
+        #[test]
+        fn hsluv_to_rgb() {
+            for values in get_test_values_hsluv().iter() {
+                let rgb = super::super::hsluv_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn rgb_to_hpluv() {
+            for values in get_test_values_hsluv().iter() {
+                let hpluv = super::super::rgb_to_hpluv(&values.0);
+
+                assert_eq!(round_hpluv(&values.2), round_hpluv(&hpluv));
+            }
+        }
+
+        #[test]
+        fn hpluv_to_rgb() {
+            for values in get_test_values_hsluv().iter() {
+                let rgb = super::super::hpluv_to_rgb(&values.2);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn hsluv_in_gamut() {
+            for i in 0..36 {
+                let hsluv = ColorHSLuvA::new_hsluv(i as f32 / 36.0, 1.0, 0.6);
+                let rgb = super::super::lchuv_to_rgb(&super::super::hsluv_to_lchuv(&hsluv));
+                let back = super::super::rgb_to_hsluv(&rgb);
+
+                assert_eq!(round_hsluv(&hsluv), round_hsluv(&back));
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the naive CMYK conversions
+    fn get_test_values_cmyk() -> [(ColorRGBA, ColorCMYKA); 7] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorCMYKA::new_cmyk(0.0, 0.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorCMYKA::new_cmyk(0.0, 0.0, 0.0, 1.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorCMYKA::new_cmyk(0.0, 0.0, 0.0, 0.5),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorCMYKA::new_cmyk(0.0, 1.0, 1.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 1.0),
+                ColorCMYKA::new_cmyk(1.0, 0.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorCMYKA::new(0.0, 0.5, 0.75, 0.2, 0.5),
+            ),
+            (
+                ColorRGBA::new_rgb(0.2, 0.5, 0.7),
+                ColorCMYKA::new_cmyk(0.714286, 0.285714, 0.0, 0.3),
+            ),
+        ];
+    }
+
+    /// Rounds the CMYK color for comparisons
+    fn round_cmyk(color: &ColorCMYKA) -> [i32; 5] {
+        return [
+            (color.get_cyan() * 1000.0).round() as i32,
+            (color.get_magenta() * 1000.0).round() as i32,
+            (color.get_yellow() * 1000.0).round() as i32,
+            (color.get_black() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod cmyk {
+        use super::*;
+
+        #[test]
+        fn rgb_to_cmyk() {
+            for values in get_test_values_cmyk().iter() {
+                let cmyk = super::super::rgb_to_cmyk(&values.0);
+
+                assert_eq!(round_cmyk(&values.1), round_cmyk(&cmyk));
+            }
+        }
+
+        #[test]
+        fn cmyk_to_rgb() {
+            for values in get_test_values_cmyk().iter() {
+                let rgb = super::super::cmyk_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn gray_component_replacement() {
+            let separation = CmykSeparation::new(
+                BlackGeneration::GrayComponentReplacement { amount: 0.5 },
+                4.0,
+            );
+            let gray = ColorRGBA::new_rgb(0.5, 0.5, 0.5);
+            let cmyk = rgb_to_cmyk_separated(&gray, &separation);
+
+            assert_eq!(round_cmyk(&cmyk), [333, 333, 333, 250, 1000]);
+
+            for values in get_test_values_cmyk().iter() {
+                let cmyk = rgb_to_cmyk_separated(&values.0, &separation);
+                let rgb = super::super::cmyk_to_rgb(&cmyk);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn under_color_removal() {
+            let separation = CmykSeparation::new(
+                BlackGeneration::UnderColorRemoval {
+                    start: 0.5,
+                    amount: 1.0,
+                },
+                4.0,
+            );
+            let light = ColorRGBA::new_rgb(0.6, 0.6, 0.6);
+            let dark = ColorRGBA::new_rgb(0.25, 0.25, 0.25);
+
+            assert_eq!(
+                round_cmyk(&rgb_to_cmyk_separated(&light, &separation)),
+                [400, 400, 400, 0, 1000]
+            );
+            assert_eq!(
+                round_cmyk(&rgb_to_cmyk_separated(&dark, &separation)),
+                [600, 600, 600, 375, 1000]
+            );
+
+            for values in get_test_values_cmyk().iter() {
+                let cmyk = rgb_to_cmyk_separated(&values.0, &separation);
+                let rgb = super::super::cmyk_to_rgb(&cmyk);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn ink_limit() {
+            let separation = CmykSeparation::new(
+                BlackGeneration::GrayComponentReplacement { amount: 0.0 },
+                2.4,
+            );
+            let black = ColorRGBA::new_rgb(0.0, 0.0, 0.0);
+            let cmyk = rgb_to_cmyk_separated(&black, &separation);
+
+            assert_eq!(round_cmyk(&cmyk), [800, 800, 800, 0, 1000]);
+            assert_eq!(
+                CmykSeparation::new(BlackGeneration::Full, 0.5).get_ink_limit(),
+                1.0
+            );
+        }
+    }
+
+    /// Retrieves all test colors for the YCbCr conversions
+    fn get_test_values_ycbcr() -> [(ColorRGBA, ColorYCbCrA); 7] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorYCbCrA::new_ycbcr(0.299, 0.331264, 1.0, YCbCrMatrix::BT601, YCbCrRange::Full),
+            ),
+            (
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorYCbCrA::new_ycbcr(0.5, 0.5, 0.5, YCbCrMatrix::BT601, YCbCrRange::Full),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorYCbCrA::new_ycbcr(
+                    0.921569,
+                    0.501961,
+                    0.501961,
+                    YCbCrMatrix::BT709,
+                    YCbCrRange::Limited,
+                ),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorYCbCrA::new_ycbcr(
+                    0.062745,
+                    0.501961,
+                    0.501961,
+                    YCbCrMatrix::BT709,
+                    YCbCrRange::Limited,
+                ),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorYCbCrA::new_ycbcr(
+                    0.7152,
+                    0.114572,
+                    0.045847,
+                    YCbCrMatrix::BT709,
+                    YCbCrRange::Full,
+                ),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorYCbCrA::new_ycbcr(
+                    0.0593,
+                    1.0,
+                    0.459786,
+                    YCbCrMatrix::BT2020,
+                    YCbCrRange::Full,
+                ),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorYCbCrA::new(
+                    0.486334,
+                    0.365055,
+                    0.684712,
+                    0.5,
+                    YCbCrMatrix::BT2020,
+                    YCbCrRange::Limited,
+                ),
+            ),
+        ];
+    }
+
+    /// Rounds the YCbCr color for comparisons
+    fn round_ycbcr(color: &ColorYCbCrA) -> [i32; 4] {
+        return [
+            (color.get_luma() * 1000.0).round() as i32,
+            (color.get_blue_difference() * 1000.0).round() as i32,
+            (color.get_red_difference() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod ycbcr {
+        use super::*;
+
+        #[test]
+        fn rgb_to_ycbcr() {
+            for values in get_test_values_ycbcr().iter() {
+                let ycbcr = super::super::rgb_to_ycbcr(
+                    &values.0,
+                    values.1.get_matrix(),
+                    values.1.get_range(),
+                );
+
+                assert_eq!(round_ycbcr(&values.1), round_ycbcr(&ycbcr));
+            }
+        }
+
+        #[test]
+        fn ycbcr_to_rgb() {
+            for values in get_test_values_ycbcr().iter() {
+                let rgb = super::super::ycbcr_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the YUV conversions
+    fn get_test_values_yuv() -> [(ColorRGBA, ColorYUVA); 5] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorYUVA::new_yuv(0.299, -0.147138, 0.615),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorYUVA::new_yuv(0.587, -0.288862, -0.514986),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorYUVA::new_yuv(0.114, 0.436, -0.100014),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorYUVA::new_yuv(1.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorYUVA::new(0.4968, -0.146055, 0.266003, 0.5),
+            ),
+        ];
+    }
+
+    /// Retrieves all test colors for the YIQ conversions
+    fn get_test_values_yiq() -> [(ColorRGBA, ColorYIQA); 5] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorYIQA::new_yiq(0.299, 0.595919, 0.211553),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorYIQA::new_yiq(0.587, -0.274578, -0.522742),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorYIQA::new_yiq(0.114, -0.321342, 0.311189),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorYIQA::new_yiq(1.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorYIQA::new(0.4968, 0.302636, 0.022383, 0.5),
+            ),
+        ];
+    }
+
+    /// Retrieves all test colors for the YCoCg conversions
+    fn get_test_values_ycocg() -> [(ColorRGBA, ColorYCoCgA); 5] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorYCoCgA::new_ycocg(0.25, 0.5, -0.25),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorYCoCgA::new_ycocg(0.5, 0.0, 0.5),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorYCoCgA::new_ycocg(0.25, -0.5, -0.25),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorYCoCgA::new_ycocg(1.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorYCoCgA::new(0.45, 0.3, -0.05, 0.5),
+            ),
+        ];
+    }
+
+    /// Retrieves all test colors for the YCoCg-R conversions
+    fn get_test_values_ycocg_r() -> [(ColorRGBA, ColorYCoCgRA); 4] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorYCoCgRA::new_ycocg_r(63, 255, -127),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorYCoCgRA::new_ycocg_r(127, 0, 255),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorYCoCgRA::new_ycocg_r(63, -255, -127),
+            ),
+            (
+                ColorRGBA::new(1.0, 1.0, 1.0, 0.5),
+                ColorYCoCgRA::new(255, 0, 0, 0.5),
+            ),
+        ];
+    }
+
+    /// Rounds the YUV color for comparisons
+    fn round_yuv(color: &ColorYUVA) -> [i32; 4] {
+        return [
+            (color.get_luma() * 1000.0).round() as i32,
+            (color.get_u() * 1000.0).round() as i32,
+            (color.get_v() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the YIQ color for comparisons
+    fn round_yiq(color: &ColorYIQA) -> [i32; 4] {
+        return [
+            (color.get_luma() * 1000.0).round() as i32,
+            (color.get_in_phase() * 1000.0).round() as i32,
+            (color.get_quadrature() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    /// Rounds the YCoCg color for comparisons
+    fn round_ycocg(color: &ColorYCoCgA) -> [i32; 4] {
+        return [
+            (color.get_luma() * 1000.0).round() as i32,
+            (color.get_orange_chroma() * 1000.0).round() as i32,
+            (color.get_green_chroma() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod yuv {
+        use super::*;
+
+        #[test]
+        fn rgb_to_yuv() {
+            for values in get_test_values_yuv().iter() {
+                let yuv = super::super::rgb_to_yuv(&values.0);
+
+                assert_eq!(round_yuv(&values.1), round_yuv(&yuv));
+            }
+        }
+
+        #[test]
+        fn yuv_to_rgb() {
+            for values in get_test_values_yuv().iter() {
+                let rgb = super::super::yuv_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn rgb_to_yiq() {
+            for values in get_test_values_yiq().iter() {
+                let yiq = super::super::rgb_to_yiq(&values.0);
+
+                assert_eq!(round_yiq(&values.1), round_yiq(&yiq));
+            }
+        }
+
+        #[test]
+        fn yiq_to_rgb() {
+            for values in get_test_values_yiq().iter() {
+                let rgb = super::super::yiq_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn rgb_to_ycocg() {
+            for values in get_test_values_ycocg().iter() {
+                let ycocg = super::super::rgb_to_ycocg(&values.0);
+
+                assert_eq!(round_ycocg(&values.1), round_ycocg(&ycocg));
+            }
+        }
+
+        #[test]
+        fn ycocg_to_rgb() {
+            for values in get_test_values_ycocg().iter() {
+                let rgb = super::super::ycocg_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn rgb_to_ycocg_r() {
+            for values in get_test_values_ycocg_r().iter() {
+                let ycocg_r = super::super::rgb_to_ycocg_r(&values.0);
+
+                assert_eq!(values.1, ycocg_r);
+            }
+        }
+
+        #[test]
+        fn ycocg_r_to_rgb() {
+            for values in get_test_values_ycocg_r().iter() {
+                let rgb = super::super::ycocg_r_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn ycocg_r_get() {
+            let color = ColorYCoCgRA::new(100, -20, 30, 0.5);
+
+            assert_eq!([100, -20, 30], color.get_integers());
+            assert_eq!([100.0, -20.0, 30.0, 0.5], color.get());
+        }
+
+        #[test]
+        fn ycocg_r_lossless() {
+            for r in (0..256).step_by(5) {
+                for g in (0..256).step_by(3) {
+                    for b in (0..256).step_by(7) {
+                        let rgb = ColorRGBA::new_rgb(
+                            r as f32 / 255.0,
+                            g as f32 / 255.0,
+                            b as f32 / 255.0,
+                        );
+                        let result =
+                            super::super::ycocg_r_to_rgb(&super::super::rgb_to_ycocg_r(&rgb));
+
+                        assert_eq!(
+                            [r, g, b],
+                            [
+                                (result.get_red() * 255.0).round() as i32,
+                                (result.get_green() * 255.0).round() as i32,
+                                (result.get_blue() * 255.0).round() as i32,
+                            ]
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the linear RGB conversions
+    fn get_test_values_linear() -> [(ColorRGBA, ColorLinearRGBA); 6] {
+        return [
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorLinearRGBA::new_rgb(0.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorLinearRGBA::new_rgb(1.0, 1.0, 1.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.5, 0.5, 0.5),
+                ColorLinearRGBA::new_rgb(0.214041, 0.214041, 0.214041),
+            ),
+            (
+                ColorRGBA::new_rgb(0.04, 0.2, 0.735357),
+                ColorLinearRGBA::new_rgb(0.003096, 0.033105, 0.5),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorLinearRGBA::new_rgb(1.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorLinearRGBA::new(0.603827, 0.132868, 0.033105, 0.5),
+            ),
+        ];
+    }
+
+    /// Rounds the linear RGB color for comparisons
+    fn round_linear(color: &ColorLinearRGBA) -> [i32; 4] {
+        return [
+            (color.get_red() * 1000.0).round() as i32,
+            (color.get_green() * 1000.0).round() as i32,
+            (color.get_blue() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod linear {
+        use super::*;
+
+        #[test]
+        fn rgb_to_linear_rgb() {
+            for values in get_test_values_linear().iter() {
+                let linear = super::super::rgb_to_linear_rgb(&values.0);
+
+                assert_eq!(round_linear(&values.1), round_linear(&linear));
+            }
+        }
+
+        #[test]
+        fn linear_rgb_to_rgb() {
+            for values in get_test_values_linear().iter() {
+                let rgb = super::super::linear_rgb_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn luminance() {
+            for values in get_test_values_linear().iter() {
+                let xyz = super::super::rgb_to_xyz(&values.0);
+
+                assert_eq!(
+                    (xyz.get_y() * 1000.0).round() as i32,
+                    (values.1.get_luminance() * 1000.0).round() as i32
+                );
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the RGB color space conversions
+    fn get_test_values_rgb_space() -> [(ColorRGBA, ColorSpaceRGBA); 28] {
+        return [
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.8, 0.4, 0.2, RgbSpace::SRGB),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.917501, 0.200306, 0.138591, RgbSpace::DISPLAY_P3),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorSpaceRGBA::new(0.748525, 0.421589, 0.248007, 0.5, RgbSpace::DISPLAY_P3),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.564939, 1.0, 0.234446, RgbSpace::ADOBE_RGB),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.709196, 0.399405, 0.223913, RgbSpace::ADOBE_RGB),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.792064, 0.231209, 0.073789, RgbSpace::REC2020),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.648028, 0.388544, 0.189631, RgbSpace::REC2020),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.702299, 0.275734, 0.103574, RgbSpace::PROPHOTO_RGB),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.573982, 0.381393, 0.198568, RgbSpace::PROPHOTO_RGB),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.454689, 0.985135, 0.361666, RgbSpace::DCI_P3),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.789965, 0.480555, 0.315378, RgbSpace::DCI_P3),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorSpaceRGBA::new_rgb(1.0, 1.0, 1.0, RgbSpace::DCI_P3),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.43968, 0.08979, 0.017548, RgbSpace::ACES2065_1),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.322245, 0.165501, 0.05425, RgbSpace::ACES2065_1),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.613161, 0.070205, 0.020623, RgbSpace::ACESCG),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.416916, 0.16459, 0.055807, RgbSpace::ACESCG),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.482753, 0.406219, 0.317157, RgbSpace::ACESCC),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.514517, 0.336057, 0.235182, RgbSpace::ACESCCT),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.533702, 0.323812, 0.214047, RgbSpace::SGAMUT3_SLOG3),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.493308, 0.39607, 0.29435, RgbSpace::SGAMUT3_SLOG3),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.521848, 0.239625, 0.181017, RgbSpace::AWG3_LOGC3),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.47899, 0.360571, 0.279887, RgbSpace::AWG3_LOGC3),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.376274, 0.224562, 0.137916, RgbSpace::AWG4_LOGC4),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.343492, 0.271151, 0.192028, RgbSpace::AWG4_LOGC4),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.543569, 0.342486, 0.235635, RgbSpace::VGAMUT_VLOG),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.504184, 0.411397, 0.313499, RgbSpace::VGAMUT_VLOG),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorSpaceRGBA::new_rgb(0.485376, 0.285366, 0.227351, RgbSpace::CINEMA_GAMUT_CLOG3),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorSpaceRGBA::new_rgb(0.440425, 0.342043, 0.277582, RgbSpace::CINEMA_GAMUT_CLOG3),
+            ),
+        ];
+    }
+
+    /// Rounds the RGB color space color for comparisons
+    fn round_space_rgb(color: &ColorSpaceRGBA) -> [i32; 4] {
+        return [
+            (color.get_red() * 1000.0).round() as i32,
+            (color.get_green() * 1000.0).round() as i32,
+            (color.get_blue() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod rgb_space {
+        use super::*;
+
+        #[test]
+        fn rgb_to_space_rgb() {
+            for values in get_test_values_rgb_space().iter() {
+                let space_rgb = super::super::rgb_to_space_rgb(&values.0, values.1.get_space());
+
+                assert_eq!(round_space_rgb(&values.1), round_space_rgb(&space_rgb));
+            }
+        }
+
+        #[test]
+        fn space_rgb_to_rgb() {
+            for values in get_test_values_rgb_space().iter() {
+                let rgb = super::super::space_rgb_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn change_rgb_space() {
+            for values in get_test_values_rgb_space().iter() {
+                let p3 = super::super::change_rgb_space(&values.1, RgbSpace::DISPLAY_P3);
+                let space_rgb = super::super::change_rgb_space(&p3, values.1.get_space());
+
+                assert_eq!(round_space_rgb(&values.1), round_space_rgb(&space_rgb));
+            }
+        }
+
+        #[test]
+        fn scene_referred() {
+            let xyz = super::super::rgb_to_xyz(&ColorRGBA::new_rgb(0.8, 0.4, 0.2));
+            let bright =
+                ColorXYZA::new_xyz(4.0 * xyz.get_x(), 4.0 * xyz.get_y(), 4.0 * xyz.get_z());
+            let expected = [
+                ColorSpaceRGBA::new_rgb(1.667666, 0.658362, 0.223229, RgbSpace::ACESCG),
+                ColorSpaceRGBA::new_rgb(0.596908, 0.520374, 0.431313, RgbSpace::ACESCCT),
+            ];
+
+            for expected in expected.iter() {
+                let space_rgb = super::super::xyz_to_space_rgb(&bright, expected.get_space());
+                let result = super::super::space_rgb_to_xyz(&space_rgb);
+
+                assert_eq!(round_space_rgb(expected), round_space_rgb(&space_rgb));
+                assert_eq!(round_xyz(&bright), round_xyz(&result));
+            }
+        }
+
+        #[test]
+        fn ap0_outside_locus() {
+            let ap0 = ColorSpaceRGBA::new_rgb(0.05, 0.02, 0.9, RgbSpace::ACES2065_1);
+            let xyz = super::super::space_rgb_to_xyz(&ap0);
+            let result = super::super::xyz_to_space_rgb(&xyz, RgbSpace::ACES2065_1);
+
+            assert!(xyz.get_y() < 0.0);
+            assert_eq!(round_space_rgb(&ap0), round_space_rgb(&result));
+        }
+
+        #[test]
+        fn aces_log_encodings() {
+            let acescc = crate::TransferFunction::ACEScc;
+            let acescct = crate::TransferFunction::ACEScct;
+
+            assert_eq!(414, (acescc.encode(0.18) * 1000.0).round() as i32);
+            assert_eq!(414, (acescct.encode(0.18) * 1000.0).round() as i32);
+            assert_eq!(-358, (acescc.encode(0.0) * 1000.0).round() as i32);
+            assert_eq!(73, (acescct.encode(0.0) * 1000.0).round() as i32);
+            assert_eq!(65504.0, acescc.decode(2.0));
+            assert_eq!(65504.0, acescct.decode(2.0));
+        }
+
+        #[test]
+        fn camera_log_middle_gray() {
+            let expected = [
+                (crate::TransferFunction::SLog3, 411),
+                (crate::TransferFunction::LogC3, 391),
+                (crate::TransferFunction::LogC4, 278),
+                (crate::TransferFunction::VLog, 423),
+                (crate::TransferFunction::CanonLog3, 357),
+            ];
+
+            for (transfer, code) in expected.iter() {
+                assert_eq!(*code, (transfer.encode(0.18) * 1000.0).round() as i32);
+            }
+        }
+
+        #[test]
+        fn srgb_matrix() {
+            let matrix = super::super::rgb_space_to_xyz_matrix(&RgbSpace::SRGB);
+
+            for (row, expected) in matrix.iter().zip(super::super::SRGB_TO_XYZ.iter()) {
+                for (value, expected) in row.iter().zip(expected.iter()) {
+                    assert_eq!((expected * 10000.0).round(), (value * 10000.0).round());
+                }
+            }
+        }
+
+        #[test]
+        fn transfer_round_trip() {
+            let transfers = [
+                crate::TransferFunction::Linear,
+                crate::TransferFunction::SRGB,
+                crate::TransferFunction::Gamma(2.2),
+                crate::TransferFunction::Rec709,
+                crate::TransferFunction::ProPhoto,
+                crate::TransferFunction::ACEScc,
+                crate::TransferFunction::ACEScct,
+                crate::TransferFunction::SLog3,
+                crate::TransferFunction::LogC3,
+                crate::TransferFunction::LogC4,
+                crate::TransferFunction::VLog,
+                crate::TransferFunction::CanonLog3,
+            ];
+
+            for transfer in transfers.iter() {
+                for i in 0..=100 {
+                    let value = i as f32 / 100.0;
+                    let result = transfer.decode(transfer.encode(value));
+
+                    assert_eq!((value * 1000.0).round(), (result * 1000.0).round());
+                }
+            }
+        }
+    }
+
+    /// Retrieves all test colors for the ICtCp conversions
+    fn get_test_values_ictcp() -> [(ColorRGBA, ColorICtCpA); 6] {
+        return [
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorICtCpA::new_ictcp(0.000001, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorICtCpA::new_ictcp(0.580689, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorICtCpA::new_ictcp(0.42789, -0.115696, 0.278722),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorICtCpA::new_ictcp(0.539758, -0.281234, -0.049492),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorICtCpA::new_ictcp(0.355977, 0.269332, -0.161388),
+            ),
+            (
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorICtCpA::new(0.432588, -0.110295, 0.137769, 0.5),
+            ),
+        ];
+    }
+
+    /// Rounds the ICtCp color for comparisons
+    fn round_ictcp(color: &ColorICtCpA) -> [i32; 4] {
+        return [
+            (color.get_intensity() * 1000.0).round() as i32,
+            (color.get_tritan() * 1000.0).round() as i32,
+            (color.get_protan() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod hdr {
+        use super::*;
+
+        #[test]
+        fn pq() {
+            let values = [
+                (0.0, 0),
+                (100.0, 508),
+                (203.0, 581),
+                (1000.0, 752),
+                (10000.0, 1000),
+            ];
+
+            for (luminance, signal) in values.iter() {
+                let encoded = super::super::pq_encode(*luminance);
+                let decoded = super::super::pq_decode(encoded);
+
+                assert_eq!(*signal, (encoded * 1000.0).round() as i32);
+                assert_eq!((luminance * 10.0).round(), (decoded * 10.0).round());
+            }
+        }
+
+        #[test]
+        fn hlg() {
+            let values = [
+                (0.0, 0),
+                (1.0 / 12.0, 500),
+                (super::super::HLG_REFERENCE_SCENE_LIGHT, 750),
+                (1.0, 1000),
+            ];
+
+            for (light, signal) in values.iter() {
+                let encoded = super::super::hlg_encode(*light);
+                let decoded = super::super::hlg_decode(encoded);
+
+                assert_eq!(*signal, (encoded * 1000.0).round() as i32);
+                assert_eq!((light * 1000.0).round(), (decoded * 1000.0).round());
+            }
+        }
+
+        #[test]
+        fn hlg_display_light() {
+            let white = super::super::hlg_to_display_light([0.75; 3], 1000.0);
+            let signal = super::super::display_light_to_hlg([203.0, 100.0, 20.0], 1000.0);
+            let light = super::super::hlg_to_display_light(signal, 1000.0);
+
+            assert_eq!([203; 3], white.map(|value| value.round() as i32));
+            assert_eq!(
+                [2030, 1000, 200],
+                light.map(|value| (value * 10.0).round() as i32)
+            );
+        }
+
+        #[test]
+        fn rec2100() {
+            let white = ColorRGBA::new_rgb(1.0, 1.0, 1.0);
+            let pq = super::super::rgb_to_space_rgb(&white, RgbSpace::REC2100_PQ);
+            let hlg = super::super::rgb_to_space_rgb(&white, RgbSpace::REC2100_HLG);
+
+            assert_eq!([581, 581, 581, 1000], round_space_rgb(&pq));
+            assert_eq!([750, 750, 750, 1000], round_space_rgb(&hlg));
+            assert_eq!(
+                round_rgb(&white),
+                round_rgb(&super::super::space_rgb_to_rgb(&pq))
+            );
+            assert_eq!(
+                round_rgb(&white),
+                round_rgb(&super::super::space_rgb_to_rgb(&hlg))
+            );
+        }
+
+        #[test]
+        fn rgb_to_ictcp() {
+            for values in get_test_values_ictcp().iter() {
+                let ictcp = super::super::rgb_to_ictcp(&values.0);
+
+                assert_eq!(round_ictcp(&values.1), round_ictcp(&ictcp));
+            }
+        }
+
+        #[test]
+        fn ictcp_to_rgb() {
+            for values in get_test_values_ictcp().iter() {
+                let rgb = super::super::ictcp_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn delta_e_itp() {
+            let color1 = super::super::rgb_to_ictcp(&ColorRGBA::new_rgb(0.8, 0.4, 0.2));
+            let color2 = super::super::rgb_to_ictcp(&ColorRGBA::new_rgb(0.7, 0.45, 0.2));
+
+            assert_eq!(
+                0,
+                super::super::delta_e_itp(&color1, &color1).round() as i32
+            );
+            assert_eq!(
+                321,
+                (super::super::delta_e_itp(&color1, &color2) * 10.0).round() as i32
+            );
+        }
+    }
+
+    /// Retrieves all test colors for the Jzazbz conversions
+    fn get_test_values_jzazbz() -> [(ColorRGBA, ColorJzazbzA, ColorJzCzhzA); 5] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorJzazbzA::new_jzazbz(0.134394, 0.117889, 0.111883),
+                ColorJzCzhzA::new_jzczhz(0.134394, 0.162529, 0.120841),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorJzazbzA::new_jzazbz(0.176805, -0.109043, 0.118989),
+                ColorJzCzhzA::new_jzczhz(0.176805, 0.161396, 0.368063),
             ),
             (
-                ColorHCMA {
-                    h: 248.3 / 360.0,
-                    c: 0.448,
-                    m: 0.149,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.211, 0.149, 0.597),
-                ColorHSVA::new_hsv(248.3 / 360.0, 0.750, 0.597),
-                ColorHSLA::new_hsl(248.3 / 360.0, 0.601, 0.373),
-                ColorHSIA::new_hsi(248.3 / 360.0, 0.533, 0.319),
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorJzazbzA::new_jzazbz(0.095764, -0.040842, -0.185844),
+                ColorJzCzhzA::new_jzczhz(0.095764, 0.190279, 0.715571),
             ),
             (
-                ColorHCMA {
-                    h: 240.5 / 360.0,
-                    c: 0.228,
-                    m: 0.493,
-                    a: 1.0,
-                },
-                ColorRGBA::new_rgb(0.495, 0.493, 0.721),
-                ColorHSVA::new_hsv(240.5 / 360.0, 0.316, 0.721),
-                ColorHSLA::new_hsl(240.5 / 360.0, 0.290, 0.607),
-                ColorHSIA::new_hsi(240.5 / 360.0, 0.1345, 0.5695),
+                ColorRGBA::new(0.8, 0.4, 0.2, 0.5),
+                ColorJzazbzA::new(0.127591, 0.057075, 0.08494, 0.5),
+                ColorJzCzhzA::new(0.127591, 0.102335, 0.155836, 0.5),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 0.0),
+                ColorJzazbzA::new_jzazbz(0.0, 0.0, 0.0),
+                ColorJzCzhzA::new_jzczhz(0.0, 0.0, 0.0),
             ),
         ];
     }
 
-    /// Rounds the HCM color for comparisons
-    fn round_hcm(color: &ColorHCMA) -> [i32; 4] {
+    /// Rounds the Jzazbz color for comparisons
+    fn round_jzazbz(color: &ColorJzazbzA) -> [i32; 4] {
         return [
-            (color.h * 1000.0).round() as i32,
-            (color.c * 1000.0).round() as i32,
-            (color.m * 1000.0).round() as i32,
-            (color.a * 1000.0).round() as i32,
+            (color.get_lightness() * 1000.0).round() as i32,
+            (color.get_a() * 1000.0).round() as i32,
+            (color.get_b() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
         ];
     }
 
-    /// Rounds the RGB color for comparisons
-    fn round_rgb(color: &ColorRGBA) -> [i32; 4] {
+    /// Rounds the JzCzhz color for comparisons
+    fn round_jzczhz(color: &ColorJzCzhzA) -> [i32; 4] {
         return [
-            (color.get_red() * 1000.0).round() as i32,
-            (color.get_green() * 1000.0).round() as i32,
-            (color.get_blue() * 1000.0).round() as i32,
+            (color.get_lightness() * 1000.0).round() as i32,
+            (color.get_chroma() * 1000.0).round() as i32,
+            (color.get_hue() * 1000.0).round() as i32,
             (color.get_alpha() * 1000.0).round() as i32,
         ];
     }
 
-    /// Rounds the HSV color for comparisons
-    fn round_hsv(color: &ColorHSVA) -> [i32; 4] {
+    mod jzazbz {
+        use super::*;
+
+        #[test]
+        fn rgb_to_jzazbz() {
+            for values in get_test_values_jzazbz().iter() {
+                let jzazbz = super::super::rgb_to_jzazbz(&values.0);
+
+                assert_eq!(round_jzazbz(&values.1), round_jzazbz(&jzazbz));
+            }
+        }
+
+        #[test]
+        fn jzazbz_to_rgb() {
+            for values in get_test_values_jzazbz().iter() {
+                let rgb = super::super::jzazbz_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
+
+        #[test]
+        fn jzazbz_to_jzczhz() {
+            for values in get_test_values_jzazbz().iter() {
+                let jzczhz = super::super::jzazbz_to_jzczhz(&values.1);
+
+                assert_eq!(round_jzczhz(&values.2), round_jzczhz(&jzczhz));
+            }
+        }
+
+        #[test]
+        fn jzczhz_to_jzazbz() {
+            for values in get_test_values_jzazbz().iter() {
+                let jzazbz = super::super::jzczhz_to_jzazbz(&values.2);
+
+                assert_eq!(round_jzazbz(&values.1), round_jzazbz(&jzazbz));
+            }
+        }
+
+        #[test]
+        fn high_dynamic_range() {
+            let white = super::super::rgb_to_xyz(&ColorRGBA::new_rgb(1.0, 1.0, 1.0));
+            let expected = [(1.0, 222), (10.0, 534), (10000.0 / 203.0, 989)];
+
+            for (scale, lightness) in expected.iter() {
+                let xyz = ColorXYZA::new_xyz(
+                    scale * white.get_x(),
+                    scale * white.get_y(),
+                    scale * white.get_z(),
+                );
+                let jzazbz = super::super::xyz_to_jzazbz(&xyz);
+                let result = super::super::jzazbz_to_xyz(&jzazbz);
+
+                assert_eq!(*lightness, (jzazbz.get_lightness() * 1000.0).round() as i32);
+                assert_eq!(1000, (result.get_y() / xyz.get_y() * 1000.0).round() as i32);
+            }
+        }
+
+        #[test]
+        fn absolute_luminance() {
+            let white = super::super::rgb_to_xyz(&ColorRGBA::new_rgb(1.0, 1.0, 1.0));
+            let xyz = ColorXYZA::new_xyz(
+                1000.0 * white.get_x(),
+                1000.0 * white.get_y(),
+                1000.0 * white.get_z(),
+            );
+            let jzazbz = super::super::xyz_nits_to_jzazbz(&xyz);
+            let jzczhz = super::super::xyz_nits_to_jzczhz(&xyz);
+            let result = super::super::jzazbz_to_xyz_nits(&jzazbz);
+            let relative = super::super::xyz_to_jzazbz(&ColorXYZA::new_xyz(
+                xyz.get_x() / super::super::REFERENCE_WHITE_LUMINANCE,
+                xyz.get_y() / super::super::REFERENCE_WHITE_LUMINANCE,
+                xyz.get_z() / super::super::REFERENCE_WHITE_LUMINANCE,
+            ));
+
+            assert_eq!(409, (jzazbz.get_lightness() * 1000.0).round() as i32);
+            assert_eq!(round_jzazbz(&relative), round_jzazbz(&jzazbz));
+            assert_eq!(
+                round_jzazbz(&jzazbz),
+                round_jzazbz(&super::super::jzczhz_to_jzazbz(&jzczhz))
+            );
+            assert_eq!(1000, result.get_y().round() as i32);
+            assert_eq!(
+                1000,
+                (super::super::jzczhz_to_xyz_nits(&jzczhz).get_y()).round() as i32
+            );
+        }
+    }
+
+    /// Retrieves all test colors for the CAM16 conversions
+    fn get_test_values_cam16() -> [(ColorRGBA, ColorCAM16A, ColorCAM16UCSA); 6] {
         return [
-            (color.get_hue() * 1000.0).round() as i32,
-            (color.get_saturation() * 1000.0).round() as i32,
-            (color.get_value() * 1000.0).round() as i32,
-            (color.get_alpha() * 1000.0).round() as i32,
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorCAM16A::new_cam16(100.0, 3.0915, 0.581894, ViewingConditions::SRGB),
+                ColorCAM16UCSA::new_cam16_ucs(100.0, -1.8976, -1.0728, ViewingConditions::SRGB),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorCAM16A::new_cam16(46.0296, 112.3966, 0.076091, ViewingConditions::SRGB),
+                ColorCAM16UCSA::new_cam16_ucs(59.1816, 40.8199, 21.1526, ViewingConditions::SRGB),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 1.0, 0.0),
+                ColorCAM16A::new_cam16(79.1001, 108.1936, 0.395075, ViewingConditions::SRGB),
+                ColorCAM16UCSA::new_cam16_ucs(86.5483, -35.4883, 27.5007, ViewingConditions::SRGB),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.0, 1.0),
+                ColorCAM16A::new_cam16(25.0629, 86.3731, 0.785428, ViewingConditions::SRGB),
+                ColorCAM16UCSA::new_cam16_ucs(36.2477, 8.5719, -37.87, ViewingConditions::SRGB),
+            ),
+            (
+                ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ColorCAM16A::new_cam16(45.695, 52.5183, 0.11893, ViewingConditions::SRGB),
+                ColorCAM16UCSA::new_cam16_ucs(58.8557, 20.0635, 18.5886, ViewingConditions::SRGB),
+            ),
+            (
+                ColorRGBA::new_rgb(0.2, 0.3, 0.4),
+                ColorCAM16A::new_cam16(22.7372, 24.1021, 0.694504, ViewingConditions::SRGB),
+                ColorCAM16UCSA::new_cam16_ucs(33.3459, -5.0129, -13.7889, ViewingConditions::SRGB),
+            ),
         ];
     }
 
-    /// Rounds the HSL color for comparisons
-    fn round_hsl(color: &ColorHSLA) -> [i32; 4] {
+    /// Rounds the CAM16 color for comparisons
+    fn round_cam16(color: &ColorCAM16A) -> [i32; 4] {
         return [
+            (color.get_lightness() * 100.0).round() as i32,
+            (color.get_chroma() * 100.0).round() as i32,
             (color.get_hue() * 1000.0).round() as i32,
-            (color.get_saturation() * 1000.0).round() as i32,
-            (color.get_lightness() * 1000.0).round() as i32,
             (color.get_alpha() * 1000.0).round() as i32,
         ];
     }
 
-    /// Rounds the HSI color for comparisons
-    fn round_hsi(color: &ColorHSIA) -> [i32; 4] {
+    /// Rounds the CAM16-UCS color for comparisons
+    fn round_cam16_ucs(color: &ColorCAM16UCSA) -> [i32; 4] {
         return [
-            (color.get_hue() * 1000.0).round() as i32,
-            (color.get_saturation() * 1000.0).round() as i32,
-            (color.get_intensity() * 1000.0).round() as i32,
+            (color.get_lightness() * 100.0).round() as i32,
+            (color.get_a() * 100.0).round() as i32,
+            (color.get_b() * 100.0).round() as i32,
             (color.get_alpha() * 1000.0).round() as i32,
         ];
     }
 
-    mod conversion {
+    mod cam16 {
         use super::*;
 
         #[test]
-        fn from_rgb() {
-            let test_values = get_test_values();
+        fn rgb_to_cam16() {
+            for values in get_test_values_cam16().iter() {
+                let cam16 = super::super::rgb_to_cam16(&values.0, ViewingConditions::SRGB);
 
-            for values in test_values.iter() {
-                let rgb = &values.1;
-                let hcm = ColorHCMA::from_rgb(rgb);
+                assert_eq!(round_cam16(&values.1), round_cam16(&cam16));
+            }
+        }
 
-                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+        #[test]
+        fn cam16_to_rgb() {
+            for values in get_test_values_cam16().iter() {
+                let rgb = super::super::cam16_to_rgb(&values.1);
+
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
             }
         }
 
         #[test]
-        fn from_hsv() {
-            let test_values = get_test_values();
+        fn cam16_to_cam16_ucs() {
+            for values in get_test_values_cam16().iter() {
+                let ucs = super::super::cam16_to_cam16_ucs(&values.1);
 
-            for values in test_values.iter() {
-                let hsv = &values.2;
-                let hcm = ColorHCMA::from_hsv(hsv);
+                assert_eq!(round_cam16_ucs(&values.2), round_cam16_ucs(&ucs));
+            }
+        }
 
-                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+        #[test]
+        fn cam16_ucs_to_cam16() {
+            for values in get_test_values_cam16().iter() {
+                let cam16 = super::super::cam16_ucs_to_cam16(&values.2);
+
+                assert_eq!(round_cam16(&values.1), round_cam16(&cam16));
             }
         }
 
         #[test]
-        fn from_hsl() {
-            let test_values = get_test_values();
+        fn correlates() {
+            let cam16 = super::super::rgb_to_cam16(
+                &ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ViewingConditions::SRGB,
+            );
 
-            for values in test_values.iter() {
-                let hsl = &values.3;
-                let hcm = ColorHCMA::from_hsl(hsl);
+            assert_eq!(8362, (cam16.get_brightness() * 100.0).round() as i32);
+            assert_eq!(3797, (cam16.get_colorfulness() * 100.0).round() as i32);
+            assert_eq!(6738, (cam16.get_saturation() * 100.0).round() as i32);
+        }
 
-                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+        #[test]
+        fn reference_conditions() {
+            let conditions = ViewingConditions::new(
+                WhitePoint::new(0.9505, 1.0888),
+                318.31,
+                20.0,
+                crate::Surround::Average,
+                false,
+            );
+            let xyz = ColorXYZA::new_xyz(0.1901, 0.2, 0.2178);
+            let cam16 = super::super::xyz_to_cam16(&xyz, conditions);
+
+            assert_eq!(4173, (cam16.get_lightness() * 100.0).round() as i32);
+            assert_eq!(10, (cam16.get_chroma() * 100.0).round() as i32);
+            assert_eq!(217, (cam16.get_hue() * 360.0).round() as i32);
+            assert_eq!(19537, (cam16.get_brightness() * 100.0).round() as i32);
+            assert_eq!(
+                round_xyz(&xyz),
+                round_xyz(&super::super::cam16_to_xyz(&cam16))
+            );
+        }
+
+        #[test]
+        fn dim_surround() {
+            let dim = ViewingConditions::new(
+                WhitePoint::D65,
+                64.0 / std::f32::consts::PI * 0.2,
+                20.0,
+                crate::Surround::Dim,
+                false,
+            );
+            let values = [
+                (
+                    ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                    ColorCAM16A::new_cam16(100.0, 4.0321, 0.581722, dim),
+                ),
+                (
+                    ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                    ColorCAM16A::new_cam16(51.5005, 107.6067, 0.076292, dim),
+                ),
+                (
+                    ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                    ColorCAM16A::new_cam16(51.1835, 50.09, 0.119629, dim),
+                ),
+                (
+                    ColorRGBA::new_rgb(0.2, 0.3, 0.4),
+                    ColorCAM16A::new_cam16(28.1823, 24.7806, 0.692676, dim),
+                ),
+            ];
+
+            for values in values.iter() {
+                let cam16 = super::super::rgb_to_cam16(&values.0, dim);
+                let rgb = super::super::cam16_to_rgb(&values.1);
+
+                assert_eq!(round_cam16(&values.1), round_cam16(&cam16));
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
             }
         }
 
         #[test]
-        fn from_hsi() {
-            let test_values = get_test_values();
+        fn delta_e_cam16_ucs() {
+            let color1 = super::super::rgb_to_cam16_ucs(
+                &ColorRGBA::new_rgb(0.8, 0.4, 0.2),
+                ViewingConditions::SRGB,
+            );
+            let color2 = super::super::rgb_to_cam16_ucs(
+                &ColorRGBA::new_rgb(0.7, 0.45, 0.2),
+                ViewingConditions::SRGB,
+            );
 
-            for values in test_values.iter() {
-                let hsi = &values.4;
-                let hcm = ColorHCMA::from_hsi(hsi);
+            assert_eq!(
+                0,
+                super::super::delta_e_cam16_ucs(&color1, &color1).round() as i32
+            );
+            assert_eq!(
+                991,
+                (super::super::delta_e_cam16_ucs(&color1, &color2) * 100.0).round() as i32
+            );
+        }
+    }
 
-                assert_eq!(round_hcm(&values.0), round_hcm(&hcm));
+    mod cct {
+        use super::*;
+
+        #[test]
+        fn blackbody_to_rgb() {
+            let values = [
+                (1000.0, ColorRGBA::new_rgb(1.0, 0.090724, 0.0)),
+                (2700.0, ColorRGBA::new_rgb(1.0, 0.676993, 0.346448)),
+                (4000.0, ColorRGBA::new_rgb(1.0, 0.828394, 0.648222)),
+                (6500.0, ColorRGBA::new_rgb(1.0, 0.973995, 0.996603)),
+                (10000.0, ColorRGBA::new_rgb(0.803308, 0.85248, 1.0)),
+                (15000.0, ColorRGBA::new_rgb(0.707733, 0.78628, 1.0)),
+            ];
+
+            for (kelvin, rgb) in values.iter() {
+                assert_eq!(
+                    round_rgb(rgb),
+                    round_rgb(&super::super::blackbody_to_rgb(*kelvin))
+                );
             }
         }
 
         #[test]
-        fn to_rgb() {
-            let test_values = get_test_values();
+        fn daylight_to_rgb() {
+            let values = [
+                (5003.0, ColorRGBA::new_rgb(1.0, 0.921438, 0.805964)),
+                (6504.0, ColorRGBA::new_rgb(0.999552, 1.0, 0.999603)),
+                (10000.0, ColorRGBA::new_rgb(0.790194, 0.865466, 1.0)),
+            ];
 
-            for values in test_values.iter() {
-                let hcm = &values.0;
-                let rgb = hcm.to_rgb();
+            for (kelvin, rgb) in values.iter() {
+                assert_eq!(
+                    round_rgb(rgb),
+                    round_rgb(&super::super::daylight_to_rgb(*kelvin))
+                );
+            }
+        }
 
-                assert_eq!(round_rgb(&values.1), round_rgb(&rgb));
+        #[test]
+        fn standard_illuminants() {
+            let a = super::super::blackbody_to_xyy(2856.0);
+            let d50 = super::super::daylight_to_xyy(5003.0);
+            let d65 = super::super::daylight_to_xyy(6504.0);
+
+            assert_eq!(
+                [4477, 4078],
+                [a.get_x(), a.get_y()].map(|value| (value * 10000.0).round() as i32)
+            );
+            assert_eq!(
+                [3457, 3586],
+                [d50.get_x(), d50.get_y()].map(|value| (value * 10000.0).round() as i32)
+            );
+            assert_eq!(
+                [3127, 3291],
+                [d65.get_x(), d65.get_y()].map(|value| (value * 10000.0).round() as i32)
+            );
+        }
+
+        #[test]
+        fn xyz_to_cct() {
+            let values = [
+                (WhitePoint::D65, 6505, 33),
+                (WhitePoint::D50, 5000, 33),
+                (WhitePoint::A, 2855, -1),
+            ];
+
+            for (white, kelvin, duv) in values.iter() {
+                let [x, y, z] = white.get();
+                let [cct, result] = super::super::xyz_to_cct(&ColorXYZA::new_xyz(x, y, z)).unwrap();
+
+                assert_eq!(*kelvin, cct.round() as i32);
+                assert_eq!(*duv, (result * 10000.0).round() as i32);
             }
         }
 
         #[test]
-        fn to_hsv() {
-            let test_values = get_test_values();
+        fn cct_round_trip() {
+            for kelvin in [1500.0, 2700.0, 4000.0, 5000.0, 6500.0, 9000.0, 14000.0].iter() {
+                for duv in [-0.02, -0.005, 0.0, 0.005, 0.02].iter() {
+                    let xyz = super::super::xyy_to_xyz(&super::super::cct_to_xyy(*kelvin, *duv));
+                    let [cct, result] = super::super::xyz_to_cct(&xyz).unwrap();
 
-            for values in test_values.iter() {
-                let hcm = &values.0;
-                let hsv = hcm.to_hsv();
+                    assert_eq!((kelvin / 10.0).round() as i32, (cct / 10.0).round() as i32);
+                    assert_eq!(
+                        (duv * 10000.0).round() as i32,
+                        (result * 10000.0).round() as i32
+                    );
+                }
+            }
+        }
 
-                assert_eq!(round_hsv(&values.2), round_hsv(&hsv));
+        #[test]
+        fn xyz_to_cct_triangular() {
+            let values = [(2700.0, 27002), (6500.0, 65007), (9000.0, 90007)];
+
+            for (kelvin, expected) in values.iter() {
+                let xyz = super::super::xyy_to_xyz(&super::super::cct_to_xyy(*kelvin, 0.001));
+                let [cct, duv] = super::super::xyz_to_cct(&xyz).unwrap();
+
+                assert_eq!(*expected, (cct * 10.0).round() as i32);
+                assert_eq!(10, (duv * 10000.0).round() as i32);
             }
         }
 
         #[test]
-        fn to_hsl() {
-            let test_values = get_test_values();
+        fn xyz_to_cct_undefined() {
+            let black = ColorXYZA::new_xyz(0.0, 0.0, 0.0);
+            let blue = super::super::rgb_to_xyz(&ColorRGBA::new_rgb(0.0, 0.0, 1.0));
+            let hot = super::super::xyy_to_xyz(&super::super::daylight_to_xyy(25000.0));
+            let cold = super::super::xyy_to_xyz(&super::super::cct_to_xyy(1000.0, 0.0));
+            let far = super::super::xyy_to_xyz(&super::super::cct_to_xyy(5000.0, 0.06));
 
-            for values in test_values.iter() {
-                let hcm = &values.0;
-                let hsl = hcm.to_hsl();
+            assert_eq!(None, super::super::xyz_to_cct(&black));
+            assert_eq!(None, super::super::xyz_to_cct(&blue));
+            assert_eq!(None, super::super::xyz_to_cct(&hot));
+            assert_eq!(None, super::super::xyz_to_cct(&far));
+            assert_eq!(
+                100,
+                (super::super::xyz_to_cct(&cold).unwrap()[0] / 10.0).round() as i32
+            );
+        }
 
-                assert_eq!(round_hsl(&values.3), round_hsl(&hsl));
+        #[test]
+        fn color_to_cct() {
+            let [cct, duv] =
+                super::super::color_to_cct(&super::super::blackbody_to_rgb(3000.0)).unwrap();
+
+            assert_eq!(300, (cct / 10.0).round() as i32);
+            assert_eq!(0, (duv * 1000.0).round() as i32);
+        }
+    }
+
+    /// Retrieves all test colors for the RYB conversions
+    fn get_test_values_ryb() -> [(ColorRGBA, ColorRYBA); 6] {
+        return [
+            (
+                ColorRGBA::new_rgb(1.0, 1.0, 1.0),
+                ColorRYBA::new_ryb(0.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                ColorRYBA::new_ryb(1.0, 0.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(1.0, 0.5, 0.0),
+                ColorRYBA::new_ryb(1.0, 1.0, 0.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.0, 0.66, 0.2),
+                ColorRYBA::new_ryb(0.0, 1.0, 1.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.2, 0.094, 0.0),
+                ColorRYBA::new_ryb(1.0, 1.0, 1.0),
+            ),
+            (
+                ColorRGBA::new_rgb(0.607875, 0.453375, 0.2875),
+                ColorRYBA::new_ryb(0.5, 0.5, 0.5),
+            ),
+        ];
+    }
+
+    /// Rounds the RYB color for comparisons
+    fn round_ryb(color: &ColorRYBA) -> [i32; 4] {
+        return [
+            (color.get_red() * 1000.0).round() as i32,
+            (color.get_yellow() * 1000.0).round() as i32,
+            (color.get_blue() * 1000.0).round() as i32,
+            (color.get_alpha() * 1000.0).round() as i32,
+        ];
+    }
+
+    mod ryb {
+        use super::*;
+
+        #[test]
+        fn rgb_to_ryb() {
+            for values in get_test_values_ryb().iter() {
+                let ryb = super::super::rgb_to_ryb(&values.0);
+
+                assert_eq!(round_ryb(&values.1), round_ryb(&ryb));
             }
         }
 
         #[test]
-        fn to_hsi() {
-            let test_values = get_test_values();
+        fn ryb_to_rgb() {
+            for values in get_test_values_ryb().iter() {
+                let rgb = super::super::ryb_to_rgb(&values.1);
 
-            for values in test_values.iter() {
-                let hcm = &values.0;
-                let hsi = hcm.to_hsi();
+                assert_eq!(round_rgb(&values.0), round_rgb(&rgb));
+            }
+        }
 
-                assert_eq!(round_hsi(&values.4), round_hsi(&hsi));
+        #[test]
+        fn ryb_round_trip() {
+            for r in [0.0, 0.3, 0.7, 1.0].iter() {
+                for y in [0.0, 0.2, 0.9].iter() {
+                    for b in [0.1, 0.6, 1.0].iter() {
+                        let ryb = ColorRYBA::new(*r, *y, *b, 0.5);
+                        let result = super::super::rgb_to_ryb(&super::super::ryb_to_rgb(&ryb));
+
+                        assert_eq!(round_ryb(&ryb), round_ryb(&result));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn ryb_hue() {
+            let values = [
+                (ColorRYBA::new_ryb(1.0, 0.0, 0.0), 0),
+                (ColorRYBA::new_ryb(1.0, 1.0, 0.0), 60),
+                (ColorRYBA::new_ryb(0.0, 1.0, 0.0), 120),
+                (ColorRYBA::new_ryb(0.0, 0.5, 0.5), 180),
+                (ColorRYBA::new_ryb(0.0, 0.0, 1.0), 240),
+                (ColorRYBA::new_ryb(0.6, 0.2, 0.6), 300),
+            ];
+
+            for (ryb, hue) in values.iter() {
+                assert_eq!(*hue, (super::super::ryb_hue(ryb) * 360.0).round() as i32);
+            }
+        }
+
+        #[test]
+        fn ryb_complement() {
+            let values = [
+                (
+                    ColorRGBA::new_rgb(1.0, 0.0, 0.0),
+                    ColorRGBA::new_rgb(0.0, 0.66, 0.2),
+                ),
+                (
+                    ColorRGBA::new_rgb(1.0, 1.0, 0.0),
+                    ColorRGBA::new_rgb(0.5, 0.0, 0.5),
+                ),
+                (
+                    ColorRGBA::new_rgb(0.163, 0.373, 0.6),
+                    ColorRGBA::new_rgb(1.0, 0.5, 0.0),
+                ),
+            ];
+
+            for (rgb, complement) in values.iter() {
+                let ryb = super::super::ryb_complement(&super::super::rgb_to_ryb(rgb));
+
+                assert_eq!(
+                    round_rgb(complement),
+                    round_rgb(&super::super::ryb_to_rgb(&ryb))
+                );
             }
         }
+
+        #[test]
+        fn rotate_ryb_hue() {
+            let ryb = ColorRYBA::new(0.8, 0.2, 0.2, 0.5);
+            let rotated = super::super::rotate_ryb_hue(&ryb, 1.0 / 3.0);
+
+            assert_eq!([200, 800, 200, 500], round_ryb(&rotated));
+            assert_eq!(
+                round_ryb(&ryb),
+                round_ryb(&super::super::rotate_ryb_hue(&rotated, -1.0 / 3.0))
+            );
+        }
     }
 }